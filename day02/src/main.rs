@@ -1,55 +1,62 @@
-const OP_ADD: i32 = 1;
-const OP_MUL: i32 = 2;
-const OP_HALT: i32 = 99;
+use intcode::{run_symbolic, solve, Program, Sym, VmError};
 
-const PART2_PROGRAM_OUTPUT: i32 = 19_690_720;
+// restore the state before the "1202 program alarm", the image has 0 at both addrs
+const ALARM_PATCH: &str = "1=0->12, 2=0->2";
+const PART2_PROGRAM_OUTPUT: i64 = 19_690_720;
+// the image has less than 40 instructions, more means a noun or verb broke it
+const MAX_INSTRUCTIONS: u64 = 10_000;
 
-fn run_program(memory: &mut [i32]) {
-    let mut i = 0;
-    loop {
-        let opcode = memory[i];
-        match opcode {
-            OP_ADD => {
-                let lhs_pos = memory[i + 1] as usize;
-                let rhs_pos = memory[i + 2] as usize;
-                let result_pos = memory[i + 3] as usize;
-                memory[result_pos] = memory[lhs_pos] + memory[rhs_pos];
-            }
-            OP_MUL => {
-                let lhs_pos = memory[i + 1] as usize;
-                let rhs_pos = memory[i + 2] as usize;
-                let result_pos = memory[i + 3] as usize;
-                memory[result_pos] = memory[lhs_pos] * memory[rhs_pos];
-            }
-            OP_HALT => break,
-            _ => panic!("Unexpected opcode {}", opcode),
-        }
-        i += 4;
-    }
+// run the program and return the value at addr 0
+fn run(program: &mut Program) -> Result<i64, VmError> {
+    program.set_instruction_budget(MAX_INSTRUCTIONS);
+    program.run_collect(&[])?;
+    Ok(program.mem()[0])
+}
+
+// set noun and verb, run the program and return the value at addr 0
+fn run_program(program: &mut Program, noun: i64, verb: i64) -> Result<i64, VmError> {
+    program.mem_mut().set(1, noun);
+    program.mem_mut().set(2, verb);
+    run(program)
 }
 
-fn part1(input: &[i32]) {
-    let mut memory = input.to_vec();
-    memory[1] = 12;
-    memory[2] = 2;
-    run_program(&mut memory);
-    println!("Part1: {}", memory[0]);
+fn part1(input: &[i64]) {
+    let patch = ALARM_PATCH.parse().unwrap();
+    let mut program = Program::with_patch(input.to_vec(), &patch).unwrap();
+    println!("Part1: {}", run(&mut program).unwrap());
 }
 
-fn part2(input: &[i32]) {
+// brute force over all noun and verb pairs
+fn search(input: &[i64], target: i64) -> Option<(i64, i64)> {
+    let mut program = Program::new(input.to_vec());
+    let snapshot = program.snapshot();
     for verb in 0..100 {
         for noun in 0..100 {
-            let mut memory = input.to_vec();
-            memory[1] = noun;
-            memory[2] = verb;
-            run_program(&mut memory);
-            if memory[0] == PART2_PROGRAM_OUTPUT {
-                println!("Part2: {}", 100 * noun + verb);
-                return;
+            program.restore(&snapshot);
+            if run_program(&mut program, noun, verb) == Ok(target) {
+                return Some((noun, verb));
             }
         }
     }
-    panic!("Did not find requested program output");
+    None
+}
+
+// run with noun and verb as unknowns and solve the resulting expression for target
+fn solve_symbolic(input: &[i64], target: i64) -> Option<(i64, i64)> {
+    let mem = run_symbolic(input, &[1, 2]).unwrap();
+    let poly = match &mem[0] {
+        Sym::Poly(poly) => poly,
+        Sym::Opaque => panic!("Value at addr 0 depends on an unknown addr"),
+    };
+    solve(poly, target, &[0..100, 0..100]).map(|solution| (solution[0], solution[1]))
+}
+
+fn part2(input: &[i64]) {
+    let (noun, verb) =
+        solve_symbolic(input, PART2_PROGRAM_OUTPUT).expect("Did not find requested program output");
+    // cross check
+    assert_eq!(search(input, PART2_PROGRAM_OUTPUT), Some((noun, verb)));
+    println!("Part2: {}", 100 * noun + verb);
 }
 
 fn main() {
@@ -58,7 +65,7 @@ fn main() {
         .trim()
         .split(',')
         .map(str::parse)
-        .collect::<Result<Vec<i32>, _>>()
+        .collect::<Result<Vec<i64>, _>>()
         .unwrap();
     part1(&input);
     part2(&input);
@@ -0,0 +1,13 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+// Translates the input into native code, see intcode::translate
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/input.txt");
+    let input = fs::read_to_string("src/input.txt").unwrap();
+    let mem = intcode::parse_program(&input).unwrap();
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("boost.rs");
+    fs::write(out, intcode::translate(&mem)).unwrap();
+}
@@ -0,0 +1,73 @@
+use intcode::{parse_program, CachedProgram, NativeProgram, Program, Translated};
+use std::time::{Duration, Instant};
+
+// Compares Program with CachedProgram and NativeProgram, run with `cargo bench`
+
+// generated with the aot tool
+mod day09 {
+    include!("../fixtures/day09.rs");
+}
+mod day13 {
+    include!("../fixtures/day13.rs");
+}
+
+const MIN_TIME: Duration = Duration::from_millis(500);
+
+// Average time per run, runs at least MIN_TIME
+fn measure<F: FnMut() -> Vec<i64>>(mut run: F) -> (Duration, Vec<i64>) {
+    let start = Instant::now();
+    let mut runs = 0;
+    let mut output;
+    loop {
+        output = run();
+        runs += 1;
+        if start.elapsed() >= MIN_TIME {
+            break;
+        }
+    }
+    (start.elapsed() / runs, output)
+}
+
+fn bench(name: &str, input: &str, translated: &Translated, inputs: &[i64]) {
+    let mem = parse_program(input).unwrap();
+    let (plain, plain_output) = measure(|| Program::new(mem.clone()).run_collect(inputs).unwrap());
+    let (cached, cached_output) =
+        measure(|| CachedProgram::new(mem.clone()).run_collect(inputs).unwrap());
+    let (native, native_output) = measure(|| {
+        NativeProgram::new(mem.clone(), translated)
+            .run_collect(inputs)
+            .unwrap()
+    });
+    assert_eq!(plain_output, cached_output);
+    assert_eq!(plain_output, native_output);
+    println!(
+        "{:<12} plain {:>10.3?} cached {:>10.3?} ({:.2}x) native {:>10.3?} ({:.2}x)",
+        name,
+        plain,
+        cached,
+        plain.as_secs_f64() / cached.as_secs_f64(),
+        native,
+        plain.as_secs_f64() / native.as_secs_f64()
+    );
+}
+
+fn main() {
+    bench(
+        "day09 part1",
+        include_str!("../../day09/src/input.txt"),
+        &day09::TRANSLATED,
+        &[1],
+    );
+    bench(
+        "day09 part2",
+        include_str!("../../day09/src/input.txt"),
+        &day09::TRANSLATED,
+        &[2],
+    );
+    bench(
+        "day13 part1",
+        include_str!("../../day13/src/input.txt"),
+        &day13::TRANSLATED,
+        &[],
+    );
+}
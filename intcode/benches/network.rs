@@ -0,0 +1,119 @@
+use intcode::{
+    assemble, parse_program, permutations, Network, NetworkState, Program, Route, ThreadedNetwork,
+};
+use std::time::Instant;
+
+// Compares Network with ThreadedNetwork, run with `cargo bench`
+
+// Pass a token around the ring, decrement it on every hop and halt when it is 0
+const RING: &str = "
+    loop: in x
+          jf x, #done
+          add x, #-1, x
+          out x
+          jt #1, #loop
+    done: out x
+          hlt
+    x:    data 0
+";
+
+// Common interface of both networks
+trait Topology {
+    fn add(&mut self, name: &str, program: Program, input: &str, route: Route);
+    fn start(&mut self, channel: &str, val: i64);
+    fn finish(&mut self) -> NetworkState;
+}
+
+impl Topology for Network {
+    fn add(&mut self, name: &str, program: Program, input: &str, route: Route) {
+        self.add_machine(name, program, input, route).unwrap();
+    }
+
+    fn start(&mut self, channel: &str, val: i64) {
+        self.send(channel, val);
+    }
+
+    fn finish(&mut self) -> NetworkState {
+        self.run().unwrap()
+    }
+}
+
+impl Topology for ThreadedNetwork {
+    fn add(&mut self, name: &str, program: Program, input: &str, route: Route) {
+        self.add_machine(name, program, input, route).unwrap();
+    }
+
+    fn start(&mut self, channel: &str, val: i64) {
+        self.send(channel, val);
+    }
+
+    fn finish(&mut self) -> NetworkState {
+        self.run().unwrap()
+    }
+}
+
+// Machines i = 0..programs.len() read from channel i and send to channel i + 1,
+// the last one sends to channel 0
+fn run_ring<T: Topology>(network: &mut T, programs: Vec<Program>, first: &[i64]) {
+    let len = programs.len();
+    for (i, program) in programs.into_iter().enumerate() {
+        let next = ((i + 1) % len).to_string();
+        network.add(
+            &i.to_string(),
+            program,
+            &i.to_string(),
+            Route::Channel(next),
+        );
+    }
+    for &val in first {
+        network.start("0", val);
+    }
+    assert_eq!(network.finish(), NetworkState::Halted);
+}
+
+fn bench<F: Fn(bool)>(name: &str, run: F) {
+    let measure = |threaded| {
+        let start = Instant::now();
+        run(threaded);
+        start.elapsed()
+    };
+    let cooperative = measure(false);
+    let threaded = measure(true);
+    println!(
+        "{:<28} cooperative {:>10.3?} threaded {:>10.3?}",
+        name, cooperative, threaded
+    );
+}
+
+fn main() {
+    let mem = parse_program(include_str!("../../day07/src/input.txt")).unwrap();
+    bench("day07 part2, 120 x 5 amps", |threaded| {
+        for phases in permutations(&[5, 6, 7, 8, 9]) {
+            let programs = phases
+                .iter()
+                .map(|&phase| {
+                    let mut program = Program::new(mem.clone());
+                    program.push_input(phase);
+                    program
+                })
+                .collect();
+            if threaded {
+                run_ring(&mut ThreadedNetwork::new(), programs, &[0]);
+            } else {
+                run_ring(&mut Network::new(), programs, &[0]);
+            }
+        }
+    });
+
+    let ring = assemble(RING).unwrap();
+    for &(machines, hops) in &[(5, 100_000), (50, 100_000), (500, 100_000)] {
+        bench(&format!("ring {} x {} hops", machines, hops), |threaded| {
+            let programs = (0..machines).map(|_| Program::new(ring.clone())).collect();
+            if threaded {
+                run_ring(&mut ThreadedNetwork::new(), programs, &[hops]);
+            } else {
+                run_ring(&mut Network::new(), programs, &[hops]);
+            }
+        });
+    }
+}
@@ -0,0 +1,211 @@
+// Generated by intcode::translate, do not edit
+
+pub static TRANSLATED: intcode::Translated = intcode::Translated {
+    image: &IMAGE,
+    instrs: &INSTRS,
+    native,
+};
+
+static IMAGE: [i64; 47] = [3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36, 98, 0, 0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000, 1, 20, 4, 20, 1105, 1, 46, 98, 99];
+
+// addr and size of the translated instructions
+static INSTRS: [(usize, usize); 21] = [(0, 2), (2, 4), (4, 4), (6, 3), (9, 4), (10, 4), (13, 3), (16, 3), (22, 4), (26, 2), (28, 3), (29, 4), (31, 2), (33, 3), (34, 4), (36, 4), (38, 4), (40, 2), (42, 3), (43, 4), (46, 1)];
+
+#[inline(always)]
+#[allow(dead_code)]
+fn rel(rb: i64, offset: i64) -> Option<usize> {
+    match rb.checked_add(offset) {
+        Some(addr) if addr >= 0 => Some(addr as usize),
+        _ => None,
+    }
+}
+
+#[allow(unused_mut, clippy::all)]
+fn native(program: &mut intcode::Program, guard: &mut intcode::CodeGuard) -> intcode::Native {
+    use intcode::{Native, State};
+    let mut ip = program.ip();
+    let mut rb = program.rel_base();
+    let native = loop {
+        match ip {
+            0 => {
+                if guard.is_modified(0) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 21;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2;
+            }
+            2 => {
+                if guard.is_modified(2) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21];
+                let rhs: i64 = 8;
+                let addr = 20;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 6;
+            }
+            4 => {
+                if guard.is_modified(4) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[20];
+                let rhs: i64 = program.mem()[1005];
+                let addr = 20;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 8;
+            }
+            6 => {
+                if guard.is_modified(6) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[20];
+                let target = 22;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 9 };
+            }
+            9 => {
+                if guard.is_modified(9) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 8;
+                let rhs: i64 = program.mem()[21];
+                let addr = 20;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 13;
+            }
+            10 => {
+                if guard.is_modified(10) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21];
+                let rhs: i64 = program.mem()[20];
+                let addr = 1006;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 14;
+            }
+            13 => {
+                if guard.is_modified(13) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[20];
+                let target = 31;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 16 };
+            }
+            16 => {
+                if guard.is_modified(16) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 36;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 19 };
+            }
+            22 => {
+                if guard.is_modified(22) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21];
+                let rhs: i64 = 125;
+                let addr = 20;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 26;
+            }
+            26 => {
+                if guard.is_modified(26) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[20];
+                program.count_instruction();
+                ip = 28;
+                break Native::Paused(State::Output(val));
+            }
+            28 => {
+                if guard.is_modified(28) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 46;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 31 };
+            }
+            29 => {
+                if guard.is_modified(29) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[46];
+                let rhs: i64 = program.mem()[104];
+                let addr = 999;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 33;
+            }
+            31 => {
+                if guard.is_modified(31) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 999;
+                program.count_instruction();
+                ip = 33;
+                break Native::Paused(State::Output(val));
+            }
+            33 => {
+                if guard.is_modified(33) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 46;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 36 };
+            }
+            34 => {
+                if guard.is_modified(34) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[46];
+                let rhs: i64 = program.mem()[1101];
+                let addr = 1000;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 38;
+            }
+            36 => {
+                if guard.is_modified(36) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1000;
+                let rhs: i64 = 1;
+                let addr = 20;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 40;
+            }
+            38 => {
+                if guard.is_modified(38) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[20];
+                let rhs: i64 = program.mem()[4];
+                let addr = 20;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 42;
+            }
+            40 => {
+                if guard.is_modified(40) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[20];
+                program.count_instruction();
+                ip = 42;
+                break Native::Paused(State::Output(val));
+            }
+            42 => {
+                if guard.is_modified(42) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 46;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 45 };
+            }
+            43 => {
+                if guard.is_modified(43) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[46];
+                let rhs: i64 = program.mem()[98];
+                let addr = 99;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 47;
+            }
+            46 => {
+                if guard.is_modified(46) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            _ => break Native::Fallback,
+        }
+    };
+    program.set_ip(ip);
+    program.set_rel_base(rb);
+    native
+}
@@ -0,0 +1,185 @@
+// Generated by intcode::translate, do not edit
+
+pub static TRANSLATED: intcode::Translated = intcode::Translated {
+    image: &IMAGE,
+    instrs: &INSTRS,
+    native,
+};
+
+static IMAGE: [i64; 137] = [1, 0, 0, 3, 1, 1, 2, 3, 1, 3, 4, 3, 1, 5, 0, 3, 2, 1, 10, 19, 2, 9, 19, 23, 2, 23, 10, 27, 1, 6, 27, 31, 1, 31, 6, 35, 2, 35, 10, 39, 1, 39, 5, 43, 2, 6, 43, 47, 2, 47, 10, 51, 1, 51, 6, 55, 1, 55, 6, 59, 1, 9, 59, 63, 1, 63, 9, 67, 1, 67, 6, 71, 2, 71, 13, 75, 1, 75, 5, 79, 1, 79, 9, 83, 2, 6, 83, 87, 1, 87, 5, 91, 2, 6, 91, 95, 1, 95, 9, 99, 2, 6, 99, 103, 1, 5, 103, 107, 1, 6, 107, 111, 1, 111, 10, 115, 2, 115, 13, 119, 1, 119, 6, 123, 1, 123, 2, 127, 1, 127, 5, 0, 99, 2, 14, 0, 0];
+
+// addr and size of the translated instructions
+static INSTRS: [(usize, usize); 18] = [(4, 4), (5, 4), (6, 4), (7, 2), (8, 4), (9, 2), (10, 2), (11, 2), (12, 4), (13, 3), (15, 2), (21, 2), (61, 2), (102, 1), (128, 4), (130, 3), (132, 1), (133, 4)];
+
+#[inline(always)]
+#[allow(dead_code)]
+fn rel(rb: i64, offset: i64) -> Option<usize> {
+    match rb.checked_add(offset) {
+        Some(addr) if addr >= 0 => Some(addr as usize),
+        _ => None,
+    }
+}
+
+#[allow(unused_mut, clippy::all)]
+fn native(program: &mut intcode::Program, guard: &mut intcode::CodeGuard) -> intcode::Native {
+    use intcode::{Native, State};
+    let mut ip = program.ip();
+    let mut rb = program.rel_base();
+    let native = loop {
+        match ip {
+            4 => {
+                if guard.is_modified(4) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[2];
+                let addr = 3;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 8;
+            }
+            5 => {
+                if guard.is_modified(5) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[3];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 9;
+            }
+            6 => {
+                if guard.is_modified(6) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[3];
+                let rhs: i64 = program.mem()[1];
+                let addr = 3;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 10;
+            }
+            7 => {
+                if guard.is_modified(7) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 1;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 9;
+            }
+            8 => {
+                if guard.is_modified(8) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[3];
+                let rhs: i64 = program.mem()[4];
+                let addr = 3;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 12;
+            }
+            9 => {
+                if guard.is_modified(9) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 4;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 11;
+            }
+            10 => {
+                if guard.is_modified(10) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                program.count_instruction();
+                ip = 12;
+                break Native::Paused(State::Output(val));
+            }
+            11 => {
+                if guard.is_modified(11) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 1;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 13;
+            }
+            12 => {
+                if guard.is_modified(12) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[5];
+                let rhs: i64 = program.mem()[0];
+                let addr = 3;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 16;
+            }
+            13 => {
+                if guard.is_modified(13) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[0];
+                let target: i64 = program.mem()[3];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 16 };
+            }
+            15 => {
+                if guard.is_modified(15) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 2;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 17;
+            }
+            21 => {
+                if guard.is_modified(21) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[19];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 23;
+            }
+            61 => {
+                if guard.is_modified(61) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[59];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 63;
+            }
+            102 => {
+                if guard.is_modified(102) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            128 => {
+                if guard.is_modified(128) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[127];
+                let rhs: i64 = program.mem()[5];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 132;
+            }
+            130 => {
+                if guard.is_modified(130) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[0];
+                let target: i64 = program.mem()[99];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 133 };
+            }
+            132 => {
+                if guard.is_modified(132) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            133 => {
+                if guard.is_modified(133) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[14];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 137;
+            }
+            _ => break Native::Fallback,
+        }
+    };
+    program.set_ip(ip);
+    program.set_rel_base(rb);
+    native
+}
@@ -0,0 +1,2475 @@
+// Generated by intcode::translate, do not edit
+
+pub static TRANSLATED: intcode::Translated = intcode::Translated {
+    image: &IMAGE,
+    instrs: &INSTRS,
+    native,
+};
+
+static IMAGE: [i64; 678] = [3, 225, 1, 225, 6, 6, 1100, 1, 238, 225, 104, 0, 1002, 148, 28, 224, 1001, 224, -672, 224, 4, 224, 1002, 223, 8, 223, 101, 3, 224, 224, 1, 224, 223, 223, 1102, 8, 21, 225, 1102, 13, 10, 225, 1102, 21, 10, 225, 1102, 6, 14, 225, 1102, 94, 17, 225, 1, 40, 173, 224, 1001, 224, -90, 224, 4, 224, 102, 8, 223, 223, 1001, 224, 4, 224, 1, 224, 223, 223, 2, 35, 44, 224, 101, -80, 224, 224, 4, 224, 102, 8, 223, 223, 101, 6, 224, 224, 1, 223, 224, 223, 1101, 26, 94, 224, 101, -120, 224, 224, 4, 224, 102, 8, 223, 223, 1001, 224, 7, 224, 1, 224, 223, 223, 1001, 52, 70, 224, 101, -87, 224, 224, 4, 224, 1002, 223, 8, 223, 1001, 224, 2, 224, 1, 223, 224, 223, 1101, 16, 92, 225, 1101, 59, 24, 225, 102, 83, 48, 224, 101, -1162, 224, 224, 4, 224, 102, 8, 223, 223, 101, 4, 224, 224, 1, 223, 224, 223, 1101, 80, 10, 225, 101, 5, 143, 224, 1001, 224, -21, 224, 4, 224, 1002, 223, 8, 223, 1001, 224, 6, 224, 1, 223, 224, 223, 1102, 94, 67, 224, 101, -6298, 224, 224, 4, 224, 102, 8, 223, 223, 1001, 224, 3, 224, 1, 224, 223, 223, 4, 223, 99, 0, 0, 0, 677, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1105, 0, 99999, 1105, 227, 247, 1105, 1, 99999, 1005, 227, 99999, 1005, 0, 256, 1105, 1, 99999, 1106, 227, 99999, 1106, 0, 265, 1105, 1, 99999, 1006, 0, 99999, 1006, 227, 274, 1105, 1, 99999, 1105, 1, 280, 1105, 1, 99999, 1, 225, 225, 225, 1101, 294, 0, 0, 105, 1, 0, 1105, 1, 99999, 1106, 0, 300, 1105, 1, 99999, 1, 225, 225, 225, 1101, 314, 0, 0, 106, 0, 0, 1105, 1, 99999, 108, 677, 677, 224, 102, 2, 223, 223, 1005, 224, 329, 101, 1, 223, 223, 1107, 677, 226, 224, 102, 2, 223, 223, 1006, 224, 344, 101, 1, 223, 223, 1107, 226, 226, 224, 102, 2, 223, 223, 1006, 224, 359, 101, 1, 223, 223, 1108, 677, 677, 224, 102, 2, 223, 223, 1005, 224, 374, 101, 1, 223, 223, 8, 677, 226, 224, 1002, 223, 2, 223, 1005, 224, 389, 101, 1, 223, 223, 108, 226, 677, 224, 1002, 223, 2, 223, 1006, 224, 404, 1001, 223, 1, 223, 107, 677, 677, 224, 102, 2, 223, 223, 1006, 224, 419, 101, 1, 223, 223, 1007, 226, 226, 224, 102, 2, 223, 223, 1005, 224, 434, 101, 1, 223, 223, 1007, 677, 677, 224, 102, 2, 223, 223, 1005, 224, 449, 1001, 223, 1, 223, 8, 677, 677, 224, 1002, 223, 2, 223, 1006, 224, 464, 101, 1, 223, 223, 1108, 677, 226, 224, 1002, 223, 2, 223, 1005, 224, 479, 101, 1, 223, 223, 7, 677, 226, 224, 1002, 223, 2, 223, 1005, 224, 494, 101, 1, 223, 223, 1008, 677, 677, 224, 1002, 223, 2, 223, 1006, 224, 509, 1001, 223, 1, 223, 1007, 226, 677, 224, 1002, 223, 2, 223, 1006, 224, 524, 1001, 223, 1, 223, 107, 226, 226, 224, 1002, 223, 2, 223, 1006, 224, 539, 1001, 223, 1, 223, 1107, 226, 677, 224, 102, 2, 223, 223, 1005, 224, 554, 101, 1, 223, 223, 1108, 226, 677, 224, 102, 2, 223, 223, 1006, 224, 569, 101, 1, 223, 223, 108, 226, 226, 224, 1002, 223, 2, 223, 1006, 224, 584, 1001, 223, 1, 223, 7, 226, 226, 224, 1002, 223, 2, 223, 1006, 224, 599, 101, 1, 223, 223, 8, 226, 677, 224, 102, 2, 223, 223, 1005, 224, 614, 101, 1, 223, 223, 7, 226, 677, 224, 1002, 223, 2, 223, 1005, 224, 629, 101, 1, 223, 223, 1008, 226, 677, 224, 1002, 223, 2, 223, 1006, 224, 644, 101, 1, 223, 223, 107, 226, 677, 224, 1002, 223, 2, 223, 1005, 224, 659, 1001, 223, 1, 223, 1008, 226, 226, 224, 1002, 223, 2, 223, 1006, 224, 674, 1001, 223, 1, 223, 4, 223, 99, 226];
+
+// addr and size of the translated instructions
+static INSTRS: [(usize, usize); 263] = [(0, 2), (2, 4), (4, 3), (5, 3), (7, 4), (10, 2), (12, 4), (16, 4), (20, 2), (22, 4), (24, 4), (26, 4), (27, 2), (30, 4), (34, 4), (35, 4), (38, 4), (42, 4), (46, 4), (47, 3), (50, 4), (54, 4), (58, 4), (62, 2), (64, 4), (65, 4), (68, 4), (70, 2), (72, 4), (76, 4), (80, 4), (84, 2), (86, 4), (87, 4), (90, 4), (91, 3), (94, 4), (98, 4), (102, 4), (106, 2), (108, 4), (109, 4), (112, 4), (114, 4), (116, 4), (120, 4), (124, 4), (128, 2), (130, 4), (132, 4), (134, 4), (136, 4), (138, 4), (142, 4), (146, 4), (150, 4), (154, 4), (158, 2), (160, 4), (161, 4), (164, 4), (165, 2), (168, 4), (172, 4), (176, 4), (177, 3), (180, 4), (184, 2), (186, 4), (188, 4), (190, 4), (192, 3), (194, 4), (198, 4), (202, 4), (206, 2), (208, 4), (209, 4), (212, 4), (214, 2), (216, 4), (220, 2), (222, 1), (238, 3), (241, 3), (244, 3), (245, 4), (247, 3), (250, 3), (253, 3), (254, 4), (256, 3), (259, 3), (262, 3), (263, 4), (265, 3), (268, 3), (271, 3), (272, 4), (274, 3), (275, 4), (277, 3), (278, 4), (280, 4), (284, 4), (288, 3), (289, 4), (291, 3), (292, 4), (294, 3), (297, 3), (298, 4), (300, 4), (304, 4), (308, 3), (311, 3), (312, 4), (314, 4), (318, 4), (319, 4), (322, 3), (325, 4), (326, 4), (329, 4), (333, 4), (334, 4), (337, 3), (340, 4), (341, 4), (344, 4), (348, 4), (349, 4), (352, 3), (355, 4), (356, 4), (359, 4), (363, 4), (364, 4), (367, 3), (370, 4), (371, 4), (374, 4), (378, 4), (380, 4), (382, 3), (385, 4), (386, 4), (389, 4), (393, 4), (395, 4), (397, 3), (400, 4), (402, 4), (404, 4), (408, 4), (409, 4), (412, 3), (415, 4), (416, 4), (419, 4), (423, 4), (424, 4), (427, 3), (430, 4), (431, 4), (434, 4), (438, 4), (439, 4), (442, 3), (445, 4), (447, 4), (449, 4), (453, 4), (455, 4), (457, 3), (460, 4), (461, 4), (464, 4), (468, 4), (470, 4), (472, 3), (475, 4), (476, 4), (479, 4), (483, 4), (485, 4), (487, 3), (490, 4), (491, 4), (494, 4), (498, 4), (500, 4), (502, 3), (505, 4), (507, 4), (509, 4), (513, 4), (515, 4), (517, 3), (520, 4), (522, 4), (524, 4), (528, 4), (530, 4), (532, 3), (535, 4), (537, 4), (539, 4), (543, 4), (544, 4), (547, 3), (550, 4), (551, 4), (554, 4), (558, 4), (559, 4), (562, 3), (565, 4), (566, 4), (569, 4), (573, 4), (575, 4), (577, 3), (580, 4), (582, 4), (584, 4), (588, 4), (590, 4), (592, 3), (595, 4), (596, 4), (599, 4), (603, 4), (604, 4), (607, 3), (610, 4), (611, 4), (614, 4), (618, 4), (620, 4), (622, 3), (625, 4), (626, 4), (629, 4), (633, 4), (635, 4), (637, 3), (640, 4), (641, 4), (644, 4), (648, 4), (650, 4), (652, 3), (655, 4), (657, 4), (659, 4), (663, 4), (665, 4), (667, 3), (670, 4), (672, 4), (674, 2), (676, 1)];
+
+#[inline(always)]
+#[allow(dead_code)]
+fn rel(rb: i64, offset: i64) -> Option<usize> {
+    match rb.checked_add(offset) {
+        Some(addr) if addr >= 0 => Some(addr as usize),
+        _ => None,
+    }
+}
+
+#[allow(unused_mut, clippy::all)]
+fn native(program: &mut intcode::Program, guard: &mut intcode::CodeGuard) -> intcode::Native {
+    use intcode::{Native, State};
+    let mut ip = program.ip();
+    let mut rb = program.rel_base();
+    let native = loop {
+        match ip {
+            0 => {
+                if guard.is_modified(0) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 225;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2;
+            }
+            2 => {
+                if guard.is_modified(2) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[225];
+                let rhs: i64 = program.mem()[6];
+                let addr = 6;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 6;
+            }
+            4 => {
+                if guard.is_modified(4) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[6];
+                let target: i64 = program.mem()[1100];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 7 };
+            }
+            5 => {
+                if guard.is_modified(5) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1100];
+                let target: i64 = program.mem()[1];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 8 };
+            }
+            7 => {
+                if guard.is_modified(7) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[238];
+                let rhs: i64 = program.mem()[225];
+                let addr = 104;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 11;
+            }
+            10 => {
+                if guard.is_modified(10) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 12;
+                break Native::Paused(State::Output(val));
+            }
+            12 => {
+                if guard.is_modified(12) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[148];
+                let rhs: i64 = 28;
+                let addr = 224;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 16;
+            }
+            16 => {
+                if guard.is_modified(16) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[224];
+                let rhs: i64 = -672;
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 20;
+            }
+            20 => {
+                if guard.is_modified(20) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                program.count_instruction();
+                ip = 22;
+                break Native::Paused(State::Output(val));
+            }
+            22 => {
+                if guard.is_modified(22) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 8;
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 26;
+            }
+            24 => {
+                if guard.is_modified(24) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[101];
+                let addr = 3;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 28;
+            }
+            26 => {
+                if guard.is_modified(26) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 3;
+                let rhs: i64 = program.mem()[224];
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 30;
+            }
+            27 => {
+                if guard.is_modified(27) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 224;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 29;
+            }
+            30 => {
+                if guard.is_modified(30) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[224];
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 34;
+            }
+            34 => {
+                if guard.is_modified(34) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 8;
+                let rhs: i64 = 21;
+                let addr = 225;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 38;
+            }
+            35 => {
+                if guard.is_modified(35) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21];
+                let rhs: i64 = program.mem()[225];
+                let addr = 1102;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 39;
+            }
+            38 => {
+                if guard.is_modified(38) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 13;
+                let rhs: i64 = 10;
+                let addr = 225;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 42;
+            }
+            42 => {
+                if guard.is_modified(42) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 21;
+                let rhs: i64 = 10;
+                let addr = 225;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 46;
+            }
+            46 => {
+                if guard.is_modified(46) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 6;
+                let rhs: i64 = 14;
+                let addr = 225;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 50;
+            }
+            47 => {
+                if guard.is_modified(47) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[14];
+                let target: i64 = program.mem()[225];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 50 };
+            }
+            50 => {
+                if guard.is_modified(50) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 94;
+                let rhs: i64 = 17;
+                let addr = 225;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 54;
+            }
+            54 => {
+                if guard.is_modified(54) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[40];
+                let rhs: i64 = program.mem()[173];
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 58;
+            }
+            58 => {
+                if guard.is_modified(58) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[224];
+                let rhs: i64 = -90;
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 62;
+            }
+            62 => {
+                if guard.is_modified(62) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                program.count_instruction();
+                ip = 64;
+                break Native::Paused(State::Output(val));
+            }
+            64 => {
+                if guard.is_modified(64) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 8;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 68;
+            }
+            65 => {
+                if guard.is_modified(65) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1001;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 69;
+            }
+            68 => {
+                if guard.is_modified(68) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[224];
+                let rhs: i64 = 4;
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 72;
+            }
+            70 => {
+                if guard.is_modified(70) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                program.count_instruction();
+                ip = 72;
+                break Native::Paused(State::Output(val));
+            }
+            72 => {
+                if guard.is_modified(72) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[224];
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 76;
+            }
+            76 => {
+                if guard.is_modified(76) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[35];
+                let rhs: i64 = program.mem()[44];
+                let addr = 224;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 80;
+            }
+            80 => {
+                if guard.is_modified(80) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = -80;
+                let rhs: i64 = program.mem()[224];
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 84;
+            }
+            84 => {
+                if guard.is_modified(84) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                program.count_instruction();
+                ip = 86;
+                break Native::Paused(State::Output(val));
+            }
+            86 => {
+                if guard.is_modified(86) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 8;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 90;
+            }
+            87 => {
+                if guard.is_modified(87) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 101;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 91;
+            }
+            90 => {
+                if guard.is_modified(90) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 6;
+                let rhs: i64 = program.mem()[224];
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 94;
+            }
+            91 => {
+                if guard.is_modified(91) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target: i64 = program.mem()[224];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 94 };
+            }
+            94 => {
+                if guard.is_modified(94) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[224];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 98;
+            }
+            98 => {
+                if guard.is_modified(98) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 26;
+                let rhs: i64 = 94;
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 102;
+            }
+            102 => {
+                if guard.is_modified(102) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = -120;
+                let rhs: i64 = program.mem()[224];
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 106;
+            }
+            106 => {
+                if guard.is_modified(106) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                program.count_instruction();
+                ip = 108;
+                break Native::Paused(State::Output(val));
+            }
+            108 => {
+                if guard.is_modified(108) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 8;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 112;
+            }
+            109 => {
+                if guard.is_modified(109) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1001;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 113;
+            }
+            112 => {
+                if guard.is_modified(112) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[224];
+                let rhs: i64 = 7;
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 116;
+            }
+            114 => {
+                if guard.is_modified(114) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[224];
+                let rhs: i64 = program.mem()[1];
+                let addr = 224;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 118;
+            }
+            116 => {
+                if guard.is_modified(116) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[224];
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 120;
+            }
+            120 => {
+                if guard.is_modified(120) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[52];
+                let rhs: i64 = 70;
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 124;
+            }
+            124 => {
+                if guard.is_modified(124) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = -87;
+                let rhs: i64 = program.mem()[224];
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 128;
+            }
+            128 => {
+                if guard.is_modified(128) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                program.count_instruction();
+                ip = 130;
+                break Native::Paused(State::Output(val));
+            }
+            130 => {
+                if guard.is_modified(130) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 8;
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 134;
+            }
+            132 => {
+                if guard.is_modified(132) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[1001];
+                let addr = 224;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 136;
+            }
+            134 => {
+                if guard.is_modified(134) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[224];
+                let rhs: i64 = 2;
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 138;
+            }
+            136 => {
+                if guard.is_modified(136) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[224];
+                let rhs: i64 = program.mem()[1];
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 140;
+            }
+            138 => {
+                if guard.is_modified(138) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[224];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 142;
+            }
+            142 => {
+                if guard.is_modified(142) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 16;
+                let rhs: i64 = 92;
+                let addr = 225;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 146;
+            }
+            146 => {
+                if guard.is_modified(146) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 59;
+                let rhs: i64 = 24;
+                let addr = 225;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 150;
+            }
+            150 => {
+                if guard.is_modified(150) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 83;
+                let rhs: i64 = program.mem()[48];
+                let addr = 224;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 154;
+            }
+            154 => {
+                if guard.is_modified(154) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = -1162;
+                let rhs: i64 = program.mem()[224];
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 158;
+            }
+            158 => {
+                if guard.is_modified(158) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                program.count_instruction();
+                ip = 160;
+                break Native::Paused(State::Output(val));
+            }
+            160 => {
+                if guard.is_modified(160) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 8;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 164;
+            }
+            161 => {
+                if guard.is_modified(161) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 101;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 165;
+            }
+            164 => {
+                if guard.is_modified(164) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 4;
+                let rhs: i64 = program.mem()[224];
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 168;
+            }
+            165 => {
+                if guard.is_modified(165) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                program.count_instruction();
+                ip = 167;
+                break Native::Paused(State::Output(val));
+            }
+            168 => {
+                if guard.is_modified(168) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[224];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 172;
+            }
+            172 => {
+                if guard.is_modified(172) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 80;
+                let rhs: i64 = 10;
+                let addr = 225;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 176;
+            }
+            176 => {
+                if guard.is_modified(176) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 5;
+                let rhs: i64 = program.mem()[143];
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 180;
+            }
+            177 => {
+                if guard.is_modified(177) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[143];
+                let target: i64 = program.mem()[224];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 180 };
+            }
+            180 => {
+                if guard.is_modified(180) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[224];
+                let rhs: i64 = -21;
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 184;
+            }
+            184 => {
+                if guard.is_modified(184) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                program.count_instruction();
+                ip = 186;
+                break Native::Paused(State::Output(val));
+            }
+            186 => {
+                if guard.is_modified(186) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 8;
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 190;
+            }
+            188 => {
+                if guard.is_modified(188) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[1001];
+                let addr = 224;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 192;
+            }
+            190 => {
+                if guard.is_modified(190) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[224];
+                let rhs: i64 = 6;
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 194;
+            }
+            192 => {
+                if guard.is_modified(192) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target: i64 = program.mem()[1];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 195 };
+            }
+            194 => {
+                if guard.is_modified(194) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[224];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 198;
+            }
+            198 => {
+                if guard.is_modified(198) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 94;
+                let rhs: i64 = 67;
+                let addr = 224;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 202;
+            }
+            202 => {
+                if guard.is_modified(202) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = -6298;
+                let rhs: i64 = program.mem()[224];
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 206;
+            }
+            206 => {
+                if guard.is_modified(206) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                program.count_instruction();
+                ip = 208;
+                break Native::Paused(State::Output(val));
+            }
+            208 => {
+                if guard.is_modified(208) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 8;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 212;
+            }
+            209 => {
+                if guard.is_modified(209) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1001;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 213;
+            }
+            212 => {
+                if guard.is_modified(212) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[224];
+                let rhs: i64 = 3;
+                let addr = 224;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 216;
+            }
+            214 => {
+                if guard.is_modified(214) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 224;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 216;
+            }
+            216 => {
+                if guard.is_modified(216) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[224];
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 220;
+            }
+            220 => {
+                if guard.is_modified(220) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[223];
+                program.count_instruction();
+                ip = 222;
+                break Native::Paused(State::Output(val));
+            }
+            222 => {
+                if guard.is_modified(222) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            238 => {
+                if guard.is_modified(238) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 99999;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 241 };
+            }
+            241 => {
+                if guard.is_modified(241) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 227;
+                let target = 247;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 244 };
+            }
+            244 => {
+                if guard.is_modified(244) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 99999;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 247 };
+            }
+            245 => {
+                if guard.is_modified(245) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[99999];
+                let rhs: i64 = program.mem()[1005];
+                let addr = 227;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 249;
+            }
+            247 => {
+                if guard.is_modified(247) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[227];
+                let target = 99999;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 250 };
+            }
+            250 => {
+                if guard.is_modified(250) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[0];
+                let target = 256;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 253 };
+            }
+            253 => {
+                if guard.is_modified(253) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 99999;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 256 };
+            }
+            254 => {
+                if guard.is_modified(254) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[99999];
+                let rhs: i64 = program.mem()[1106];
+                let addr = 227;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 258;
+            }
+            256 => {
+                if guard.is_modified(256) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 227;
+                let target = 99999;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 259 };
+            }
+            259 => {
+                if guard.is_modified(259) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 265;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 262 };
+            }
+            262 => {
+                if guard.is_modified(262) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 99999;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 265 };
+            }
+            263 => {
+                if guard.is_modified(263) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[99999];
+                let rhs: i64 = program.mem()[1006];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 267;
+            }
+            265 => {
+                if guard.is_modified(265) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[0];
+                let target = 99999;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 268 };
+            }
+            268 => {
+                if guard.is_modified(268) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[227];
+                let target = 274;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 271 };
+            }
+            271 => {
+                if guard.is_modified(271) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 99999;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 274 };
+            }
+            272 => {
+                if guard.is_modified(272) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[99999];
+                let rhs: i64 = program.mem()[1105];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 276;
+            }
+            274 => {
+                if guard.is_modified(274) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 280;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 277 };
+            }
+            275 => {
+                if guard.is_modified(275) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[280];
+                let rhs: i64 = program.mem()[1105];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 279;
+            }
+            277 => {
+                if guard.is_modified(277) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 99999;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 280 };
+            }
+            278 => {
+                if guard.is_modified(278) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[99999];
+                let rhs: i64 = program.mem()[1];
+                let addr = 225;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 282;
+            }
+            280 => {
+                if guard.is_modified(280) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[225];
+                let rhs: i64 = program.mem()[225];
+                let addr = 225;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 284;
+            }
+            284 => {
+                if guard.is_modified(284) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 294;
+                let rhs: i64 = 0;
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 288;
+            }
+            288 => {
+                if guard.is_modified(288) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target: i64 = program.mem()[0];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 291 };
+            }
+            289 => {
+                if guard.is_modified(289) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[1105];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 293;
+            }
+            291 => {
+                if guard.is_modified(291) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 99999;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 294 };
+            }
+            292 => {
+                if guard.is_modified(292) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[99999];
+                let rhs: i64 = program.mem()[1106];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 296;
+            }
+            294 => {
+                if guard.is_modified(294) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 300;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 297 };
+            }
+            297 => {
+                if guard.is_modified(297) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 99999;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 300 };
+            }
+            298 => {
+                if guard.is_modified(298) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[99999];
+                let rhs: i64 = program.mem()[1];
+                let addr = 225;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 302;
+            }
+            300 => {
+                if guard.is_modified(300) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[225];
+                let rhs: i64 = program.mem()[225];
+                let addr = 225;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 304;
+            }
+            304 => {
+                if guard.is_modified(304) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 314;
+                let rhs: i64 = 0;
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 308;
+            }
+            308 => {
+                if guard.is_modified(308) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target: i64 = program.mem()[0];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 311 };
+            }
+            311 => {
+                if guard.is_modified(311) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 99999;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 314 };
+            }
+            312 => {
+                if guard.is_modified(312) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[99999];
+                let rhs: i64 = program.mem()[108];
+                let addr = 677;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 316;
+            }
+            314 => {
+                if guard.is_modified(314) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 677;
+                let rhs: i64 = program.mem()[677];
+                let addr = 224;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 318;
+            }
+            318 => {
+                if guard.is_modified(318) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 322;
+            }
+            319 => {
+                if guard.is_modified(319) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1005;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 323;
+            }
+            322 => {
+                if guard.is_modified(322) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 329;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 325 };
+            }
+            325 => {
+                if guard.is_modified(325) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 329;
+            }
+            326 => {
+                if guard.is_modified(326) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1107;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 330;
+            }
+            329 => {
+                if guard.is_modified(329) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 677;
+                let rhs: i64 = 226;
+                let addr = 224;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 333;
+            }
+            333 => {
+                if guard.is_modified(333) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 337;
+            }
+            334 => {
+                if guard.is_modified(334) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1006;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 338;
+            }
+            337 => {
+                if guard.is_modified(337) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 344;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 340 };
+            }
+            340 => {
+                if guard.is_modified(340) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 344;
+            }
+            341 => {
+                if guard.is_modified(341) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1107;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 345;
+            }
+            344 => {
+                if guard.is_modified(344) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 226;
+                let rhs: i64 = 226;
+                let addr = 224;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 348;
+            }
+            348 => {
+                if guard.is_modified(348) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 352;
+            }
+            349 => {
+                if guard.is_modified(349) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1006;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 353;
+            }
+            352 => {
+                if guard.is_modified(352) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 359;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 355 };
+            }
+            355 => {
+                if guard.is_modified(355) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 359;
+            }
+            356 => {
+                if guard.is_modified(356) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1108;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 360;
+            }
+            359 => {
+                if guard.is_modified(359) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 677;
+                let rhs: i64 = 677;
+                let addr = 224;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 363;
+            }
+            363 => {
+                if guard.is_modified(363) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 367;
+            }
+            364 => {
+                if guard.is_modified(364) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1005;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 368;
+            }
+            367 => {
+                if guard.is_modified(367) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 374;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 370 };
+            }
+            370 => {
+                if guard.is_modified(370) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 374;
+            }
+            371 => {
+                if guard.is_modified(371) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 8;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 375;
+            }
+            374 => {
+                if guard.is_modified(374) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[677];
+                let rhs: i64 = program.mem()[226];
+                let addr = 224;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 378;
+            }
+            378 => {
+                if guard.is_modified(378) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 2;
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 382;
+            }
+            380 => {
+                if guard.is_modified(380) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[1005];
+                let addr = 224;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 384;
+            }
+            382 => {
+                if guard.is_modified(382) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 389;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 385 };
+            }
+            385 => {
+                if guard.is_modified(385) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 389;
+            }
+            386 => {
+                if guard.is_modified(386) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 108;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 390;
+            }
+            389 => {
+                if guard.is_modified(389) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 226;
+                let rhs: i64 = program.mem()[677];
+                let addr = 224;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 393;
+            }
+            393 => {
+                if guard.is_modified(393) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 2;
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 397;
+            }
+            395 => {
+                if guard.is_modified(395) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[1006];
+                let addr = 224;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 399;
+            }
+            397 => {
+                if guard.is_modified(397) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 404;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 400 };
+            }
+            400 => {
+                if guard.is_modified(400) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 1;
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 404;
+            }
+            402 => {
+                if guard.is_modified(402) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[107];
+                let addr = 677;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 406;
+            }
+            404 => {
+                if guard.is_modified(404) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 677;
+                let rhs: i64 = program.mem()[677];
+                let addr = 224;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 408;
+            }
+            408 => {
+                if guard.is_modified(408) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 412;
+            }
+            409 => {
+                if guard.is_modified(409) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1006;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 413;
+            }
+            412 => {
+                if guard.is_modified(412) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 419;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 415 };
+            }
+            415 => {
+                if guard.is_modified(415) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 419;
+            }
+            416 => {
+                if guard.is_modified(416) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1007;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 420;
+            }
+            419 => {
+                if guard.is_modified(419) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[226];
+                let rhs: i64 = 226;
+                let addr = 224;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 423;
+            }
+            423 => {
+                if guard.is_modified(423) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 427;
+            }
+            424 => {
+                if guard.is_modified(424) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1005;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 428;
+            }
+            427 => {
+                if guard.is_modified(427) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 434;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 430 };
+            }
+            430 => {
+                if guard.is_modified(430) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 434;
+            }
+            431 => {
+                if guard.is_modified(431) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1007;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 435;
+            }
+            434 => {
+                if guard.is_modified(434) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[677];
+                let rhs: i64 = 677;
+                let addr = 224;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 438;
+            }
+            438 => {
+                if guard.is_modified(438) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 442;
+            }
+            439 => {
+                if guard.is_modified(439) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1005;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 443;
+            }
+            442 => {
+                if guard.is_modified(442) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 449;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 445 };
+            }
+            445 => {
+                if guard.is_modified(445) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 1;
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 449;
+            }
+            447 => {
+                if guard.is_modified(447) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[8];
+                let addr = 677;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 451;
+            }
+            449 => {
+                if guard.is_modified(449) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[677];
+                let rhs: i64 = program.mem()[677];
+                let addr = 224;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 453;
+            }
+            453 => {
+                if guard.is_modified(453) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 2;
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 457;
+            }
+            455 => {
+                if guard.is_modified(455) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[1006];
+                let addr = 224;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 459;
+            }
+            457 => {
+                if guard.is_modified(457) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 464;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 460 };
+            }
+            460 => {
+                if guard.is_modified(460) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 464;
+            }
+            461 => {
+                if guard.is_modified(461) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1108;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 465;
+            }
+            464 => {
+                if guard.is_modified(464) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 677;
+                let rhs: i64 = 226;
+                let addr = 224;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 468;
+            }
+            468 => {
+                if guard.is_modified(468) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 2;
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 472;
+            }
+            470 => {
+                if guard.is_modified(470) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[1005];
+                let addr = 224;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 474;
+            }
+            472 => {
+                if guard.is_modified(472) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 479;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 475 };
+            }
+            475 => {
+                if guard.is_modified(475) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 479;
+            }
+            476 => {
+                if guard.is_modified(476) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 7;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 480;
+            }
+            479 => {
+                if guard.is_modified(479) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[677];
+                let rhs: i64 = program.mem()[226];
+                let addr = 224;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 483;
+            }
+            483 => {
+                if guard.is_modified(483) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 2;
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 487;
+            }
+            485 => {
+                if guard.is_modified(485) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[1005];
+                let addr = 224;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 489;
+            }
+            487 => {
+                if guard.is_modified(487) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 494;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 490 };
+            }
+            490 => {
+                if guard.is_modified(490) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 494;
+            }
+            491 => {
+                if guard.is_modified(491) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1008;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 495;
+            }
+            494 => {
+                if guard.is_modified(494) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[677];
+                let rhs: i64 = 677;
+                let addr = 224;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 498;
+            }
+            498 => {
+                if guard.is_modified(498) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 2;
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 502;
+            }
+            500 => {
+                if guard.is_modified(500) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[1006];
+                let addr = 224;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 504;
+            }
+            502 => {
+                if guard.is_modified(502) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 509;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 505 };
+            }
+            505 => {
+                if guard.is_modified(505) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 1;
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 509;
+            }
+            507 => {
+                if guard.is_modified(507) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[1007];
+                let addr = 226;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 511;
+            }
+            509 => {
+                if guard.is_modified(509) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[226];
+                let rhs: i64 = 677;
+                let addr = 224;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 513;
+            }
+            513 => {
+                if guard.is_modified(513) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 2;
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 517;
+            }
+            515 => {
+                if guard.is_modified(515) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[1006];
+                let addr = 224;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 519;
+            }
+            517 => {
+                if guard.is_modified(517) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 524;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 520 };
+            }
+            520 => {
+                if guard.is_modified(520) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 1;
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 524;
+            }
+            522 => {
+                if guard.is_modified(522) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[107];
+                let addr = 226;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 526;
+            }
+            524 => {
+                if guard.is_modified(524) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 226;
+                let rhs: i64 = program.mem()[226];
+                let addr = 224;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 528;
+            }
+            528 => {
+                if guard.is_modified(528) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 2;
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 532;
+            }
+            530 => {
+                if guard.is_modified(530) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[1006];
+                let addr = 224;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 534;
+            }
+            532 => {
+                if guard.is_modified(532) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 539;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 535 };
+            }
+            535 => {
+                if guard.is_modified(535) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 1;
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 539;
+            }
+            537 => {
+                if guard.is_modified(537) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[1107];
+                let addr = 226;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 541;
+            }
+            539 => {
+                if guard.is_modified(539) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 226;
+                let rhs: i64 = 677;
+                let addr = 224;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 543;
+            }
+            543 => {
+                if guard.is_modified(543) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 547;
+            }
+            544 => {
+                if guard.is_modified(544) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1005;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 548;
+            }
+            547 => {
+                if guard.is_modified(547) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 554;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 550 };
+            }
+            550 => {
+                if guard.is_modified(550) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 554;
+            }
+            551 => {
+                if guard.is_modified(551) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1108;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 555;
+            }
+            554 => {
+                if guard.is_modified(554) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 226;
+                let rhs: i64 = 677;
+                let addr = 224;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 558;
+            }
+            558 => {
+                if guard.is_modified(558) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 562;
+            }
+            559 => {
+                if guard.is_modified(559) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1006;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 563;
+            }
+            562 => {
+                if guard.is_modified(562) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 569;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 565 };
+            }
+            565 => {
+                if guard.is_modified(565) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 569;
+            }
+            566 => {
+                if guard.is_modified(566) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 108;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 570;
+            }
+            569 => {
+                if guard.is_modified(569) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 226;
+                let rhs: i64 = program.mem()[226];
+                let addr = 224;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 573;
+            }
+            573 => {
+                if guard.is_modified(573) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 2;
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 577;
+            }
+            575 => {
+                if guard.is_modified(575) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[1006];
+                let addr = 224;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 579;
+            }
+            577 => {
+                if guard.is_modified(577) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 584;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 580 };
+            }
+            580 => {
+                if guard.is_modified(580) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 1;
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 584;
+            }
+            582 => {
+                if guard.is_modified(582) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[7];
+                let addr = 226;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 586;
+            }
+            584 => {
+                if guard.is_modified(584) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[226];
+                let rhs: i64 = program.mem()[226];
+                let addr = 224;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 588;
+            }
+            588 => {
+                if guard.is_modified(588) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 2;
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 592;
+            }
+            590 => {
+                if guard.is_modified(590) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[1006];
+                let addr = 224;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 594;
+            }
+            592 => {
+                if guard.is_modified(592) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 599;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 595 };
+            }
+            595 => {
+                if guard.is_modified(595) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 599;
+            }
+            596 => {
+                if guard.is_modified(596) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 8;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 600;
+            }
+            599 => {
+                if guard.is_modified(599) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[226];
+                let rhs: i64 = program.mem()[677];
+                let addr = 224;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 603;
+            }
+            603 => {
+                if guard.is_modified(603) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 607;
+            }
+            604 => {
+                if guard.is_modified(604) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1005;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 608;
+            }
+            607 => {
+                if guard.is_modified(607) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 614;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 610 };
+            }
+            610 => {
+                if guard.is_modified(610) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 614;
+            }
+            611 => {
+                if guard.is_modified(611) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 7;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 615;
+            }
+            614 => {
+                if guard.is_modified(614) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[226];
+                let rhs: i64 = program.mem()[677];
+                let addr = 224;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 618;
+            }
+            618 => {
+                if guard.is_modified(618) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 2;
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 622;
+            }
+            620 => {
+                if guard.is_modified(620) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[1005];
+                let addr = 224;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 624;
+            }
+            622 => {
+                if guard.is_modified(622) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 629;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 625 };
+            }
+            625 => {
+                if guard.is_modified(625) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 629;
+            }
+            626 => {
+                if guard.is_modified(626) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 1008;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 630;
+            }
+            629 => {
+                if guard.is_modified(629) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[226];
+                let rhs: i64 = 677;
+                let addr = 224;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 633;
+            }
+            633 => {
+                if guard.is_modified(633) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 2;
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 637;
+            }
+            635 => {
+                if guard.is_modified(635) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[1006];
+                let addr = 224;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 639;
+            }
+            637 => {
+                if guard.is_modified(637) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 644;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 640 };
+            }
+            640 => {
+                if guard.is_modified(640) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[223];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 644;
+            }
+            641 => {
+                if guard.is_modified(641) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[223];
+                let addr = 107;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 645;
+            }
+            644 => {
+                if guard.is_modified(644) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 226;
+                let rhs: i64 = program.mem()[677];
+                let addr = 224;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 648;
+            }
+            648 => {
+                if guard.is_modified(648) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 2;
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 652;
+            }
+            650 => {
+                if guard.is_modified(650) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[1005];
+                let addr = 224;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 654;
+            }
+            652 => {
+                if guard.is_modified(652) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 659;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 655 };
+            }
+            655 => {
+                if guard.is_modified(655) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 1;
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 659;
+            }
+            657 => {
+                if guard.is_modified(657) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[1008];
+                let addr = 226;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 661;
+            }
+            659 => {
+                if guard.is_modified(659) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[226];
+                let rhs: i64 = 226;
+                let addr = 224;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 663;
+            }
+            663 => {
+                if guard.is_modified(663) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 2;
+                let addr = 223;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 667;
+            }
+            665 => {
+                if guard.is_modified(665) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[1006];
+                let addr = 224;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 669;
+            }
+            667 => {
+                if guard.is_modified(667) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[224];
+                let target = 674;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 670 };
+            }
+            670 => {
+                if guard.is_modified(670) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = 1;
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 674;
+            }
+            672 => {
+                if guard.is_modified(672) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[223];
+                let rhs: i64 = program.mem()[4];
+                let addr = 223;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 676;
+            }
+            674 => {
+                if guard.is_modified(674) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[223];
+                program.count_instruction();
+                ip = 676;
+                break Native::Paused(State::Output(val));
+            }
+            676 => {
+                if guard.is_modified(676) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            _ => break Native::Fallback,
+        }
+    };
+    program.set_ip(ip);
+    program.set_rel_base(rb);
+    native
+}
@@ -0,0 +1,3996 @@
+// Generated by intcode::translate, do not edit
+
+pub static TRANSLATED: intcode::Translated = intcode::Translated {
+    image: &IMAGE,
+    instrs: &INSTRS,
+    native,
+};
+
+static IMAGE: [i64; 523] = [3, 8, 1001, 8, 10, 8, 105, 1, 0, 0, 21, 46, 63, 76, 97, 118, 199, 280, 361, 442, 99999, 3, 9, 102, 4, 9, 9, 101, 2, 9, 9, 1002, 9, 5, 9, 101, 4, 9, 9, 102, 2, 9, 9, 4, 9, 99, 3, 9, 101, 5, 9, 9, 102, 3, 9, 9, 101, 3, 9, 9, 4, 9, 99, 3, 9, 1001, 9, 2, 9, 102, 3, 9, 9, 4, 9, 99, 3, 9, 1002, 9, 5, 9, 101, 4, 9, 9, 1002, 9, 3, 9, 101, 2, 9, 9, 4, 9, 99, 3, 9, 1002, 9, 5, 9, 101, 3, 9, 9, 1002, 9, 5, 9, 1001, 9, 5, 9, 4, 9, 99, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 1002, 9, 2, 9, 4, 9, 3, 9, 1002, 9, 2, 9, 4, 9, 3, 9, 1001, 9, 1, 9, 4, 9, 3, 9, 101, 1, 9, 9, 4, 9, 3, 9, 1001, 9, 1, 9, 4, 9, 3, 9, 1002, 9, 2, 9, 4, 9, 3, 9, 1001, 9, 2, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 99, 3, 9, 1002, 9, 2, 9, 4, 9, 3, 9, 101, 2, 9, 9, 4, 9, 3, 9, 1001, 9, 1, 9, 4, 9, 3, 9, 101, 2, 9, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 1002, 9, 2, 9, 4, 9, 3, 9, 1002, 9, 2, 9, 4, 9, 3, 9, 101, 2, 9, 9, 4, 9, 3, 9, 1001, 9, 1, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 99, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 1001, 9, 2, 9, 4, 9, 3, 9, 101, 1, 9, 9, 4, 9, 3, 9, 101, 2, 9, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 1001, 9, 2, 9, 4, 9, 3, 9, 101, 1, 9, 9, 4, 9, 99, 3, 9, 1002, 9, 2, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 1001, 9, 2, 9, 4, 9, 3, 9, 101, 1, 9, 9, 4, 9, 3, 9, 1001, 9, 1, 9, 4, 9, 3, 9, 1001, 9, 2, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 101, 1, 9, 9, 4, 9, 3, 9, 1001, 9, 1, 9, 4, 9, 3, 9, 101, 2, 9, 9, 4, 9, 99, 3, 9, 101, 1, 9, 9, 4, 9, 3, 9, 1002, 9, 2, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 1002, 9, 2, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 101, 1, 9, 9, 4, 9, 3, 9, 101, 2, 9, 9, 4, 9, 3, 9, 1001, 9, 2, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 101, 2, 9, 9, 4, 9, 99];
+
+// addr and size of the translated instructions
+static INSTRS: [(usize, usize); 506] = [(0, 2), (1, 4), (2, 4), (3, 4), (21, 2), (22, 2), (23, 4), (24, 2), (25, 2), (26, 2), (27, 4), (28, 4), (29, 2), (30, 2), (31, 4), (32, 2), (33, 3), (34, 2), (35, 4), (36, 2), (37, 2), (38, 2), (39, 4), (40, 4), (41, 2), (42, 2), (43, 2), (44, 2), (45, 1), (46, 2), (47, 2), (48, 4), (49, 3), (50, 2), (51, 2), (52, 4), (53, 2), (54, 2), (55, 2), (56, 4), (57, 2), (58, 2), (59, 2), (60, 2), (61, 2), (62, 1), (63, 2), (64, 2), (65, 4), (66, 2), (67, 4), (68, 2), (69, 4), (70, 2), (71, 2), (72, 2), (73, 2), (74, 2), (75, 1), (76, 2), (77, 2), (78, 4), (79, 2), (80, 3), (81, 2), (82, 4), (83, 2), (84, 2), (85, 2), (86, 4), (87, 2), (88, 2), (89, 2), (90, 4), (91, 4), (92, 2), (93, 2), (94, 2), (95, 2), (96, 1), (97, 2), (98, 2), (99, 4), (100, 2), (101, 3), (102, 2), (103, 4), (104, 2), (105, 2), (106, 2), (107, 4), (108, 2), (109, 3), (110, 2), (111, 4), (112, 2), (113, 3), (114, 2), (115, 2), (116, 2), (117, 1), (118, 2), (119, 2), (120, 4), (121, 4), (122, 2), (123, 2), (124, 2), (125, 2), (126, 2), (127, 2), (128, 4), (129, 2), (130, 4), (131, 2), (132, 2), (133, 2), (134, 2), (135, 2), (136, 4), (137, 2), (138, 4), (139, 2), (140, 2), (141, 2), (142, 2), (143, 2), (144, 4), (145, 2), (146, 4), (147, 2), (148, 2), (149, 2), (150, 2), (151, 2), (152, 4), (153, 4), (154, 2), (155, 2), (156, 2), (157, 2), (158, 2), (159, 2), (160, 4), (161, 2), (162, 4), (163, 2), (164, 2), (165, 2), (166, 2), (167, 2), (168, 4), (169, 2), (170, 4), (171, 2), (172, 2), (173, 2), (174, 2), (175, 2), (176, 4), (177, 2), (178, 4), (179, 2), (180, 2), (181, 2), (182, 2), (183, 2), (184, 4), (185, 4), (186, 2), (187, 2), (188, 2), (189, 2), (190, 2), (191, 2), (192, 4), (193, 4), (194, 2), (195, 2), (196, 2), (197, 2), (198, 1), (199, 2), (200, 2), (201, 4), (202, 2), (203, 4), (204, 2), (205, 2), (206, 2), (207, 2), (208, 2), (209, 4), (210, 4), (211, 2), (212, 2), (213, 2), (214, 2), (215, 2), (216, 2), (217, 4), (218, 2), (219, 4), (220, 2), (221, 2), (222, 2), (223, 2), (224, 2), (225, 4), (226, 4), (227, 2), (228, 2), (229, 2), (230, 2), (231, 2), (232, 2), (233, 4), (234, 4), (235, 2), (236, 2), (237, 2), (238, 2), (239, 2), (240, 2), (241, 4), (242, 2), (243, 4), (244, 2), (245, 2), (246, 2), (247, 2), (248, 2), (249, 4), (250, 2), (251, 4), (252, 2), (253, 2), (254, 2), (255, 2), (256, 2), (257, 4), (258, 4), (259, 2), (260, 2), (261, 2), (262, 2), (263, 2), (264, 2), (265, 4), (266, 2), (267, 4), (268, 2), (269, 2), (270, 2), (271, 2), (272, 2), (273, 4), (274, 4), (275, 2), (276, 2), (277, 2), (278, 2), (279, 1), (280, 2), (281, 2), (282, 4), (283, 4), (284, 2), (285, 2), (286, 2), (287, 2), (288, 2), (289, 2), (290, 4), (291, 4), (292, 2), (293, 2), (294, 2), (295, 2), (296, 2), (297, 2), (298, 4), (299, 4), (300, 2), (301, 2), (302, 2), (303, 2), (304, 2), (305, 2), (306, 4), (307, 2), (308, 4), (309, 2), (310, 2), (311, 2), (312, 2), (313, 2), (314, 4), (315, 4), (316, 2), (317, 2), (318, 2), (319, 2), (320, 2), (321, 2), (322, 4), (323, 4), (324, 2), (325, 2), (326, 2), (327, 2), (328, 2), (329, 2), (330, 4), (331, 4), (332, 2), (333, 2), (334, 2), (335, 2), (336, 2), (337, 2), (338, 4), (339, 4), (340, 2), (341, 2), (342, 2), (343, 2), (344, 2), (345, 2), (346, 4), (347, 2), (348, 4), (349, 2), (350, 2), (351, 2), (352, 2), (353, 2), (354, 4), (355, 4), (356, 2), (357, 2), (358, 2), (359, 2), (360, 1), (361, 2), (362, 2), (363, 4), (364, 2), (365, 4), (366, 2), (367, 2), (368, 2), (369, 2), (370, 2), (371, 4), (372, 4), (373, 2), (374, 2), (375, 2), (376, 2), (377, 2), (378, 2), (379, 4), (380, 2), (381, 4), (382, 2), (383, 2), (384, 2), (385, 2), (386, 2), (387, 4), (388, 4), (389, 2), (390, 2), (391, 2), (392, 2), (393, 2), (394, 2), (395, 4), (396, 2), (397, 4), (398, 2), (399, 2), (400, 2), (401, 2), (402, 2), (403, 4), (404, 2), (405, 4), (406, 2), (407, 2), (408, 2), (409, 2), (410, 2), (411, 4), (412, 4), (413, 2), (414, 2), (415, 2), (416, 2), (417, 2), (418, 2), (419, 4), (420, 4), (421, 2), (422, 2), (423, 2), (424, 2), (425, 2), (426, 2), (427, 4), (428, 2), (429, 4), (430, 2), (431, 2), (432, 2), (433, 2), (434, 2), (435, 4), (436, 4), (437, 2), (438, 2), (439, 2), (440, 2), (441, 1), (442, 2), (443, 2), (444, 4), (445, 4), (446, 2), (447, 2), (448, 2), (449, 2), (450, 2), (451, 2), (452, 4), (453, 2), (454, 4), (455, 2), (456, 2), (457, 2), (458, 2), (459, 2), (460, 4), (461, 4), (462, 2), (463, 2), (464, 2), (465, 2), (466, 2), (467, 2), (468, 4), (469, 2), (470, 4), (471, 2), (472, 2), (473, 2), (474, 2), (475, 2), (476, 4), (477, 4), (478, 2), (479, 2), (480, 2), (481, 2), (482, 2), (483, 2), (484, 4), (485, 4), (486, 2), (487, 2), (488, 2), (489, 2), (490, 2), (491, 2), (492, 4), (493, 4), (494, 2), (495, 2), (496, 2), (497, 2), (498, 2), (499, 2), (500, 4), (501, 2), (502, 4), (503, 2), (504, 2), (505, 2), (506, 2), (507, 2), (508, 4), (509, 4), (510, 2), (511, 2), (512, 2), (513, 2), (514, 2), (515, 2), (516, 4), (517, 4), (518, 2), (519, 2), (520, 2), (521, 2), (522, 1)];
+
+#[inline(always)]
+#[allow(dead_code)]
+fn rel(rb: i64, offset: i64) -> Option<usize> {
+    match rb.checked_add(offset) {
+        Some(addr) if addr >= 0 => Some(addr as usize),
+        _ => None,
+    }
+}
+
+#[allow(unused_mut, clippy::all)]
+fn native(program: &mut intcode::Program, guard: &mut intcode::CodeGuard) -> intcode::Native {
+    use intcode::{Native, State};
+    let mut ip = program.ip();
+    let mut rb = program.rel_base();
+    let native = loop {
+        match ip {
+            0 => {
+                if guard.is_modified(0) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 8;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2;
+            }
+            1 => {
+                if guard.is_modified(1) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1001];
+                let rhs: i64 = program.mem()[8];
+                let addr = 10;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 5;
+            }
+            2 => {
+                if guard.is_modified(2) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[8];
+                let rhs: i64 = 10;
+                let addr = 8;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 6;
+            }
+            3 => {
+                if guard.is_modified(3) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[8];
+                let addr = 105;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 7;
+            }
+            21 => {
+                if guard.is_modified(21) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 23;
+            }
+            22 => {
+                if guard.is_modified(22) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[102];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 24;
+            }
+            23 => {
+                if guard.is_modified(23) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 4;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 27;
+            }
+            24 => {
+                if guard.is_modified(24) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 26;
+                break Native::Paused(State::Output(val));
+            }
+            25 => {
+                if guard.is_modified(25) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 27;
+            }
+            26 => {
+                if guard.is_modified(26) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 28;
+            }
+            27 => {
+                if guard.is_modified(27) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 31;
+            }
+            28 => {
+                if guard.is_modified(28) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 1002;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 32;
+            }
+            29 => {
+                if guard.is_modified(29) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 31;
+            }
+            30 => {
+                if guard.is_modified(30) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1002];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 32;
+            }
+            31 => {
+                if guard.is_modified(31) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 5;
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 35;
+            }
+            32 => {
+                if guard.is_modified(32) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[5];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 34;
+            }
+            33 => {
+                if guard.is_modified(33) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                let target: i64 = program.mem()[101];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 36 };
+            }
+            34 => {
+                if guard.is_modified(34) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 36;
+            }
+            35 => {
+                if guard.is_modified(35) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 4;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 39;
+            }
+            36 => {
+                if guard.is_modified(36) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 38;
+                break Native::Paused(State::Output(val));
+            }
+            37 => {
+                if guard.is_modified(37) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 39;
+            }
+            38 => {
+                if guard.is_modified(38) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[102];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 40;
+            }
+            39 => {
+                if guard.is_modified(39) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 43;
+            }
+            40 => {
+                if guard.is_modified(40) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 44;
+            }
+            41 => {
+                if guard.is_modified(41) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 43;
+            }
+            42 => {
+                if guard.is_modified(42) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 44;
+            }
+            43 => {
+                if guard.is_modified(43) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 45;
+                break Native::Paused(State::Output(val));
+            }
+            44 => {
+                if guard.is_modified(44) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[99];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 46;
+            }
+            45 => {
+                if guard.is_modified(45) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            46 => {
+                if guard.is_modified(46) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 48;
+            }
+            47 => {
+                if guard.is_modified(47) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 49;
+            }
+            48 => {
+                if guard.is_modified(48) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 5;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 52;
+            }
+            49 => {
+                if guard.is_modified(49) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                let target: i64 = program.mem()[9];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 52 };
+            }
+            50 => {
+                if guard.is_modified(50) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 52;
+            }
+            51 => {
+                if guard.is_modified(51) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[102];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 53;
+            }
+            52 => {
+                if guard.is_modified(52) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 3;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 56;
+            }
+            53 => {
+                if guard.is_modified(53) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 55;
+            }
+            54 => {
+                if guard.is_modified(54) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 56;
+            }
+            55 => {
+                if guard.is_modified(55) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 57;
+            }
+            56 => {
+                if guard.is_modified(56) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 3;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 60;
+            }
+            57 => {
+                if guard.is_modified(57) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 59;
+            }
+            58 => {
+                if guard.is_modified(58) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 60;
+            }
+            59 => {
+                if guard.is_modified(59) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 61;
+            }
+            60 => {
+                if guard.is_modified(60) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 62;
+                break Native::Paused(State::Output(val));
+            }
+            61 => {
+                if guard.is_modified(61) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[99];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 63;
+            }
+            62 => {
+                if guard.is_modified(62) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            63 => {
+                if guard.is_modified(63) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 65;
+            }
+            64 => {
+                if guard.is_modified(64) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1001];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 66;
+            }
+            65 => {
+                if guard.is_modified(65) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 2;
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 69;
+            }
+            66 => {
+                if guard.is_modified(66) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[2];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 68;
+            }
+            67 => {
+                if guard.is_modified(67) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[102];
+                let addr = 3;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 71;
+            }
+            68 => {
+                if guard.is_modified(68) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[102];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 70;
+            }
+            69 => {
+                if guard.is_modified(69) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 3;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 73;
+            }
+            70 => {
+                if guard.is_modified(70) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 72;
+            }
+            71 => {
+                if guard.is_modified(71) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 73;
+            }
+            72 => {
+                if guard.is_modified(72) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 74;
+            }
+            73 => {
+                if guard.is_modified(73) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 75;
+                break Native::Paused(State::Output(val));
+            }
+            74 => {
+                if guard.is_modified(74) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[99];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 76;
+            }
+            75 => {
+                if guard.is_modified(75) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            76 => {
+                if guard.is_modified(76) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 78;
+            }
+            77 => {
+                if guard.is_modified(77) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1002];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 79;
+            }
+            78 => {
+                if guard.is_modified(78) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 5;
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 82;
+            }
+            79 => {
+                if guard.is_modified(79) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[5];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 81;
+            }
+            80 => {
+                if guard.is_modified(80) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                let target: i64 = program.mem()[101];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 83 };
+            }
+            81 => {
+                if guard.is_modified(81) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 83;
+            }
+            82 => {
+                if guard.is_modified(82) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 4;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 86;
+            }
+            83 => {
+                if guard.is_modified(83) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 85;
+                break Native::Paused(State::Output(val));
+            }
+            84 => {
+                if guard.is_modified(84) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 86;
+            }
+            85 => {
+                if guard.is_modified(85) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1002];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 87;
+            }
+            86 => {
+                if guard.is_modified(86) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 3;
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 90;
+            }
+            87 => {
+                if guard.is_modified(87) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 89;
+            }
+            88 => {
+                if guard.is_modified(88) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 90;
+            }
+            89 => {
+                if guard.is_modified(89) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 91;
+            }
+            90 => {
+                if guard.is_modified(90) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 94;
+            }
+            91 => {
+                if guard.is_modified(91) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 95;
+            }
+            92 => {
+                if guard.is_modified(92) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 94;
+            }
+            93 => {
+                if guard.is_modified(93) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 95;
+            }
+            94 => {
+                if guard.is_modified(94) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 96;
+                break Native::Paused(State::Output(val));
+            }
+            95 => {
+                if guard.is_modified(95) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[99];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 97;
+            }
+            96 => {
+                if guard.is_modified(96) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            97 => {
+                if guard.is_modified(97) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 99;
+            }
+            98 => {
+                if guard.is_modified(98) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1002];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 100;
+            }
+            99 => {
+                if guard.is_modified(99) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 5;
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 103;
+            }
+            100 => {
+                if guard.is_modified(100) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[5];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 102;
+            }
+            101 => {
+                if guard.is_modified(101) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                let target: i64 = program.mem()[101];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 104 };
+            }
+            102 => {
+                if guard.is_modified(102) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 104;
+            }
+            103 => {
+                if guard.is_modified(103) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 3;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 107;
+            }
+            104 => {
+                if guard.is_modified(104) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 106;
+            }
+            105 => {
+                if guard.is_modified(105) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 107;
+            }
+            106 => {
+                if guard.is_modified(106) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1002];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 108;
+            }
+            107 => {
+                if guard.is_modified(107) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 5;
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 111;
+            }
+            108 => {
+                if guard.is_modified(108) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[5];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 110;
+            }
+            109 => {
+                if guard.is_modified(109) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                let target: i64 = program.mem()[1001];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 112 };
+            }
+            110 => {
+                if guard.is_modified(110) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1001];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 112;
+            }
+            111 => {
+                if guard.is_modified(111) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 5;
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 115;
+            }
+            112 => {
+                if guard.is_modified(112) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[5];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 114;
+            }
+            113 => {
+                if guard.is_modified(113) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                let target: i64 = program.mem()[4];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 116 };
+            }
+            114 => {
+                if guard.is_modified(114) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 116;
+            }
+            115 => {
+                if guard.is_modified(115) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 117;
+                break Native::Paused(State::Output(val));
+            }
+            116 => {
+                if guard.is_modified(116) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[99];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 118;
+            }
+            117 => {
+                if guard.is_modified(117) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            118 => {
+                if guard.is_modified(118) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 120;
+            }
+            119 => {
+                if guard.is_modified(119) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[102];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 121;
+            }
+            120 => {
+                if guard.is_modified(120) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 124;
+            }
+            121 => {
+                if guard.is_modified(121) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 125;
+            }
+            122 => {
+                if guard.is_modified(122) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 124;
+            }
+            123 => {
+                if guard.is_modified(123) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 125;
+            }
+            124 => {
+                if guard.is_modified(124) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 126;
+                break Native::Paused(State::Output(val));
+            }
+            125 => {
+                if guard.is_modified(125) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 127;
+            }
+            126 => {
+                if guard.is_modified(126) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 128;
+            }
+            127 => {
+                if guard.is_modified(127) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1002];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 129;
+            }
+            128 => {
+                if guard.is_modified(128) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 2;
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 132;
+            }
+            129 => {
+                if guard.is_modified(129) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[2];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 131;
+            }
+            130 => {
+                if guard.is_modified(130) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 134;
+            }
+            131 => {
+                if guard.is_modified(131) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 133;
+            }
+            132 => {
+                if guard.is_modified(132) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 134;
+                break Native::Paused(State::Output(val));
+            }
+            133 => {
+                if guard.is_modified(133) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 135;
+            }
+            134 => {
+                if guard.is_modified(134) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 136;
+            }
+            135 => {
+                if guard.is_modified(135) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1002];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 137;
+            }
+            136 => {
+                if guard.is_modified(136) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 2;
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 140;
+            }
+            137 => {
+                if guard.is_modified(137) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[2];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 139;
+            }
+            138 => {
+                if guard.is_modified(138) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 142;
+            }
+            139 => {
+                if guard.is_modified(139) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 141;
+            }
+            140 => {
+                if guard.is_modified(140) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 142;
+                break Native::Paused(State::Output(val));
+            }
+            141 => {
+                if guard.is_modified(141) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 143;
+            }
+            142 => {
+                if guard.is_modified(142) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 144;
+            }
+            143 => {
+                if guard.is_modified(143) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1001];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 145;
+            }
+            144 => {
+                if guard.is_modified(144) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 1;
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 148;
+            }
+            145 => {
+                if guard.is_modified(145) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 147;
+            }
+            146 => {
+                if guard.is_modified(146) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 150;
+            }
+            147 => {
+                if guard.is_modified(147) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 149;
+            }
+            148 => {
+                if guard.is_modified(148) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 150;
+                break Native::Paused(State::Output(val));
+            }
+            149 => {
+                if guard.is_modified(149) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 151;
+            }
+            150 => {
+                if guard.is_modified(150) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 152;
+            }
+            151 => {
+                if guard.is_modified(151) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 153;
+            }
+            152 => {
+                if guard.is_modified(152) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 156;
+            }
+            153 => {
+                if guard.is_modified(153) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 157;
+            }
+            154 => {
+                if guard.is_modified(154) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 156;
+            }
+            155 => {
+                if guard.is_modified(155) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 157;
+            }
+            156 => {
+                if guard.is_modified(156) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 158;
+                break Native::Paused(State::Output(val));
+            }
+            157 => {
+                if guard.is_modified(157) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 159;
+            }
+            158 => {
+                if guard.is_modified(158) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 160;
+            }
+            159 => {
+                if guard.is_modified(159) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1001];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 161;
+            }
+            160 => {
+                if guard.is_modified(160) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 1;
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 164;
+            }
+            161 => {
+                if guard.is_modified(161) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 163;
+            }
+            162 => {
+                if guard.is_modified(162) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 166;
+            }
+            163 => {
+                if guard.is_modified(163) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 165;
+            }
+            164 => {
+                if guard.is_modified(164) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 166;
+                break Native::Paused(State::Output(val));
+            }
+            165 => {
+                if guard.is_modified(165) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 167;
+            }
+            166 => {
+                if guard.is_modified(166) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 168;
+            }
+            167 => {
+                if guard.is_modified(167) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1002];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 169;
+            }
+            168 => {
+                if guard.is_modified(168) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 2;
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 172;
+            }
+            169 => {
+                if guard.is_modified(169) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[2];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 171;
+            }
+            170 => {
+                if guard.is_modified(170) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 174;
+            }
+            171 => {
+                if guard.is_modified(171) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 173;
+            }
+            172 => {
+                if guard.is_modified(172) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 174;
+                break Native::Paused(State::Output(val));
+            }
+            173 => {
+                if guard.is_modified(173) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 175;
+            }
+            174 => {
+                if guard.is_modified(174) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 176;
+            }
+            175 => {
+                if guard.is_modified(175) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1001];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 177;
+            }
+            176 => {
+                if guard.is_modified(176) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 2;
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 180;
+            }
+            177 => {
+                if guard.is_modified(177) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[2];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 179;
+            }
+            178 => {
+                if guard.is_modified(178) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 182;
+            }
+            179 => {
+                if guard.is_modified(179) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 181;
+            }
+            180 => {
+                if guard.is_modified(180) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 182;
+                break Native::Paused(State::Output(val));
+            }
+            181 => {
+                if guard.is_modified(181) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 183;
+            }
+            182 => {
+                if guard.is_modified(182) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 184;
+            }
+            183 => {
+                if guard.is_modified(183) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[102];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 185;
+            }
+            184 => {
+                if guard.is_modified(184) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 188;
+            }
+            185 => {
+                if guard.is_modified(185) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 189;
+            }
+            186 => {
+                if guard.is_modified(186) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 188;
+            }
+            187 => {
+                if guard.is_modified(187) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 189;
+            }
+            188 => {
+                if guard.is_modified(188) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 190;
+                break Native::Paused(State::Output(val));
+            }
+            189 => {
+                if guard.is_modified(189) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 191;
+            }
+            190 => {
+                if guard.is_modified(190) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 192;
+            }
+            191 => {
+                if guard.is_modified(191) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[102];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 193;
+            }
+            192 => {
+                if guard.is_modified(192) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 196;
+            }
+            193 => {
+                if guard.is_modified(193) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 197;
+            }
+            194 => {
+                if guard.is_modified(194) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 196;
+            }
+            195 => {
+                if guard.is_modified(195) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 197;
+            }
+            196 => {
+                if guard.is_modified(196) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 198;
+                break Native::Paused(State::Output(val));
+            }
+            197 => {
+                if guard.is_modified(197) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[99];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 199;
+            }
+            198 => {
+                if guard.is_modified(198) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            199 => {
+                if guard.is_modified(199) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 201;
+            }
+            200 => {
+                if guard.is_modified(200) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1002];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 202;
+            }
+            201 => {
+                if guard.is_modified(201) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 2;
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 205;
+            }
+            202 => {
+                if guard.is_modified(202) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[2];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 204;
+            }
+            203 => {
+                if guard.is_modified(203) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 207;
+            }
+            204 => {
+                if guard.is_modified(204) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 206;
+            }
+            205 => {
+                if guard.is_modified(205) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 207;
+                break Native::Paused(State::Output(val));
+            }
+            206 => {
+                if guard.is_modified(206) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 208;
+            }
+            207 => {
+                if guard.is_modified(207) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 209;
+            }
+            208 => {
+                if guard.is_modified(208) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 210;
+            }
+            209 => {
+                if guard.is_modified(209) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 213;
+            }
+            210 => {
+                if guard.is_modified(210) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 214;
+            }
+            211 => {
+                if guard.is_modified(211) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 213;
+            }
+            212 => {
+                if guard.is_modified(212) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 214;
+            }
+            213 => {
+                if guard.is_modified(213) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 215;
+                break Native::Paused(State::Output(val));
+            }
+            214 => {
+                if guard.is_modified(214) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 216;
+            }
+            215 => {
+                if guard.is_modified(215) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 217;
+            }
+            216 => {
+                if guard.is_modified(216) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1001];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 218;
+            }
+            217 => {
+                if guard.is_modified(217) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 1;
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 221;
+            }
+            218 => {
+                if guard.is_modified(218) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 220;
+            }
+            219 => {
+                if guard.is_modified(219) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 223;
+            }
+            220 => {
+                if guard.is_modified(220) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 222;
+            }
+            221 => {
+                if guard.is_modified(221) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 223;
+                break Native::Paused(State::Output(val));
+            }
+            222 => {
+                if guard.is_modified(222) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 224;
+            }
+            223 => {
+                if guard.is_modified(223) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 225;
+            }
+            224 => {
+                if guard.is_modified(224) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 226;
+            }
+            225 => {
+                if guard.is_modified(225) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 229;
+            }
+            226 => {
+                if guard.is_modified(226) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 230;
+            }
+            227 => {
+                if guard.is_modified(227) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 229;
+            }
+            228 => {
+                if guard.is_modified(228) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 230;
+            }
+            229 => {
+                if guard.is_modified(229) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 231;
+                break Native::Paused(State::Output(val));
+            }
+            230 => {
+                if guard.is_modified(230) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 232;
+            }
+            231 => {
+                if guard.is_modified(231) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 233;
+            }
+            232 => {
+                if guard.is_modified(232) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[102];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 234;
+            }
+            233 => {
+                if guard.is_modified(233) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 237;
+            }
+            234 => {
+                if guard.is_modified(234) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 238;
+            }
+            235 => {
+                if guard.is_modified(235) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 237;
+            }
+            236 => {
+                if guard.is_modified(236) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 238;
+            }
+            237 => {
+                if guard.is_modified(237) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 239;
+                break Native::Paused(State::Output(val));
+            }
+            238 => {
+                if guard.is_modified(238) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 240;
+            }
+            239 => {
+                if guard.is_modified(239) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 241;
+            }
+            240 => {
+                if guard.is_modified(240) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1002];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 242;
+            }
+            241 => {
+                if guard.is_modified(241) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 2;
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 245;
+            }
+            242 => {
+                if guard.is_modified(242) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[2];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 244;
+            }
+            243 => {
+                if guard.is_modified(243) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 247;
+            }
+            244 => {
+                if guard.is_modified(244) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 246;
+            }
+            245 => {
+                if guard.is_modified(245) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 247;
+                break Native::Paused(State::Output(val));
+            }
+            246 => {
+                if guard.is_modified(246) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 248;
+            }
+            247 => {
+                if guard.is_modified(247) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 249;
+            }
+            248 => {
+                if guard.is_modified(248) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1002];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 250;
+            }
+            249 => {
+                if guard.is_modified(249) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 2;
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 253;
+            }
+            250 => {
+                if guard.is_modified(250) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[2];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 252;
+            }
+            251 => {
+                if guard.is_modified(251) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 255;
+            }
+            252 => {
+                if guard.is_modified(252) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 254;
+            }
+            253 => {
+                if guard.is_modified(253) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 255;
+                break Native::Paused(State::Output(val));
+            }
+            254 => {
+                if guard.is_modified(254) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 256;
+            }
+            255 => {
+                if guard.is_modified(255) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 257;
+            }
+            256 => {
+                if guard.is_modified(256) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 258;
+            }
+            257 => {
+                if guard.is_modified(257) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 261;
+            }
+            258 => {
+                if guard.is_modified(258) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 262;
+            }
+            259 => {
+                if guard.is_modified(259) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 261;
+            }
+            260 => {
+                if guard.is_modified(260) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 262;
+            }
+            261 => {
+                if guard.is_modified(261) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 263;
+                break Native::Paused(State::Output(val));
+            }
+            262 => {
+                if guard.is_modified(262) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 264;
+            }
+            263 => {
+                if guard.is_modified(263) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 265;
+            }
+            264 => {
+                if guard.is_modified(264) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1001];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 266;
+            }
+            265 => {
+                if guard.is_modified(265) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 1;
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 269;
+            }
+            266 => {
+                if guard.is_modified(266) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 268;
+            }
+            267 => {
+                if guard.is_modified(267) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 271;
+            }
+            268 => {
+                if guard.is_modified(268) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 270;
+            }
+            269 => {
+                if guard.is_modified(269) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 271;
+                break Native::Paused(State::Output(val));
+            }
+            270 => {
+                if guard.is_modified(270) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 272;
+            }
+            271 => {
+                if guard.is_modified(271) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 273;
+            }
+            272 => {
+                if guard.is_modified(272) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[102];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 274;
+            }
+            273 => {
+                if guard.is_modified(273) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 277;
+            }
+            274 => {
+                if guard.is_modified(274) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 278;
+            }
+            275 => {
+                if guard.is_modified(275) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 277;
+            }
+            276 => {
+                if guard.is_modified(276) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 278;
+            }
+            277 => {
+                if guard.is_modified(277) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 279;
+                break Native::Paused(State::Output(val));
+            }
+            278 => {
+                if guard.is_modified(278) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[99];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 280;
+            }
+            279 => {
+                if guard.is_modified(279) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            280 => {
+                if guard.is_modified(280) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 282;
+            }
+            281 => {
+                if guard.is_modified(281) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[102];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 283;
+            }
+            282 => {
+                if guard.is_modified(282) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 286;
+            }
+            283 => {
+                if guard.is_modified(283) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 287;
+            }
+            284 => {
+                if guard.is_modified(284) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 286;
+            }
+            285 => {
+                if guard.is_modified(285) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 287;
+            }
+            286 => {
+                if guard.is_modified(286) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 288;
+                break Native::Paused(State::Output(val));
+            }
+            287 => {
+                if guard.is_modified(287) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 289;
+            }
+            288 => {
+                if guard.is_modified(288) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 290;
+            }
+            289 => {
+                if guard.is_modified(289) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[102];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 291;
+            }
+            290 => {
+                if guard.is_modified(290) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 294;
+            }
+            291 => {
+                if guard.is_modified(291) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 295;
+            }
+            292 => {
+                if guard.is_modified(292) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 294;
+            }
+            293 => {
+                if guard.is_modified(293) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 295;
+            }
+            294 => {
+                if guard.is_modified(294) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 296;
+                break Native::Paused(State::Output(val));
+            }
+            295 => {
+                if guard.is_modified(295) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 297;
+            }
+            296 => {
+                if guard.is_modified(296) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 298;
+            }
+            297 => {
+                if guard.is_modified(297) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[102];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 299;
+            }
+            298 => {
+                if guard.is_modified(298) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 302;
+            }
+            299 => {
+                if guard.is_modified(299) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 303;
+            }
+            300 => {
+                if guard.is_modified(300) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 302;
+            }
+            301 => {
+                if guard.is_modified(301) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 303;
+            }
+            302 => {
+                if guard.is_modified(302) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 304;
+                break Native::Paused(State::Output(val));
+            }
+            303 => {
+                if guard.is_modified(303) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 305;
+            }
+            304 => {
+                if guard.is_modified(304) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 306;
+            }
+            305 => {
+                if guard.is_modified(305) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1001];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 307;
+            }
+            306 => {
+                if guard.is_modified(306) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 2;
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 310;
+            }
+            307 => {
+                if guard.is_modified(307) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[2];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 309;
+            }
+            308 => {
+                if guard.is_modified(308) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 312;
+            }
+            309 => {
+                if guard.is_modified(309) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 311;
+            }
+            310 => {
+                if guard.is_modified(310) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 312;
+                break Native::Paused(State::Output(val));
+            }
+            311 => {
+                if guard.is_modified(311) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 313;
+            }
+            312 => {
+                if guard.is_modified(312) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 314;
+            }
+            313 => {
+                if guard.is_modified(313) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 315;
+            }
+            314 => {
+                if guard.is_modified(314) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 318;
+            }
+            315 => {
+                if guard.is_modified(315) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 319;
+            }
+            316 => {
+                if guard.is_modified(316) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 318;
+            }
+            317 => {
+                if guard.is_modified(317) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 319;
+            }
+            318 => {
+                if guard.is_modified(318) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 320;
+                break Native::Paused(State::Output(val));
+            }
+            319 => {
+                if guard.is_modified(319) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 321;
+            }
+            320 => {
+                if guard.is_modified(320) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 322;
+            }
+            321 => {
+                if guard.is_modified(321) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 323;
+            }
+            322 => {
+                if guard.is_modified(322) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 326;
+            }
+            323 => {
+                if guard.is_modified(323) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 327;
+            }
+            324 => {
+                if guard.is_modified(324) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 326;
+            }
+            325 => {
+                if guard.is_modified(325) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 327;
+            }
+            326 => {
+                if guard.is_modified(326) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 328;
+                break Native::Paused(State::Output(val));
+            }
+            327 => {
+                if guard.is_modified(327) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 329;
+            }
+            328 => {
+                if guard.is_modified(328) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 330;
+            }
+            329 => {
+                if guard.is_modified(329) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[102];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 331;
+            }
+            330 => {
+                if guard.is_modified(330) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 334;
+            }
+            331 => {
+                if guard.is_modified(331) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 335;
+            }
+            332 => {
+                if guard.is_modified(332) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 334;
+            }
+            333 => {
+                if guard.is_modified(333) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 335;
+            }
+            334 => {
+                if guard.is_modified(334) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 336;
+                break Native::Paused(State::Output(val));
+            }
+            335 => {
+                if guard.is_modified(335) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 337;
+            }
+            336 => {
+                if guard.is_modified(336) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 338;
+            }
+            337 => {
+                if guard.is_modified(337) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[102];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 339;
+            }
+            338 => {
+                if guard.is_modified(338) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 342;
+            }
+            339 => {
+                if guard.is_modified(339) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 343;
+            }
+            340 => {
+                if guard.is_modified(340) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 342;
+            }
+            341 => {
+                if guard.is_modified(341) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 343;
+            }
+            342 => {
+                if guard.is_modified(342) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 344;
+                break Native::Paused(State::Output(val));
+            }
+            343 => {
+                if guard.is_modified(343) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 345;
+            }
+            344 => {
+                if guard.is_modified(344) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 346;
+            }
+            345 => {
+                if guard.is_modified(345) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1001];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 347;
+            }
+            346 => {
+                if guard.is_modified(346) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 2;
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 350;
+            }
+            347 => {
+                if guard.is_modified(347) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[2];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 349;
+            }
+            348 => {
+                if guard.is_modified(348) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 352;
+            }
+            349 => {
+                if guard.is_modified(349) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 351;
+            }
+            350 => {
+                if guard.is_modified(350) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 352;
+                break Native::Paused(State::Output(val));
+            }
+            351 => {
+                if guard.is_modified(351) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 353;
+            }
+            352 => {
+                if guard.is_modified(352) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 354;
+            }
+            353 => {
+                if guard.is_modified(353) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 355;
+            }
+            354 => {
+                if guard.is_modified(354) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 358;
+            }
+            355 => {
+                if guard.is_modified(355) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 359;
+            }
+            356 => {
+                if guard.is_modified(356) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 358;
+            }
+            357 => {
+                if guard.is_modified(357) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 359;
+            }
+            358 => {
+                if guard.is_modified(358) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 360;
+                break Native::Paused(State::Output(val));
+            }
+            359 => {
+                if guard.is_modified(359) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[99];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 361;
+            }
+            360 => {
+                if guard.is_modified(360) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            361 => {
+                if guard.is_modified(361) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 363;
+            }
+            362 => {
+                if guard.is_modified(362) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1002];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 364;
+            }
+            363 => {
+                if guard.is_modified(363) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 2;
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 367;
+            }
+            364 => {
+                if guard.is_modified(364) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[2];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 366;
+            }
+            365 => {
+                if guard.is_modified(365) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 369;
+            }
+            366 => {
+                if guard.is_modified(366) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 368;
+            }
+            367 => {
+                if guard.is_modified(367) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 369;
+                break Native::Paused(State::Output(val));
+            }
+            368 => {
+                if guard.is_modified(368) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 370;
+            }
+            369 => {
+                if guard.is_modified(369) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 371;
+            }
+            370 => {
+                if guard.is_modified(370) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[102];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 372;
+            }
+            371 => {
+                if guard.is_modified(371) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 375;
+            }
+            372 => {
+                if guard.is_modified(372) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 376;
+            }
+            373 => {
+                if guard.is_modified(373) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 375;
+            }
+            374 => {
+                if guard.is_modified(374) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 376;
+            }
+            375 => {
+                if guard.is_modified(375) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 377;
+                break Native::Paused(State::Output(val));
+            }
+            376 => {
+                if guard.is_modified(376) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 378;
+            }
+            377 => {
+                if guard.is_modified(377) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 379;
+            }
+            378 => {
+                if guard.is_modified(378) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1001];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 380;
+            }
+            379 => {
+                if guard.is_modified(379) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 2;
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 383;
+            }
+            380 => {
+                if guard.is_modified(380) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[2];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 382;
+            }
+            381 => {
+                if guard.is_modified(381) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 385;
+            }
+            382 => {
+                if guard.is_modified(382) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 384;
+            }
+            383 => {
+                if guard.is_modified(383) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 385;
+                break Native::Paused(State::Output(val));
+            }
+            384 => {
+                if guard.is_modified(384) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 386;
+            }
+            385 => {
+                if guard.is_modified(385) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 387;
+            }
+            386 => {
+                if guard.is_modified(386) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 388;
+            }
+            387 => {
+                if guard.is_modified(387) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 391;
+            }
+            388 => {
+                if guard.is_modified(388) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 392;
+            }
+            389 => {
+                if guard.is_modified(389) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 391;
+            }
+            390 => {
+                if guard.is_modified(390) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 392;
+            }
+            391 => {
+                if guard.is_modified(391) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 393;
+                break Native::Paused(State::Output(val));
+            }
+            392 => {
+                if guard.is_modified(392) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 394;
+            }
+            393 => {
+                if guard.is_modified(393) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 395;
+            }
+            394 => {
+                if guard.is_modified(394) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1001];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 396;
+            }
+            395 => {
+                if guard.is_modified(395) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 1;
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 399;
+            }
+            396 => {
+                if guard.is_modified(396) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 398;
+            }
+            397 => {
+                if guard.is_modified(397) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 401;
+            }
+            398 => {
+                if guard.is_modified(398) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 400;
+            }
+            399 => {
+                if guard.is_modified(399) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 401;
+                break Native::Paused(State::Output(val));
+            }
+            400 => {
+                if guard.is_modified(400) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 402;
+            }
+            401 => {
+                if guard.is_modified(401) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 403;
+            }
+            402 => {
+                if guard.is_modified(402) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1001];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 404;
+            }
+            403 => {
+                if guard.is_modified(403) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 2;
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 407;
+            }
+            404 => {
+                if guard.is_modified(404) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[2];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 406;
+            }
+            405 => {
+                if guard.is_modified(405) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 409;
+            }
+            406 => {
+                if guard.is_modified(406) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 408;
+            }
+            407 => {
+                if guard.is_modified(407) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 409;
+                break Native::Paused(State::Output(val));
+            }
+            408 => {
+                if guard.is_modified(408) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 410;
+            }
+            409 => {
+                if guard.is_modified(409) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 411;
+            }
+            410 => {
+                if guard.is_modified(410) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[102];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 412;
+            }
+            411 => {
+                if guard.is_modified(411) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 415;
+            }
+            412 => {
+                if guard.is_modified(412) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 416;
+            }
+            413 => {
+                if guard.is_modified(413) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 415;
+            }
+            414 => {
+                if guard.is_modified(414) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 416;
+            }
+            415 => {
+                if guard.is_modified(415) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 417;
+                break Native::Paused(State::Output(val));
+            }
+            416 => {
+                if guard.is_modified(416) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 418;
+            }
+            417 => {
+                if guard.is_modified(417) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 419;
+            }
+            418 => {
+                if guard.is_modified(418) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 420;
+            }
+            419 => {
+                if guard.is_modified(419) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 423;
+            }
+            420 => {
+                if guard.is_modified(420) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 424;
+            }
+            421 => {
+                if guard.is_modified(421) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 423;
+            }
+            422 => {
+                if guard.is_modified(422) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 424;
+            }
+            423 => {
+                if guard.is_modified(423) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 425;
+                break Native::Paused(State::Output(val));
+            }
+            424 => {
+                if guard.is_modified(424) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 426;
+            }
+            425 => {
+                if guard.is_modified(425) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 427;
+            }
+            426 => {
+                if guard.is_modified(426) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1001];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 428;
+            }
+            427 => {
+                if guard.is_modified(427) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 1;
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 431;
+            }
+            428 => {
+                if guard.is_modified(428) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 430;
+            }
+            429 => {
+                if guard.is_modified(429) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 433;
+            }
+            430 => {
+                if guard.is_modified(430) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 432;
+            }
+            431 => {
+                if guard.is_modified(431) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 433;
+                break Native::Paused(State::Output(val));
+            }
+            432 => {
+                if guard.is_modified(432) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 434;
+            }
+            433 => {
+                if guard.is_modified(433) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 435;
+            }
+            434 => {
+                if guard.is_modified(434) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 436;
+            }
+            435 => {
+                if guard.is_modified(435) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 439;
+            }
+            436 => {
+                if guard.is_modified(436) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 440;
+            }
+            437 => {
+                if guard.is_modified(437) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 439;
+            }
+            438 => {
+                if guard.is_modified(438) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 440;
+            }
+            439 => {
+                if guard.is_modified(439) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 441;
+                break Native::Paused(State::Output(val));
+            }
+            440 => {
+                if guard.is_modified(440) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[99];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 442;
+            }
+            441 => {
+                if guard.is_modified(441) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            442 => {
+                if guard.is_modified(442) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 444;
+            }
+            443 => {
+                if guard.is_modified(443) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 445;
+            }
+            444 => {
+                if guard.is_modified(444) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 448;
+            }
+            445 => {
+                if guard.is_modified(445) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 449;
+            }
+            446 => {
+                if guard.is_modified(446) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 448;
+            }
+            447 => {
+                if guard.is_modified(447) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 449;
+            }
+            448 => {
+                if guard.is_modified(448) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 450;
+                break Native::Paused(State::Output(val));
+            }
+            449 => {
+                if guard.is_modified(449) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 451;
+            }
+            450 => {
+                if guard.is_modified(450) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 452;
+            }
+            451 => {
+                if guard.is_modified(451) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1002];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 453;
+            }
+            452 => {
+                if guard.is_modified(452) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 2;
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 456;
+            }
+            453 => {
+                if guard.is_modified(453) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[2];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 455;
+            }
+            454 => {
+                if guard.is_modified(454) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 458;
+            }
+            455 => {
+                if guard.is_modified(455) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 457;
+            }
+            456 => {
+                if guard.is_modified(456) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 458;
+                break Native::Paused(State::Output(val));
+            }
+            457 => {
+                if guard.is_modified(457) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 459;
+            }
+            458 => {
+                if guard.is_modified(458) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 460;
+            }
+            459 => {
+                if guard.is_modified(459) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[102];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 461;
+            }
+            460 => {
+                if guard.is_modified(460) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 464;
+            }
+            461 => {
+                if guard.is_modified(461) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 465;
+            }
+            462 => {
+                if guard.is_modified(462) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 464;
+            }
+            463 => {
+                if guard.is_modified(463) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 465;
+            }
+            464 => {
+                if guard.is_modified(464) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 466;
+                break Native::Paused(State::Output(val));
+            }
+            465 => {
+                if guard.is_modified(465) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 467;
+            }
+            466 => {
+                if guard.is_modified(466) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 468;
+            }
+            467 => {
+                if guard.is_modified(467) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1002];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 469;
+            }
+            468 => {
+                if guard.is_modified(468) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 2;
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 472;
+            }
+            469 => {
+                if guard.is_modified(469) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[2];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 471;
+            }
+            470 => {
+                if guard.is_modified(470) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 474;
+            }
+            471 => {
+                if guard.is_modified(471) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 473;
+            }
+            472 => {
+                if guard.is_modified(472) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 474;
+                break Native::Paused(State::Output(val));
+            }
+            473 => {
+                if guard.is_modified(473) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 475;
+            }
+            474 => {
+                if guard.is_modified(474) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 476;
+            }
+            475 => {
+                if guard.is_modified(475) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[102];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 477;
+            }
+            476 => {
+                if guard.is_modified(476) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 480;
+            }
+            477 => {
+                if guard.is_modified(477) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 481;
+            }
+            478 => {
+                if guard.is_modified(478) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 480;
+            }
+            479 => {
+                if guard.is_modified(479) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 481;
+            }
+            480 => {
+                if guard.is_modified(480) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 482;
+                break Native::Paused(State::Output(val));
+            }
+            481 => {
+                if guard.is_modified(481) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 483;
+            }
+            482 => {
+                if guard.is_modified(482) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 484;
+            }
+            483 => {
+                if guard.is_modified(483) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 485;
+            }
+            484 => {
+                if guard.is_modified(484) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 488;
+            }
+            485 => {
+                if guard.is_modified(485) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 489;
+            }
+            486 => {
+                if guard.is_modified(486) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 488;
+            }
+            487 => {
+                if guard.is_modified(487) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 489;
+            }
+            488 => {
+                if guard.is_modified(488) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 490;
+                break Native::Paused(State::Output(val));
+            }
+            489 => {
+                if guard.is_modified(489) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 491;
+            }
+            490 => {
+                if guard.is_modified(490) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 492;
+            }
+            491 => {
+                if guard.is_modified(491) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 493;
+            }
+            492 => {
+                if guard.is_modified(492) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 496;
+            }
+            493 => {
+                if guard.is_modified(493) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 497;
+            }
+            494 => {
+                if guard.is_modified(494) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 496;
+            }
+            495 => {
+                if guard.is_modified(495) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 497;
+            }
+            496 => {
+                if guard.is_modified(496) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 498;
+                break Native::Paused(State::Output(val));
+            }
+            497 => {
+                if guard.is_modified(497) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 499;
+            }
+            498 => {
+                if guard.is_modified(498) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 500;
+            }
+            499 => {
+                if guard.is_modified(499) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1001];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 501;
+            }
+            500 => {
+                if guard.is_modified(500) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 2;
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 504;
+            }
+            501 => {
+                if guard.is_modified(501) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[2];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 503;
+            }
+            502 => {
+                if guard.is_modified(502) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[4];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 506;
+            }
+            503 => {
+                if guard.is_modified(503) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 505;
+            }
+            504 => {
+                if guard.is_modified(504) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 506;
+                break Native::Paused(State::Output(val));
+            }
+            505 => {
+                if guard.is_modified(505) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 507;
+            }
+            506 => {
+                if guard.is_modified(506) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 508;
+            }
+            507 => {
+                if guard.is_modified(507) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[102];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 509;
+            }
+            508 => {
+                if guard.is_modified(508) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 512;
+            }
+            509 => {
+                if guard.is_modified(509) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 513;
+            }
+            510 => {
+                if guard.is_modified(510) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 512;
+            }
+            511 => {
+                if guard.is_modified(511) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 513;
+            }
+            512 => {
+                if guard.is_modified(512) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 514;
+                break Native::Paused(State::Output(val));
+            }
+            513 => {
+                if guard.is_modified(513) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 515;
+            }
+            514 => {
+                if guard.is_modified(514) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 9;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 516;
+            }
+            515 => {
+                if guard.is_modified(515) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 517;
+            }
+            516 => {
+                if guard.is_modified(516) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 2;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 520;
+            }
+            517 => {
+                if guard.is_modified(517) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 521;
+            }
+            518 => {
+                if guard.is_modified(518) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 520;
+            }
+            519 => {
+                if guard.is_modified(519) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[4];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 521;
+            }
+            520 => {
+                if guard.is_modified(520) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                program.count_instruction();
+                ip = 522;
+                break Native::Paused(State::Output(val));
+            }
+            521 => {
+                if guard.is_modified(521) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[99];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 523;
+            }
+            522 => {
+                if guard.is_modified(522) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            _ => break Native::Fallback,
+        }
+    };
+    program.set_ip(ip);
+    program.set_rel_base(rb);
+    native
+}
@@ -0,0 +1,3895 @@
+// Generated by intcode::translate, do not edit
+
+pub static TRANSLATED: intcode::Translated = intcode::Translated {
+    image: &IMAGE,
+    instrs: &INSTRS,
+    native,
+};
+
+static IMAGE: [i64; 973] = [1102, 34463338, 34463338, 63, 1007, 63, 34463338, 63, 1005, 63, 53, 1101, 3, 0, 1000, 109, 988, 209, 12, 9, 1000, 209, 6, 209, 3, 203, 0, 1008, 1000, 1, 63, 1005, 63, 65, 1008, 1000, 2, 63, 1005, 63, 904, 1008, 1000, 0, 63, 1005, 63, 58, 4, 25, 104, 0, 99, 4, 0, 104, 0, 99, 4, 17, 104, 0, 99, 0, 0, 1102, 0, 1, 1020, 1102, 29, 1, 1001, 1101, 0, 28, 1016, 1102, 1, 31, 1011, 1102, 1, 396, 1029, 1101, 26, 0, 1007, 1101, 0, 641, 1026, 1101, 466, 0, 1023, 1101, 30, 0, 1008, 1102, 1, 22, 1003, 1101, 0, 35, 1019, 1101, 0, 36, 1018, 1102, 1, 37, 1012, 1102, 1, 405, 1028, 1102, 638, 1, 1027, 1102, 33, 1, 1000, 1102, 1, 27, 1002, 1101, 21, 0, 1017, 1101, 0, 20, 1015, 1101, 0, 34, 1005, 1101, 0, 23, 1010, 1102, 25, 1, 1013, 1101, 39, 0, 1004, 1101, 32, 0, 1009, 1101, 0, 38, 1006, 1101, 0, 473, 1022, 1102, 1, 1, 1021, 1101, 0, 607, 1024, 1102, 1, 602, 1025, 1101, 24, 0, 1014, 109, 22, 21108, 40, 40, -9, 1005, 1013, 199, 4, 187, 1105, 1, 203, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, -17, 2102, 1, 4, 63, 1008, 63, 32, 63, 1005, 63, 229, 4, 209, 1001, 64, 1, 64, 1105, 1, 229, 1002, 64, 2, 64, 109, 9, 21108, 41, 44, 1, 1005, 1015, 245, 1105, 1, 251, 4, 235, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, 4, 1206, 3, 263, 1105, 1, 269, 4, 257, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, -8, 21102, 42, 1, 5, 1008, 1015, 42, 63, 1005, 63, 291, 4, 275, 1105, 1, 295, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, -13, 1208, 6, 22, 63, 1005, 63, 313, 4, 301, 1105, 1, 317, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, 24, 21107, 43, 44, -4, 1005, 1017, 339, 4, 323, 1001, 64, 1, 64, 1105, 1, 339, 1002, 64, 2, 64, 109, -5, 2107, 29, -8, 63, 1005, 63, 361, 4, 345, 1001, 64, 1, 64, 1105, 1, 361, 1002, 64, 2, 64, 109, -4, 2101, 0, -3, 63, 1008, 63, 32, 63, 1005, 63, 387, 4, 367, 1001, 64, 1, 64, 1106, 0, 387, 1002, 64, 2, 64, 109, 13, 2106, 0, 3, 4, 393, 1001, 64, 1, 64, 1105, 1, 405, 1002, 64, 2, 64, 109, -27, 2102, 1, 2, 63, 1008, 63, 35, 63, 1005, 63, 425, 1105, 1, 431, 4, 411, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, 5, 1202, 2, 1, 63, 1008, 63, 31, 63, 1005, 63, 455, 1001, 64, 1, 64, 1106, 0, 457, 4, 437, 1002, 64, 2, 64, 109, 19, 2105, 1, 1, 1001, 64, 1, 64, 1105, 1, 475, 4, 463, 1002, 64, 2, 64, 109, -6, 21102, 44, 1, 1, 1008, 1017, 45, 63, 1005, 63, 499, 1001, 64, 1, 64, 1105, 1, 501, 4, 481, 1002, 64, 2, 64, 109, 6, 1205, -2, 513, 1106, 0, 519, 4, 507, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, -17, 1207, -1, 40, 63, 1005, 63, 537, 4, 525, 1106, 0, 541, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, -8, 1201, 9, 0, 63, 1008, 63, 38, 63, 1005, 63, 567, 4, 547, 1001, 64, 1, 64, 1106, 0, 567, 1002, 64, 2, 64, 109, -3, 2101, 0, 6, 63, 1008, 63, 32, 63, 1005, 63, 591, 1001, 64, 1, 64, 1105, 1, 593, 4, 573, 1002, 64, 2, 64, 109, 22, 2105, 1, 8, 4, 599, 1106, 0, 611, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, 8, 1206, -4, 625, 4, 617, 1105, 1, 629, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, 3, 2106, 0, 0, 1106, 0, 647, 4, 635, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, -29, 2107, 27, 9, 63, 1005, 63, 667, 1001, 64, 1, 64, 1106, 0, 669, 4, 653, 1002, 64, 2, 64, 109, 7, 1207, -4, 28, 63, 1005, 63, 689, 1001, 64, 1, 64, 1105, 1, 691, 4, 675, 1002, 64, 2, 64, 109, -7, 2108, 30, 3, 63, 1005, 63, 711, 1001, 64, 1, 64, 1105, 1, 713, 4, 697, 1002, 64, 2, 64, 109, 17, 21101, 45, 0, -5, 1008, 1010, 45, 63, 1005, 63, 735, 4, 719, 1106, 0, 739, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, -9, 1202, -2, 1, 63, 1008, 63, 39, 63, 1005, 63, 765, 4, 745, 1001, 64, 1, 64, 1106, 0, 765, 1002, 64, 2, 64, 109, 10, 21101, 46, 0, -5, 1008, 1011, 48, 63, 1005, 63, 785, 1106, 0, 791, 4, 771, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, -10, 1208, 0, 36, 63, 1005, 63, 811, 1001, 64, 1, 64, 1105, 1, 813, 4, 797, 1002, 64, 2, 64, 109, 7, 1205, 8, 827, 4, 819, 1105, 1, 831, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, -15, 2108, 27, 4, 63, 1005, 63, 853, 4, 837, 1001, 64, 1, 64, 1106, 0, 853, 1002, 64, 2, 64, 109, 14, 1201, -3, 0, 63, 1008, 63, 30, 63, 1005, 63, 877, 1001, 64, 1, 64, 1106, 0, 879, 4, 859, 1002, 64, 2, 64, 109, 11, 21107, 47, 46, -5, 1005, 1018, 899, 1001, 64, 1, 64, 1105, 1, 901, 4, 885, 4, 64, 99, 21101, 0, 27, 1, 21101, 0, 915, 0, 1105, 1, 922, 21201, 1, 31783, 1, 204, 1, 99, 109, 3, 1207, -2, 3, 63, 1005, 63, 964, 21201, -2, -1, 1, 21101, 0, 942, 0, 1106, 0, 922, 21201, 1, 0, -1, 21201, -2, -3, 1, 21101, 0, 957, 0, 1105, 1, 922, 22201, 1, -1, -2, 1106, 0, 968, 22102, 1, -2, -2, 109, -3, 2105, 1, 0];
+
+// addr and size of the translated instructions
+static INSTRS: [(usize, usize); 439] = [(0, 4), (4, 4), (8, 3), (11, 4), (12, 2), (15, 2), (17, 2), (19, 2), (21, 2), (22, 3), (23, 2), (24, 2), (25, 2), (27, 4), (29, 4), (31, 3), (34, 4), (36, 4), (38, 3), (41, 4), (45, 3), (48, 2), (50, 2), (52, 1), (53, 2), (55, 2), (57, 1), (58, 2), (60, 2), (62, 1), (65, 4), (67, 4), (69, 4), (71, 4), (72, 4), (73, 4), (77, 4), (78, 4), (81, 4), (82, 4), (85, 4), (88, 4), (89, 4), (93, 4), (97, 4), (100, 4), (101, 4), (102, 4), (105, 4), (109, 4), (113, 4), (114, 4), (117, 4), (118, 4), (121, 4), (123, 4), (125, 4), (127, 4), (129, 4), (130, 4), (132, 4), (133, 4), (137, 4), (141, 4), (144, 3), (145, 4), (149, 4), (151, 4), (153, 4), (157, 4), (161, 4), (164, 3), (165, 4), (169, 4), (170, 4), (171, 4), (173, 4), (177, 4), (178, 4), (181, 4), (185, 2), (187, 4), (191, 3), (194, 2), (196, 3), (197, 4), (198, 2), (199, 4), (201, 4), (203, 4), (207, 2), (209, 4), (210, 4), (211, 2), (213, 4), (217, 3), (220, 2), (221, 2), (222, 4), (224, 4), (226, 3), (227, 4), (229, 4), (231, 4), (233, 2), (234, 2), (235, 4), (238, 4), (239, 3), (242, 3), (243, 4), (245, 2), (247, 4), (249, 4), (251, 4), (253, 4), (255, 2), (256, 2), (257, 3), (258, 2), (260, 3), (261, 4), (263, 2), (265, 4), (267, 4), (269, 4), (273, 2), (275, 4), (277, 4), (278, 3), (279, 4), (283, 3), (286, 2), (288, 3), (289, 4), (291, 4), (293, 4), (295, 4), (299, 2), (301, 4), (302, 3), (305, 3), (308, 2), (310, 3), (311, 4), (313, 4), (315, 4), (317, 4), (319, 4), (321, 2), (323, 4), (327, 3), (330, 2), (332, 4), (334, 4), (336, 3), (337, 4), (339, 4), (343, 2), (345, 4), (349, 3), (352, 2), (354, 4), (356, 4), (358, 3), (359, 4), (361, 4), (365, 2), (367, 4), (371, 4), (375, 3), (378, 2), (380, 4), (382, 4), (384, 3), (387, 4), (389, 4), (391, 2), (393, 3), (395, 2), (396, 2), (398, 4), (400, 4), (402, 3), (403, 4), (405, 4), (409, 2), (411, 4), (412, 4), (413, 4), (415, 4), (419, 3), (422, 3), (423, 4), (425, 2), (427, 4), (429, 4), (431, 4), (433, 4), (435, 2), (436, 3), (437, 4), (438, 4), (439, 4), (441, 4), (445, 3), (448, 4), (450, 4), (452, 3), (455, 2), (457, 4), (459, 4), (461, 2), (463, 3), (464, 4), (465, 4), (466, 4), (468, 4), (470, 3), (471, 4), (473, 2), (475, 4), (479, 2), (481, 4), (483, 4), (484, 4), (485, 4), (489, 3), (492, 4), (494, 4), (496, 3), (497, 4), (499, 2), (501, 4), (503, 4), (505, 2), (507, 3), (510, 3), (513, 2), (515, 4), (517, 4), (519, 4), (523, 2), (525, 4), (529, 3), (532, 2), (534, 3), (537, 4), (539, 4), (541, 4), (545, 2), (547, 4), (548, 2), (551, 4), (555, 3), (558, 2), (560, 4), (562, 4), (564, 3), (567, 4), (571, 2), (573, 4), (575, 3), (577, 4), (581, 3), (584, 4), (586, 4), (588, 3), (589, 4), (591, 2), (593, 4), (595, 4), (597, 2), (599, 3), (600, 4), (601, 4), (602, 2), (604, 3), (607, 4), (609, 4), (611, 4), (613, 4), (615, 2), (617, 3), (620, 2), (622, 3), (623, 4), (625, 4), (627, 4), (629, 4), (631, 4), (633, 2), (634, 2), (635, 3), (638, 3), (641, 2), (643, 4), (645, 4), (647, 4), (651, 2), (653, 4), (655, 2), (657, 3), (660, 4), (662, 4), (664, 3), (667, 2), (669, 4), (671, 4), (673, 2), (675, 4), (679, 3), (682, 4), (684, 4), (686, 3), (687, 4), (689, 2), (691, 4), (695, 2), (697, 4), (699, 2), (701, 3), (704, 4), (706, 4), (708, 3), (709, 4), (711, 2), (713, 4), (715, 4), (717, 2), (719, 4), (723, 4), (727, 3), (730, 2), (732, 3), (735, 4), (737, 4), (739, 4), (743, 2), (745, 4), (747, 4), (749, 4), (753, 3), (756, 2), (758, 4), (760, 4), (762, 3), (765, 4), (767, 4), (769, 2), (771, 4), (775, 4), (779, 3), (782, 3), (785, 2), (787, 4), (789, 4), (791, 4), (795, 2), (797, 4), (801, 3), (804, 4), (806, 4), (808, 3), (809, 4), (811, 2), (813, 4), (815, 4), (817, 2), (818, 4), (819, 3), (820, 4), (822, 2), (824, 3), (825, 4), (827, 4), (829, 4), (831, 4), (835, 2), (837, 4), (839, 2), (841, 3), (844, 2), (846, 4), (848, 4), (850, 3), (853, 4), (855, 4), (857, 2), (859, 4), (863, 4), (867, 3), (870, 4), (872, 4), (874, 3), (877, 2), (879, 4), (881, 4), (883, 2), (885, 4), (889, 3), (892, 4), (894, 4), (896, 3), (897, 4), (899, 2), (901, 2), (903, 1), (904, 4), (907, 4), (908, 4), (912, 3), (913, 4), (915, 4), (916, 4), (918, 4), (919, 2), (920, 4), (921, 1), (922, 2), (923, 2), (924, 4), (926, 2), (928, 3), (931, 4), (934, 4), (935, 4), (939, 3), (942, 4), (946, 4), (949, 4), (950, 4), (954, 3), (955, 4), (957, 4), (961, 3), (964, 4), (968, 2), (970, 3)];
+
+#[inline(always)]
+#[allow(dead_code)]
+fn rel(rb: i64, offset: i64) -> Option<usize> {
+    match rb.checked_add(offset) {
+        Some(addr) if addr >= 0 => Some(addr as usize),
+        _ => None,
+    }
+}
+
+#[allow(unused_mut, clippy::all)]
+fn native(program: &mut intcode::Program, guard: &mut intcode::CodeGuard) -> intcode::Native {
+    use intcode::{Native, State};
+    let mut ip = program.ip();
+    let mut rb = program.rel_base();
+    let native = loop {
+        match ip {
+            0 => {
+                if guard.is_modified(0) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 34463338;
+                let rhs: i64 = 34463338;
+                let addr = 63;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 4;
+            }
+            4 => {
+                if guard.is_modified(4) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[63];
+                let rhs: i64 = 34463338;
+                let addr = 63;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 8;
+            }
+            8 => {
+                if guard.is_modified(8) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 53;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 11 };
+            }
+            11 => {
+                if guard.is_modified(11) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 3;
+                let rhs: i64 = 0;
+                let addr = 1000;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 15;
+            }
+            12 => {
+                if guard.is_modified(12) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 0;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 14;
+            }
+            15 => {
+                if guard.is_modified(15) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 988;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 17;
+            }
+            17 => {
+                if guard.is_modified(17) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, 12) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 19;
+            }
+            19 => {
+                if guard.is_modified(19) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1000];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 21;
+            }
+            21 => {
+                if guard.is_modified(21) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, 6) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 23;
+            }
+            22 => {
+                if guard.is_modified(22) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[209];
+                let target: i64 = program.mem()[3];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 25 };
+            }
+            23 => {
+                if guard.is_modified(23) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, 3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 25;
+            }
+            24 => {
+                if guard.is_modified(24) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 203;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 26;
+            }
+            25 => {
+                if guard.is_modified(25) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 27;
+            }
+            27 => {
+                if guard.is_modified(27) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1000];
+                let rhs: i64 = 1;
+                let addr = 63;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 31;
+            }
+            29 => {
+                if guard.is_modified(29) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[63];
+                let rhs: i64 = program.mem()[1005];
+                let addr = 63;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 33;
+            }
+            31 => {
+                if guard.is_modified(31) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 65;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 34 };
+            }
+            34 => {
+                if guard.is_modified(34) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1000];
+                let rhs: i64 = 2;
+                let addr = 63;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 38;
+            }
+            36 => {
+                if guard.is_modified(36) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[63];
+                let rhs: i64 = program.mem()[1005];
+                let addr = 63;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 40;
+            }
+            38 => {
+                if guard.is_modified(38) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 904;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 41 };
+            }
+            41 => {
+                if guard.is_modified(41) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1000];
+                let rhs: i64 = 0;
+                let addr = 63;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 45;
+            }
+            45 => {
+                if guard.is_modified(45) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 58;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 48 };
+            }
+            48 => {
+                if guard.is_modified(48) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[25];
+                program.count_instruction();
+                ip = 50;
+                break Native::Paused(State::Output(val));
+            }
+            50 => {
+                if guard.is_modified(50) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 52;
+                break Native::Paused(State::Output(val));
+            }
+            52 => {
+                if guard.is_modified(52) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            53 => {
+                if guard.is_modified(53) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[0];
+                program.count_instruction();
+                ip = 55;
+                break Native::Paused(State::Output(val));
+            }
+            55 => {
+                if guard.is_modified(55) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 57;
+                break Native::Paused(State::Output(val));
+            }
+            57 => {
+                if guard.is_modified(57) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            58 => {
+                if guard.is_modified(58) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[17];
+                program.count_instruction();
+                ip = 60;
+                break Native::Paused(State::Output(val));
+            }
+            60 => {
+                if guard.is_modified(60) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 62;
+                break Native::Paused(State::Output(val));
+            }
+            62 => {
+                if guard.is_modified(62) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            65 => {
+                if guard.is_modified(65) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 1;
+                let addr = 1020;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 69;
+            }
+            67 => {
+                if guard.is_modified(67) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1020];
+                let rhs: i64 = program.mem()[1102];
+                let addr = 29;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 71;
+            }
+            69 => {
+                if guard.is_modified(69) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 29;
+                let rhs: i64 = 1;
+                let addr = 1001;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 73;
+            }
+            71 => {
+                if guard.is_modified(71) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1001];
+                let rhs: i64 = program.mem()[1101];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 75;
+            }
+            72 => {
+                if guard.is_modified(72) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1101];
+                let rhs: i64 = 0;
+                let addr = 28;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 76;
+            }
+            73 => {
+                if guard.is_modified(73) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 28;
+                let addr = 1016;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 77;
+            }
+            77 => {
+                if guard.is_modified(77) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 31;
+                let addr = 1011;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 81;
+            }
+            78 => {
+                if guard.is_modified(78) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[31];
+                let rhs: i64 = program.mem()[1011];
+                let addr = 1102;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 82;
+            }
+            81 => {
+                if guard.is_modified(81) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 396;
+                let addr = 1029;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 85;
+            }
+            82 => {
+                if guard.is_modified(82) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[396];
+                let rhs: i64 = program.mem()[1029];
+                let addr = 1101;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 86;
+            }
+            85 => {
+                if guard.is_modified(85) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 26;
+                let rhs: i64 = 0;
+                let addr = 1007;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 89;
+            }
+            88 => {
+                if guard.is_modified(88) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1101];
+                let rhs: i64 = 0;
+                let addr = 641;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 92;
+            }
+            89 => {
+                if guard.is_modified(89) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 641;
+                let addr = 1026;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 93;
+            }
+            93 => {
+                if guard.is_modified(93) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 466;
+                let rhs: i64 = 0;
+                let addr = 1023;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 97;
+            }
+            97 => {
+                if guard.is_modified(97) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 30;
+                let rhs: i64 = 0;
+                let addr = 1008;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 101;
+            }
+            100 => {
+                if guard.is_modified(100) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1102];
+                let rhs: i64 = 1;
+                let addr = 22;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 104;
+            }
+            101 => {
+                if guard.is_modified(101) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 22;
+                let addr = 1003;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 105;
+            }
+            102 => {
+                if guard.is_modified(102) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[22];
+                let rhs: i64 = program.mem()[1003];
+                let addr = 1101;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 106;
+            }
+            105 => {
+                if guard.is_modified(105) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 35;
+                let addr = 1019;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 109;
+            }
+            109 => {
+                if guard.is_modified(109) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 36;
+                let addr = 1018;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 113;
+            }
+            113 => {
+                if guard.is_modified(113) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 37;
+                let addr = 1012;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 117;
+            }
+            114 => {
+                if guard.is_modified(114) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[37];
+                let rhs: i64 = program.mem()[1012];
+                let addr = 1102;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 118;
+            }
+            117 => {
+                if guard.is_modified(117) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 405;
+                let addr = 1028;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 121;
+            }
+            118 => {
+                if guard.is_modified(118) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[405];
+                let rhs: i64 = program.mem()[1028];
+                let addr = 1102;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 122;
+            }
+            121 => {
+                if guard.is_modified(121) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 638;
+                let rhs: i64 = 1;
+                let addr = 1027;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 125;
+            }
+            123 => {
+                if guard.is_modified(123) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1027];
+                let rhs: i64 = program.mem()[1102];
+                let addr = 33;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 127;
+            }
+            125 => {
+                if guard.is_modified(125) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 33;
+                let rhs: i64 = 1;
+                let addr = 1000;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 129;
+            }
+            127 => {
+                if guard.is_modified(127) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1000];
+                let rhs: i64 = program.mem()[1102];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 131;
+            }
+            129 => {
+                if guard.is_modified(129) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 27;
+                let addr = 1002;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 133;
+            }
+            130 => {
+                if guard.is_modified(130) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[27];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 1101;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 134;
+            }
+            132 => {
+                if guard.is_modified(132) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1101];
+                let rhs: i64 = 21;
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 136;
+            }
+            133 => {
+                if guard.is_modified(133) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 21;
+                let rhs: i64 = 0;
+                let addr = 1017;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 137;
+            }
+            137 => {
+                if guard.is_modified(137) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 20;
+                let addr = 1015;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 141;
+            }
+            141 => {
+                if guard.is_modified(141) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 34;
+                let addr = 1005;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 145;
+            }
+            144 => {
+                if guard.is_modified(144) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1101];
+                let target = 0;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 147 };
+            }
+            145 => {
+                if guard.is_modified(145) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 23;
+                let addr = 1010;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 149;
+            }
+            149 => {
+                if guard.is_modified(149) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 25;
+                let rhs: i64 = 1;
+                let addr = 1013;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 153;
+            }
+            151 => {
+                if guard.is_modified(151) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1013];
+                let rhs: i64 = program.mem()[1101];
+                let addr = 39;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 155;
+            }
+            153 => {
+                if guard.is_modified(153) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 39;
+                let rhs: i64 = 0;
+                let addr = 1004;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 157;
+            }
+            157 => {
+                if guard.is_modified(157) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 32;
+                let rhs: i64 = 0;
+                let addr = 1009;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 161;
+            }
+            161 => {
+                if guard.is_modified(161) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 38;
+                let addr = 1006;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 165;
+            }
+            164 => {
+                if guard.is_modified(164) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1101];
+                let target = 0;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 167 };
+            }
+            165 => {
+                if guard.is_modified(165) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 473;
+                let addr = 1022;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 169;
+            }
+            169 => {
+                if guard.is_modified(169) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 1;
+                let addr = 1021;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 173;
+            }
+            170 => {
+                if guard.is_modified(170) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1021];
+                let addr = 1101;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 174;
+            }
+            171 => {
+                if guard.is_modified(171) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1021];
+                let rhs: i64 = program.mem()[1101];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 175;
+            }
+            173 => {
+                if guard.is_modified(173) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 607;
+                let addr = 1024;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 177;
+            }
+            177 => {
+                if guard.is_modified(177) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 602;
+                let addr = 1025;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 181;
+            }
+            178 => {
+                if guard.is_modified(178) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[602];
+                let rhs: i64 = program.mem()[1025];
+                let addr = 1101;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 182;
+            }
+            181 => {
+                if guard.is_modified(181) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 24;
+                let rhs: i64 = 0;
+                let addr = 1014;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 185;
+            }
+            185 => {
+                if guard.is_modified(185) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 22;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 187;
+            }
+            187 => {
+                if guard.is_modified(187) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 40;
+                let rhs: i64 = 40;
+                let addr = match rel(rb, -9) { Some(addr) => addr, None => break Native::Fallback };
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 191;
+            }
+            191 => {
+                if guard.is_modified(191) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1013];
+                let target = 199;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 194 };
+            }
+            194 => {
+                if guard.is_modified(194) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[187];
+                program.count_instruction();
+                ip = 196;
+                break Native::Paused(State::Output(val));
+            }
+            196 => {
+                if guard.is_modified(196) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 203;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 199 };
+            }
+            197 => {
+                if guard.is_modified(197) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[203];
+                let rhs: i64 = program.mem()[1001];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 201;
+            }
+            198 => {
+                if guard.is_modified(198) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = match rel(rb, 1001) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 200;
+            }
+            199 => {
+                if guard.is_modified(199) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 203;
+            }
+            201 => {
+                if guard.is_modified(201) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 205;
+            }
+            203 => {
+                if guard.is_modified(203) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 207;
+            }
+            207 => {
+                if guard.is_modified(207) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -17;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 209;
+            }
+            209 => {
+                if guard.is_modified(209) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = match rel(rb, 4) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = 63;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 213;
+            }
+            210 => {
+                if guard.is_modified(210) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[4];
+                let rhs: i64 = program.mem()[63];
+                let addr = 1008;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 214;
+            }
+            211 => {
+                if guard.is_modified(211) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                program.count_instruction();
+                ip = 213;
+                break Native::Paused(State::Output(val));
+            }
+            213 => {
+                if guard.is_modified(213) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[63];
+                let rhs: i64 = 32;
+                let addr = 63;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 217;
+            }
+            217 => {
+                if guard.is_modified(217) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 229;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 220 };
+            }
+            220 => {
+                if guard.is_modified(220) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[209];
+                program.count_instruction();
+                ip = 222;
+                break Native::Paused(State::Output(val));
+            }
+            221 => {
+                if guard.is_modified(221) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, 1001) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 223;
+            }
+            222 => {
+                if guard.is_modified(222) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 226;
+            }
+            224 => {
+                if guard.is_modified(224) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1105];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 228;
+            }
+            226 => {
+                if guard.is_modified(226) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 229;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 229 };
+            }
+            227 => {
+                if guard.is_modified(227) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[229];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 231;
+            }
+            229 => {
+                if guard.is_modified(229) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 233;
+            }
+            231 => {
+                if guard.is_modified(231) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[109];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 235;
+            }
+            233 => {
+                if guard.is_modified(233) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 9;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 235;
+            }
+            234 => {
+                if guard.is_modified(234) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[21108];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 236;
+            }
+            235 => {
+                if guard.is_modified(235) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 41;
+                let rhs: i64 = 44;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 239;
+            }
+            238 => {
+                if guard.is_modified(238) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1005];
+                let rhs: i64 = program.mem()[1015];
+                let addr = 245;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 242;
+            }
+            239 => {
+                if guard.is_modified(239) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1015];
+                let target = 245;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 242 };
+            }
+            242 => {
+                if guard.is_modified(242) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 251;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 245 };
+            }
+            243 => {
+                if guard.is_modified(243) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[251];
+                let rhs: i64 = program.mem()[4];
+                let addr = 235;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 247;
+            }
+            245 => {
+                if guard.is_modified(245) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[235];
+                program.count_instruction();
+                ip = 247;
+                break Native::Paused(State::Output(val));
+            }
+            247 => {
+                if guard.is_modified(247) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 251;
+            }
+            249 => {
+                if guard.is_modified(249) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 253;
+            }
+            251 => {
+                if guard.is_modified(251) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 255;
+            }
+            253 => {
+                if guard.is_modified(253) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[109];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 257;
+            }
+            255 => {
+                if guard.is_modified(255) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 4;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 257;
+            }
+            256 => {
+                if guard.is_modified(256) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1206];
+                program.count_instruction();
+                ip = 258;
+                break Native::Paused(State::Output(val));
+            }
+            257 => {
+                if guard.is_modified(257) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, 3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let target = 263;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 260 };
+            }
+            258 => {
+                if guard.is_modified(258) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 263;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 260;
+            }
+            260 => {
+                if guard.is_modified(260) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 269;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 263 };
+            }
+            261 => {
+                if guard.is_modified(261) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[269];
+                let rhs: i64 = program.mem()[4];
+                let addr = 257;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 265;
+            }
+            263 => {
+                if guard.is_modified(263) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[257];
+                program.count_instruction();
+                ip = 265;
+                break Native::Paused(State::Output(val));
+            }
+            265 => {
+                if guard.is_modified(265) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 269;
+            }
+            267 => {
+                if guard.is_modified(267) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 271;
+            }
+            269 => {
+                if guard.is_modified(269) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 273;
+            }
+            273 => {
+                if guard.is_modified(273) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -8;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 275;
+            }
+            275 => {
+                if guard.is_modified(275) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 42;
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 5) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 279;
+            }
+            277 => {
+                if guard.is_modified(277) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[5];
+                let rhs: i64 = program.mem()[1008];
+                let addr = 1015;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 281;
+            }
+            278 => {
+                if guard.is_modified(278) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1008];
+                let target: i64 = program.mem()[1015];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 281 };
+            }
+            279 => {
+                if guard.is_modified(279) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1015];
+                let rhs: i64 = 42;
+                let addr = 63;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 283;
+            }
+            283 => {
+                if guard.is_modified(283) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 291;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 286 };
+            }
+            286 => {
+                if guard.is_modified(286) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[275];
+                program.count_instruction();
+                ip = 288;
+                break Native::Paused(State::Output(val));
+            }
+            288 => {
+                if guard.is_modified(288) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 295;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 291 };
+            }
+            289 => {
+                if guard.is_modified(289) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[295];
+                let rhs: i64 = program.mem()[1001];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 293;
+            }
+            291 => {
+                if guard.is_modified(291) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 295;
+            }
+            293 => {
+                if guard.is_modified(293) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 297;
+            }
+            295 => {
+                if guard.is_modified(295) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 299;
+            }
+            299 => {
+                if guard.is_modified(299) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -13;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 301;
+            }
+            301 => {
+                if guard.is_modified(301) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, 6) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 22;
+                let addr = 63;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 305;
+            }
+            302 => {
+                if guard.is_modified(302) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[22];
+                let target: i64 = program.mem()[63];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 305 };
+            }
+            305 => {
+                if guard.is_modified(305) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 313;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 308 };
+            }
+            308 => {
+                if guard.is_modified(308) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[301];
+                program.count_instruction();
+                ip = 310;
+                break Native::Paused(State::Output(val));
+            }
+            310 => {
+                if guard.is_modified(310) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 317;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 313 };
+            }
+            311 => {
+                if guard.is_modified(311) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[317];
+                let rhs: i64 = program.mem()[1001];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 315;
+            }
+            313 => {
+                if guard.is_modified(313) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 317;
+            }
+            315 => {
+                if guard.is_modified(315) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 319;
+            }
+            317 => {
+                if guard.is_modified(317) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 321;
+            }
+            319 => {
+                if guard.is_modified(319) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[109];
+                let addr = 24;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 323;
+            }
+            321 => {
+                if guard.is_modified(321) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 24;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 323;
+            }
+            323 => {
+                if guard.is_modified(323) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 43;
+                let rhs: i64 = 44;
+                let addr = match rel(rb, -4) { Some(addr) => addr, None => break Native::Fallback };
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 327;
+            }
+            327 => {
+                if guard.is_modified(327) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1017];
+                let target = 339;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 330 };
+            }
+            330 => {
+                if guard.is_modified(330) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[323];
+                program.count_instruction();
+                ip = 332;
+                break Native::Paused(State::Output(val));
+            }
+            332 => {
+                if guard.is_modified(332) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 336;
+            }
+            334 => {
+                if guard.is_modified(334) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1105];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 338;
+            }
+            336 => {
+                if guard.is_modified(336) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 339;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 339 };
+            }
+            337 => {
+                if guard.is_modified(337) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[339];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 341;
+            }
+            339 => {
+                if guard.is_modified(339) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 343;
+            }
+            343 => {
+                if guard.is_modified(343) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -5;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 345;
+            }
+            345 => {
+                if guard.is_modified(345) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 29;
+                let rhs: i64 = match rel(rb, -8) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = 63;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 349;
+            }
+            349 => {
+                if guard.is_modified(349) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 361;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 352 };
+            }
+            352 => {
+                if guard.is_modified(352) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[345];
+                program.count_instruction();
+                ip = 354;
+                break Native::Paused(State::Output(val));
+            }
+            354 => {
+                if guard.is_modified(354) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 358;
+            }
+            356 => {
+                if guard.is_modified(356) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1105];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 360;
+            }
+            358 => {
+                if guard.is_modified(358) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 361;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 361 };
+            }
+            359 => {
+                if guard.is_modified(359) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[361];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 363;
+            }
+            361 => {
+                if guard.is_modified(361) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 365;
+            }
+            365 => {
+                if guard.is_modified(365) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -4;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 367;
+            }
+            367 => {
+                if guard.is_modified(367) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = 63;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 371;
+            }
+            371 => {
+                if guard.is_modified(371) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[63];
+                let rhs: i64 = 32;
+                let addr = 63;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 375;
+            }
+            375 => {
+                if guard.is_modified(375) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 387;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 378 };
+            }
+            378 => {
+                if guard.is_modified(378) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[367];
+                program.count_instruction();
+                ip = 380;
+                break Native::Paused(State::Output(val));
+            }
+            380 => {
+                if guard.is_modified(380) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 384;
+            }
+            382 => {
+                if guard.is_modified(382) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1106];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 386;
+            }
+            384 => {
+                if guard.is_modified(384) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 387;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 387 };
+            }
+            387 => {
+                if guard.is_modified(387) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 391;
+            }
+            389 => {
+                if guard.is_modified(389) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[109];
+                let addr = 13;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 393;
+            }
+            391 => {
+                if guard.is_modified(391) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 13;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 393;
+            }
+            393 => {
+                if guard.is_modified(393) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target: i64 = match rel(rb, 3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 396 };
+            }
+            395 => {
+                if guard.is_modified(395) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 4;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 397;
+            }
+            396 => {
+                if guard.is_modified(396) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[393];
+                program.count_instruction();
+                ip = 398;
+                break Native::Paused(State::Output(val));
+            }
+            398 => {
+                if guard.is_modified(398) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 402;
+            }
+            400 => {
+                if guard.is_modified(400) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1105];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 404;
+            }
+            402 => {
+                if guard.is_modified(402) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 405;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 405 };
+            }
+            403 => {
+                if guard.is_modified(403) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[405];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 407;
+            }
+            405 => {
+                if guard.is_modified(405) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 409;
+            }
+            409 => {
+                if guard.is_modified(409) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -27;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 411;
+            }
+            411 => {
+                if guard.is_modified(411) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = match rel(rb, 2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = 63;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 415;
+            }
+            412 => {
+                if guard.is_modified(412) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[63];
+                let addr = 1008;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 416;
+            }
+            413 => {
+                if guard.is_modified(413) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[63];
+                let rhs: i64 = program.mem()[1008];
+                let addr = 63;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 417;
+            }
+            415 => {
+                if guard.is_modified(415) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[63];
+                let rhs: i64 = 35;
+                let addr = 63;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 419;
+            }
+            419 => {
+                if guard.is_modified(419) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 425;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 422 };
+            }
+            422 => {
+                if guard.is_modified(422) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 431;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 425 };
+            }
+            423 => {
+                if guard.is_modified(423) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[431];
+                let rhs: i64 = program.mem()[4];
+                let addr = 411;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 427;
+            }
+            425 => {
+                if guard.is_modified(425) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[411];
+                program.count_instruction();
+                ip = 427;
+                break Native::Paused(State::Output(val));
+            }
+            427 => {
+                if guard.is_modified(427) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 431;
+            }
+            429 => {
+                if guard.is_modified(429) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 433;
+            }
+            431 => {
+                if guard.is_modified(431) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 435;
+            }
+            433 => {
+                if guard.is_modified(433) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[109];
+                let addr = 5;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 437;
+            }
+            435 => {
+                if guard.is_modified(435) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 5;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 437;
+            }
+            436 => {
+                if guard.is_modified(436) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1202];
+                let target: i64 = program.mem()[2];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 439 };
+            }
+            437 => {
+                if guard.is_modified(437) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, 2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 1;
+                let addr = 63;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 441;
+            }
+            438 => {
+                if guard.is_modified(438) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[63];
+                let addr = 1008;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 442;
+            }
+            439 => {
+                if guard.is_modified(439) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[63];
+                let rhs: i64 = program.mem()[1008];
+                let addr = 63;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 443;
+            }
+            441 => {
+                if guard.is_modified(441) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[63];
+                let rhs: i64 = 31;
+                let addr = 63;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 445;
+            }
+            445 => {
+                if guard.is_modified(445) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 455;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 448 };
+            }
+            448 => {
+                if guard.is_modified(448) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 452;
+            }
+            450 => {
+                if guard.is_modified(450) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1106];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 454;
+            }
+            452 => {
+                if guard.is_modified(452) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 457;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 455 };
+            }
+            455 => {
+                if guard.is_modified(455) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[437];
+                program.count_instruction();
+                ip = 457;
+                break Native::Paused(State::Output(val));
+            }
+            457 => {
+                if guard.is_modified(457) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 461;
+            }
+            459 => {
+                if guard.is_modified(459) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[109];
+                let addr = 19;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 463;
+            }
+            461 => {
+                if guard.is_modified(461) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 19;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 463;
+            }
+            463 => {
+                if guard.is_modified(463) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target: i64 = match rel(rb, 1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 466 };
+            }
+            464 => {
+                if guard.is_modified(464) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1001];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 468;
+            }
+            465 => {
+                if guard.is_modified(465) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1001];
+                let rhs: i64 = program.mem()[64];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 469;
+            }
+            466 => {
+                if guard.is_modified(466) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 470;
+            }
+            468 => {
+                if guard.is_modified(468) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1105];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 472;
+            }
+            470 => {
+                if guard.is_modified(470) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 475;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 473 };
+            }
+            471 => {
+                if guard.is_modified(471) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[475];
+                let rhs: i64 = program.mem()[4];
+                let addr = 463;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 475;
+            }
+            473 => {
+                if guard.is_modified(473) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[463];
+                program.count_instruction();
+                ip = 475;
+                break Native::Paused(State::Output(val));
+            }
+            475 => {
+                if guard.is_modified(475) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 479;
+            }
+            479 => {
+                if guard.is_modified(479) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -6;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 481;
+            }
+            481 => {
+                if guard.is_modified(481) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 44;
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 485;
+            }
+            483 => {
+                if guard.is_modified(483) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1008];
+                let addr = 1017;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 487;
+            }
+            484 => {
+                if guard.is_modified(484) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1008];
+                let rhs: i64 = program.mem()[1017];
+                let addr = 45;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 488;
+            }
+            485 => {
+                if guard.is_modified(485) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1017];
+                let rhs: i64 = 45;
+                let addr = 63;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 489;
+            }
+            489 => {
+                if guard.is_modified(489) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 499;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 492 };
+            }
+            492 => {
+                if guard.is_modified(492) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 496;
+            }
+            494 => {
+                if guard.is_modified(494) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1105];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 498;
+            }
+            496 => {
+                if guard.is_modified(496) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 501;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 499 };
+            }
+            497 => {
+                if guard.is_modified(497) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[501];
+                let rhs: i64 = program.mem()[4];
+                let addr = 481;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 501;
+            }
+            499 => {
+                if guard.is_modified(499) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[481];
+                program.count_instruction();
+                ip = 501;
+                break Native::Paused(State::Output(val));
+            }
+            501 => {
+                if guard.is_modified(501) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 505;
+            }
+            503 => {
+                if guard.is_modified(503) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[109];
+                let addr = 6;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 507;
+            }
+            505 => {
+                if guard.is_modified(505) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 6;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 507;
+            }
+            507 => {
+                if guard.is_modified(507) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let target = 513;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 510 };
+            }
+            510 => {
+                if guard.is_modified(510) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 519;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 513 };
+            }
+            513 => {
+                if guard.is_modified(513) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[507];
+                program.count_instruction();
+                ip = 515;
+                break Native::Paused(State::Output(val));
+            }
+            515 => {
+                if guard.is_modified(515) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 519;
+            }
+            517 => {
+                if guard.is_modified(517) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 521;
+            }
+            519 => {
+                if guard.is_modified(519) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 523;
+            }
+            523 => {
+                if guard.is_modified(523) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -17;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 525;
+            }
+            525 => {
+                if guard.is_modified(525) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 40;
+                let addr = 63;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 529;
+            }
+            529 => {
+                if guard.is_modified(529) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 537;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 532 };
+            }
+            532 => {
+                if guard.is_modified(532) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[525];
+                program.count_instruction();
+                ip = 534;
+                break Native::Paused(State::Output(val));
+            }
+            534 => {
+                if guard.is_modified(534) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 541;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 537 };
+            }
+            537 => {
+                if guard.is_modified(537) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 541;
+            }
+            539 => {
+                if guard.is_modified(539) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 543;
+            }
+            541 => {
+                if guard.is_modified(541) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 545;
+            }
+            545 => {
+                if guard.is_modified(545) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -8;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 547;
+            }
+            547 => {
+                if guard.is_modified(547) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, 9) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 0;
+                let addr = 63;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 551;
+            }
+            548 => {
+                if guard.is_modified(548) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[0];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 550;
+            }
+            551 => {
+                if guard.is_modified(551) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[63];
+                let rhs: i64 = 38;
+                let addr = 63;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 555;
+            }
+            555 => {
+                if guard.is_modified(555) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 567;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 558 };
+            }
+            558 => {
+                if guard.is_modified(558) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[547];
+                program.count_instruction();
+                ip = 560;
+                break Native::Paused(State::Output(val));
+            }
+            560 => {
+                if guard.is_modified(560) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 564;
+            }
+            562 => {
+                if guard.is_modified(562) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1106];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 566;
+            }
+            564 => {
+                if guard.is_modified(564) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 567;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 567 };
+            }
+            567 => {
+                if guard.is_modified(567) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 571;
+            }
+            571 => {
+                if guard.is_modified(571) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -3;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 573;
+            }
+            573 => {
+                if guard.is_modified(573) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = match rel(rb, 6) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = 63;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 577;
+            }
+            575 => {
+                if guard.is_modified(575) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target: i64 = program.mem()[1008];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 578 };
+            }
+            577 => {
+                if guard.is_modified(577) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[63];
+                let rhs: i64 = 32;
+                let addr = 63;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 581;
+            }
+            581 => {
+                if guard.is_modified(581) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 591;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 584 };
+            }
+            584 => {
+                if guard.is_modified(584) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 588;
+            }
+            586 => {
+                if guard.is_modified(586) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1105];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 590;
+            }
+            588 => {
+                if guard.is_modified(588) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 593;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 591 };
+            }
+            589 => {
+                if guard.is_modified(589) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[593];
+                let rhs: i64 = program.mem()[4];
+                let addr = 573;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 593;
+            }
+            591 => {
+                if guard.is_modified(591) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[573];
+                program.count_instruction();
+                ip = 593;
+                break Native::Paused(State::Output(val));
+            }
+            593 => {
+                if guard.is_modified(593) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 597;
+            }
+            595 => {
+                if guard.is_modified(595) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[109];
+                let addr = 22;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 599;
+            }
+            597 => {
+                if guard.is_modified(597) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 22;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 599;
+            }
+            599 => {
+                if guard.is_modified(599) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target: i64 = match rel(rb, 8) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 602 };
+            }
+            600 => {
+                if guard.is_modified(600) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[8];
+                let rhs: i64 = program.mem()[4];
+                let addr = 599;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 604;
+            }
+            601 => {
+                if guard.is_modified(601) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[4];
+                let rhs: i64 = program.mem()[599];
+                let addr = 1106;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 605;
+            }
+            602 => {
+                if guard.is_modified(602) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[599];
+                program.count_instruction();
+                ip = 604;
+                break Native::Paused(State::Output(val));
+            }
+            604 => {
+                if guard.is_modified(604) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 611;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 607 };
+            }
+            607 => {
+                if guard.is_modified(607) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 611;
+            }
+            609 => {
+                if guard.is_modified(609) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 613;
+            }
+            611 => {
+                if guard.is_modified(611) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 615;
+            }
+            613 => {
+                if guard.is_modified(613) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[109];
+                let addr = 8;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 617;
+            }
+            615 => {
+                if guard.is_modified(615) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 8;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 617;
+            }
+            617 => {
+                if guard.is_modified(617) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, -4) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let target = 625;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 620 };
+            }
+            620 => {
+                if guard.is_modified(620) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[617];
+                program.count_instruction();
+                ip = 622;
+                break Native::Paused(State::Output(val));
+            }
+            622 => {
+                if guard.is_modified(622) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 629;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 625 };
+            }
+            623 => {
+                if guard.is_modified(623) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[629];
+                let rhs: i64 = program.mem()[1001];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 627;
+            }
+            625 => {
+                if guard.is_modified(625) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 629;
+            }
+            627 => {
+                if guard.is_modified(627) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 631;
+            }
+            629 => {
+                if guard.is_modified(629) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 633;
+            }
+            631 => {
+                if guard.is_modified(631) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[109];
+                let addr = 3;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 635;
+            }
+            633 => {
+                if guard.is_modified(633) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 3;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 635;
+            }
+            634 => {
+                if guard.is_modified(634) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 2106;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 636;
+            }
+            635 => {
+                if guard.is_modified(635) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target: i64 = match rel(rb, 0) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 638 };
+            }
+            638 => {
+                if guard.is_modified(638) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 647;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 641 };
+            }
+            641 => {
+                if guard.is_modified(641) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[635];
+                program.count_instruction();
+                ip = 643;
+                break Native::Paused(State::Output(val));
+            }
+            643 => {
+                if guard.is_modified(643) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 647;
+            }
+            645 => {
+                if guard.is_modified(645) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 649;
+            }
+            647 => {
+                if guard.is_modified(647) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 651;
+            }
+            651 => {
+                if guard.is_modified(651) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -29;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 653;
+            }
+            653 => {
+                if guard.is_modified(653) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 27;
+                let rhs: i64 = match rel(rb, 9) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = 63;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 657;
+            }
+            655 => {
+                if guard.is_modified(655) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 657;
+            }
+            657 => {
+                if guard.is_modified(657) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 667;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 660 };
+            }
+            660 => {
+                if guard.is_modified(660) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 664;
+            }
+            662 => {
+                if guard.is_modified(662) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1106];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 666;
+            }
+            664 => {
+                if guard.is_modified(664) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 669;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 667 };
+            }
+            667 => {
+                if guard.is_modified(667) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[653];
+                program.count_instruction();
+                ip = 669;
+                break Native::Paused(State::Output(val));
+            }
+            669 => {
+                if guard.is_modified(669) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 673;
+            }
+            671 => {
+                if guard.is_modified(671) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[109];
+                let addr = 7;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 675;
+            }
+            673 => {
+                if guard.is_modified(673) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 7;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 675;
+            }
+            675 => {
+                if guard.is_modified(675) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -4) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 28;
+                let addr = 63;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 679;
+            }
+            679 => {
+                if guard.is_modified(679) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 689;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 682 };
+            }
+            682 => {
+                if guard.is_modified(682) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 686;
+            }
+            684 => {
+                if guard.is_modified(684) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1105];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 688;
+            }
+            686 => {
+                if guard.is_modified(686) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 691;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 689 };
+            }
+            687 => {
+                if guard.is_modified(687) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[691];
+                let rhs: i64 = program.mem()[4];
+                let addr = 675;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 691;
+            }
+            689 => {
+                if guard.is_modified(689) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[675];
+                program.count_instruction();
+                ip = 691;
+                break Native::Paused(State::Output(val));
+            }
+            691 => {
+                if guard.is_modified(691) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 695;
+            }
+            695 => {
+                if guard.is_modified(695) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -7;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 697;
+            }
+            697 => {
+                if guard.is_modified(697) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 30;
+                let rhs: i64 = match rel(rb, 3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = 63;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 701;
+            }
+            699 => {
+                if guard.is_modified(699) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 63;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 701;
+            }
+            701 => {
+                if guard.is_modified(701) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 711;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 704 };
+            }
+            704 => {
+                if guard.is_modified(704) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 708;
+            }
+            706 => {
+                if guard.is_modified(706) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1105];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 710;
+            }
+            708 => {
+                if guard.is_modified(708) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 713;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 711 };
+            }
+            709 => {
+                if guard.is_modified(709) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[713];
+                let rhs: i64 = program.mem()[4];
+                let addr = 697;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 713;
+            }
+            711 => {
+                if guard.is_modified(711) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[697];
+                program.count_instruction();
+                ip = 713;
+                break Native::Paused(State::Output(val));
+            }
+            713 => {
+                if guard.is_modified(713) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 717;
+            }
+            715 => {
+                if guard.is_modified(715) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[109];
+                let addr = 17;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 719;
+            }
+            717 => {
+                if guard.is_modified(717) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 17;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 719;
+            }
+            719 => {
+                if guard.is_modified(719) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 45;
+                let rhs: i64 = 0;
+                let addr = match rel(rb, -5) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 723;
+            }
+            723 => {
+                if guard.is_modified(723) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1010];
+                let rhs: i64 = 45;
+                let addr = 63;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 727;
+            }
+            727 => {
+                if guard.is_modified(727) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 735;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 730 };
+            }
+            730 => {
+                if guard.is_modified(730) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[719];
+                program.count_instruction();
+                ip = 732;
+                break Native::Paused(State::Output(val));
+            }
+            732 => {
+                if guard.is_modified(732) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 739;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 735 };
+            }
+            735 => {
+                if guard.is_modified(735) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 739;
+            }
+            737 => {
+                if guard.is_modified(737) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 741;
+            }
+            739 => {
+                if guard.is_modified(739) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 743;
+            }
+            743 => {
+                if guard.is_modified(743) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -9;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 745;
+            }
+            745 => {
+                if guard.is_modified(745) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 1;
+                let addr = 63;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 749;
+            }
+            747 => {
+                if guard.is_modified(747) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[63];
+                let rhs: i64 = program.mem()[1008];
+                let addr = 63;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 751;
+            }
+            749 => {
+                if guard.is_modified(749) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[63];
+                let rhs: i64 = 39;
+                let addr = 63;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 753;
+            }
+            753 => {
+                if guard.is_modified(753) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 765;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 756 };
+            }
+            756 => {
+                if guard.is_modified(756) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[745];
+                program.count_instruction();
+                ip = 758;
+                break Native::Paused(State::Output(val));
+            }
+            758 => {
+                if guard.is_modified(758) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 762;
+            }
+            760 => {
+                if guard.is_modified(760) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1106];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 764;
+            }
+            762 => {
+                if guard.is_modified(762) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 765;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 765 };
+            }
+            765 => {
+                if guard.is_modified(765) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 769;
+            }
+            767 => {
+                if guard.is_modified(767) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[109];
+                let addr = 10;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 771;
+            }
+            769 => {
+                if guard.is_modified(769) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 10;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 771;
+            }
+            771 => {
+                if guard.is_modified(771) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 46;
+                let rhs: i64 = 0;
+                let addr = match rel(rb, -5) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 775;
+            }
+            775 => {
+                if guard.is_modified(775) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1011];
+                let rhs: i64 = 48;
+                let addr = 63;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 779;
+            }
+            779 => {
+                if guard.is_modified(779) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 785;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 782 };
+            }
+            782 => {
+                if guard.is_modified(782) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 791;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 785 };
+            }
+            785 => {
+                if guard.is_modified(785) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[771];
+                program.count_instruction();
+                ip = 787;
+                break Native::Paused(State::Output(val));
+            }
+            787 => {
+                if guard.is_modified(787) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 791;
+            }
+            789 => {
+                if guard.is_modified(789) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 793;
+            }
+            791 => {
+                if guard.is_modified(791) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 795;
+            }
+            795 => {
+                if guard.is_modified(795) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -10;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 797;
+            }
+            797 => {
+                if guard.is_modified(797) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, 0) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 36;
+                let addr = 63;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 801;
+            }
+            801 => {
+                if guard.is_modified(801) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 811;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 804 };
+            }
+            804 => {
+                if guard.is_modified(804) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 808;
+            }
+            806 => {
+                if guard.is_modified(806) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1105];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 810;
+            }
+            808 => {
+                if guard.is_modified(808) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 813;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 811 };
+            }
+            809 => {
+                if guard.is_modified(809) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[813];
+                let rhs: i64 = program.mem()[4];
+                let addr = 797;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 813;
+            }
+            811 => {
+                if guard.is_modified(811) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[797];
+                program.count_instruction();
+                ip = 813;
+                break Native::Paused(State::Output(val));
+            }
+            813 => {
+                if guard.is_modified(813) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 817;
+            }
+            815 => {
+                if guard.is_modified(815) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[109];
+                let addr = 7;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 819;
+            }
+            817 => {
+                if guard.is_modified(817) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 7;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 819;
+            }
+            818 => {
+                if guard.is_modified(818) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1205];
+                let rhs: i64 = program.mem()[8];
+                let addr = 827;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 822;
+            }
+            819 => {
+                if guard.is_modified(819) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, 8) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let target = 827;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 822 };
+            }
+            820 => {
+                if guard.is_modified(820) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[827];
+                let rhs: i64 = program.mem()[4];
+                let addr = 819;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 824;
+            }
+            822 => {
+                if guard.is_modified(822) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[819];
+                program.count_instruction();
+                ip = 824;
+                break Native::Paused(State::Output(val));
+            }
+            824 => {
+                if guard.is_modified(824) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 831;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 827 };
+            }
+            825 => {
+                if guard.is_modified(825) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[831];
+                let rhs: i64 = program.mem()[1001];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 829;
+            }
+            827 => {
+                if guard.is_modified(827) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 831;
+            }
+            829 => {
+                if guard.is_modified(829) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 833;
+            }
+            831 => {
+                if guard.is_modified(831) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 835;
+            }
+            835 => {
+                if guard.is_modified(835) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -15;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 837;
+            }
+            837 => {
+                if guard.is_modified(837) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 27;
+                let rhs: i64 = match rel(rb, 4) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = 63;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 841;
+            }
+            839 => {
+                if guard.is_modified(839) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                program.count_instruction();
+                ip = 841;
+                break Native::Paused(State::Output(val));
+            }
+            841 => {
+                if guard.is_modified(841) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 853;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 844 };
+            }
+            844 => {
+                if guard.is_modified(844) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[837];
+                program.count_instruction();
+                ip = 846;
+                break Native::Paused(State::Output(val));
+            }
+            846 => {
+                if guard.is_modified(846) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 850;
+            }
+            848 => {
+                if guard.is_modified(848) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1106];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 852;
+            }
+            850 => {
+                if guard.is_modified(850) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 853;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 853 };
+            }
+            853 => {
+                if guard.is_modified(853) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 857;
+            }
+            855 => {
+                if guard.is_modified(855) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[109];
+                let addr = 14;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 859;
+            }
+            857 => {
+                if guard.is_modified(857) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 14;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 859;
+            }
+            859 => {
+                if guard.is_modified(859) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 0;
+                let addr = 63;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 863;
+            }
+            863 => {
+                if guard.is_modified(863) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[63];
+                let rhs: i64 = 30;
+                let addr = 63;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 867;
+            }
+            867 => {
+                if guard.is_modified(867) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 877;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 870 };
+            }
+            870 => {
+                if guard.is_modified(870) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 874;
+            }
+            872 => {
+                if guard.is_modified(872) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1106];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 876;
+            }
+            874 => {
+                if guard.is_modified(874) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 879;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 877 };
+            }
+            877 => {
+                if guard.is_modified(877) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[859];
+                program.count_instruction();
+                ip = 879;
+                break Native::Paused(State::Output(val));
+            }
+            879 => {
+                if guard.is_modified(879) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 2;
+                let addr = 64;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 883;
+            }
+            881 => {
+                if guard.is_modified(881) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[109];
+                let addr = 11;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 885;
+            }
+            883 => {
+                if guard.is_modified(883) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 11;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 885;
+            }
+            885 => {
+                if guard.is_modified(885) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 47;
+                let rhs: i64 = 46;
+                let addr = match rel(rb, -5) { Some(addr) => addr, None => break Native::Fallback };
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 889;
+            }
+            889 => {
+                if guard.is_modified(889) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1018];
+                let target = 899;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 892 };
+            }
+            892 => {
+                if guard.is_modified(892) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = 1;
+                let addr = 64;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 896;
+            }
+            894 => {
+                if guard.is_modified(894) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[64];
+                let rhs: i64 = program.mem()[1105];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 898;
+            }
+            896 => {
+                if guard.is_modified(896) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 901;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 899 };
+            }
+            897 => {
+                if guard.is_modified(897) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[901];
+                let rhs: i64 = program.mem()[4];
+                let addr = 885;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 901;
+            }
+            899 => {
+                if guard.is_modified(899) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[885];
+                program.count_instruction();
+                ip = 901;
+                break Native::Paused(State::Output(val));
+            }
+            901 => {
+                if guard.is_modified(901) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[64];
+                program.count_instruction();
+                ip = 903;
+                break Native::Paused(State::Output(val));
+            }
+            903 => {
+                if guard.is_modified(903) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            904 => {
+                if guard.is_modified(904) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 27;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 908;
+            }
+            907 => {
+                if guard.is_modified(907) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[0];
+                let addr = 915;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 911;
+            }
+            908 => {
+                if guard.is_modified(908) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 915;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 912;
+            }
+            912 => {
+                if guard.is_modified(912) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 922;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 915 };
+            }
+            913 => {
+                if guard.is_modified(913) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[922];
+                let rhs: i64 = program.mem()[21201];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 917;
+            }
+            915 => {
+                if guard.is_modified(915) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, 1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 31783;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 919;
+            }
+            916 => {
+                if guard.is_modified(916) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[31783];
+                let rhs: i64 = program.mem()[1];
+                let addr = 204;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 920;
+            }
+            918 => {
+                if guard.is_modified(918) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[204];
+                let rhs: i64 = program.mem()[1];
+                let addr = 99;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 922;
+            }
+            919 => {
+                if guard.is_modified(919) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, 1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                program.count_instruction();
+                ip = 921;
+                break Native::Paused(State::Output(val));
+            }
+            920 => {
+                if guard.is_modified(920) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[99];
+                let rhs: i64 = program.mem()[109];
+                let addr = 3;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 924;
+            }
+            921 => {
+                if guard.is_modified(921) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            922 => {
+                if guard.is_modified(922) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 3;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 924;
+            }
+            923 => {
+                if guard.is_modified(923) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 1207;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 925;
+            }
+            924 => {
+                if guard.is_modified(924) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 3;
+                let addr = 63;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 928;
+            }
+            926 => {
+                if guard.is_modified(926) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 63;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 928;
+            }
+            928 => {
+                if guard.is_modified(928) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[63];
+                let target = 964;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 931 };
+            }
+            931 => {
+                if guard.is_modified(931) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = -1;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 935;
+            }
+            934 => {
+                if guard.is_modified(934) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[0];
+                let addr = 942;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 938;
+            }
+            935 => {
+                if guard.is_modified(935) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 942;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 939;
+            }
+            939 => {
+                if guard.is_modified(939) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 922;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 942 };
+            }
+            942 => {
+                if guard.is_modified(942) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, 1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 0;
+                let addr = match rel(rb, -1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 946;
+            }
+            946 => {
+                if guard.is_modified(946) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = -3;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 950;
+            }
+            949 => {
+                if guard.is_modified(949) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[0];
+                let addr = 957;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 953;
+            }
+            950 => {
+                if guard.is_modified(950) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 957;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 954;
+            }
+            954 => {
+                if guard.is_modified(954) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 922;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 957 };
+            }
+            955 => {
+                if guard.is_modified(955) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[922];
+                let rhs: i64 = program.mem()[22201];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 959;
+            }
+            957 => {
+                if guard.is_modified(957) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, 1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = match rel(rb, -1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = match rel(rb, -2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 961;
+            }
+            961 => {
+                if guard.is_modified(961) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 968;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 964 };
+            }
+            964 => {
+                if guard.is_modified(964) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = match rel(rb, -2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 968;
+            }
+            968 => {
+                if guard.is_modified(968) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -3;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 970;
+            }
+            970 => {
+                if guard.is_modified(970) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target: i64 = match rel(rb, 0) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 973 };
+            }
+            _ => break Native::Fallback,
+        }
+    };
+    program.set_ip(ip);
+    program.set_rel_base(rb);
+    native
+}
@@ -0,0 +1,2614 @@
+// Generated by intcode::translate, do not edit
+
+pub static TRANSLATED: intcode::Translated = intcode::Translated {
+    image: &IMAGE,
+    instrs: &INSTRS,
+    native,
+};
+
+static IMAGE: [i64; 640] = [3, 8, 1005, 8, 318, 1106, 0, 11, 0, 0, 0, 104, 1, 104, 0, 3, 8, 1002, 8, -1, 10, 1001, 10, 1, 10, 4, 10, 108, 1, 8, 10, 4, 10, 1002, 8, 1, 28, 1, 107, 14, 10, 1, 107, 18, 10, 3, 8, 102, -1, 8, 10, 101, 1, 10, 10, 4, 10, 108, 1, 8, 10, 4, 10, 102, 1, 8, 58, 1006, 0, 90, 2, 1006, 20, 10, 3, 8, 1002, 8, -1, 10, 101, 1, 10, 10, 4, 10, 1008, 8, 1, 10, 4, 10, 1001, 8, 0, 88, 2, 103, 2, 10, 2, 4, 7, 10, 3, 8, 1002, 8, -1, 10, 101, 1, 10, 10, 4, 10, 1008, 8, 1, 10, 4, 10, 1001, 8, 0, 118, 1, 1009, 14, 10, 1, 1103, 9, 10, 3, 8, 1002, 8, -1, 10, 1001, 10, 1, 10, 4, 10, 108, 0, 8, 10, 4, 10, 1002, 8, 1, 147, 1006, 0, 59, 1, 104, 4, 10, 2, 106, 18, 10, 3, 8, 102, -1, 8, 10, 1001, 10, 1, 10, 4, 10, 1008, 8, 0, 10, 4, 10, 101, 0, 8, 181, 2, 4, 17, 10, 1006, 0, 36, 1, 107, 7, 10, 2, 1008, 0, 10, 3, 8, 1002, 8, -1, 10, 1001, 10, 1, 10, 4, 10, 108, 0, 8, 10, 4, 10, 101, 0, 8, 217, 3, 8, 102, -1, 8, 10, 1001, 10, 1, 10, 4, 10, 1008, 8, 0, 10, 4, 10, 101, 0, 8, 240, 1006, 0, 64, 3, 8, 102, -1, 8, 10, 1001, 10, 1, 10, 4, 10, 108, 0, 8, 10, 4, 10, 1002, 8, 1, 264, 3, 8, 1002, 8, -1, 10, 1001, 10, 1, 10, 4, 10, 1008, 8, 1, 10, 4, 10, 1001, 8, 0, 287, 1, 1104, 15, 10, 1, 102, 8, 10, 1006, 0, 2, 101, 1, 9, 9, 1007, 9, 940, 10, 1005, 10, 15, 99, 109, 640, 104, 0, 104, 1, 21102, 932700857236, 1, 1, 21101, 335, 0, 0, 1106, 0, 439, 21101, 0, 387511792424, 1, 21101, 346, 0, 0, 1106, 0, 439, 3, 10, 104, 0, 104, 1, 3, 10, 104, 0, 104, 0, 3, 10, 104, 0, 104, 1, 3, 10, 104, 0, 104, 1, 3, 10, 104, 0, 104, 0, 3, 10, 104, 0, 104, 1, 21101, 46372252675, 0, 1, 21102, 393, 1, 0, 1106, 0, 439, 21101, 97806162983, 0, 1, 21102, 404, 1, 0, 1105, 1, 439, 3, 10, 104, 0, 104, 0, 3, 10, 104, 0, 104, 0, 21102, 1, 825452438376, 1, 21101, 0, 427, 0, 1106, 0, 439, 21102, 709475586836, 1, 1, 21101, 0, 438, 0, 1106, 0, 439, 99, 109, 2, 22101, 0, -1, 1, 21101, 40, 0, 2, 21102, 1, 470, 3, 21102, 1, 460, 0, 1106, 0, 503, 109, -2, 2106, 0, 0, 0, 1, 0, 0, 1, 109, 2, 3, 10, 204, -1, 1001, 465, 466, 481, 4, 0, 1001, 465, 1, 465, 108, 4, 465, 10, 1006, 10, 497, 1101, 0, 0, 465, 109, -2, 2105, 1, 0, 0, 109, 4, 2102, 1, -1, 502, 1207, -3, 0, 10, 1006, 10, 520, 21102, 1, 0, -3, 21202, -3, 1, 1, 21202, -2, 1, 2, 21101, 0, 1, 3, 21101, 0, 539, 0, 1106, 0, 544, 109, -4, 2105, 1, 0, 109, 5, 1207, -3, 1, 10, 1006, 10, 567, 2207, -4, -2, 10, 1006, 10, 567, 22101, 0, -4, -4, 1106, 0, 635, 21202, -4, 1, 1, 21201, -3, -1, 2, 21202, -2, 2, 3, 21102, 586, 1, 0, 1105, 1, 544, 22101, 0, 1, -4, 21102, 1, 1, -1, 2207, -4, -2, 10, 1006, 10, 605, 21102, 0, 1, -1, 22202, -2, -1, -2, 2107, 0, -3, 10, 1006, 10, 627, 22101, 0, -1, 1, 21102, 1, 627, 0, 106, 0, 502, 21202, -2, -1, -2, 22201, -4, -2, -4, 109, -5, 2105, 1, 0];
+
+// addr and size of the translated instructions
+static INSTRS: [(usize, usize); 290] = [(0, 2), (1, 4), (2, 3), (3, 4), (5, 3), (11, 2), (12, 4), (13, 2), (15, 2), (17, 4), (21, 4), (23, 4), (25, 2), (29, 4), (31, 2), (33, 4), (34, 4), (35, 4), (37, 4), (38, 4), (41, 4), (42, 4), (45, 2), (47, 4), (49, 4), (51, 4), (52, 4), (55, 2), (59, 4), (61, 2), (63, 4), (64, 4), (65, 4), (67, 3), (70, 4), (71, 3), (74, 2), (76, 4), (80, 4), (81, 4), (84, 2), (90, 2), (92, 4), (93, 4), (96, 4), (98, 4), (100, 4), (101, 2), (102, 4), (104, 2), (106, 4), (110, 4), (111, 4), (114, 2), (120, 2), (122, 4), (123, 4), (126, 4), (130, 4), (132, 2), (134, 2), (136, 4), (140, 4), (142, 4), (144, 2), (148, 4), (150, 2), (152, 4), (153, 4), (154, 4), (156, 3), (159, 4), (160, 2), (161, 2), (163, 4), (164, 3), (167, 2), (169, 4), (171, 4), (173, 4), (175, 4), (177, 2), (183, 2), (185, 4), (187, 4), (189, 4), (190, 2), (193, 3), (196, 4), (197, 4), (198, 4), (200, 4), (201, 4), (204, 2), (206, 4), (210, 4), (212, 4), (214, 2), (218, 4), (220, 2), (222, 4), (224, 4), (226, 2), (228, 4), (230, 4), (232, 4), (234, 4), (236, 2), (242, 2), (244, 4), (246, 4), (248, 3), (251, 2), (253, 4), (255, 4), (257, 4), (259, 4), (261, 2), (265, 4), (267, 2), (269, 4), (270, 4), (271, 4), (273, 2), (275, 4), (279, 4), (281, 4), (283, 2), (289, 2), (291, 4), (292, 4), (295, 4), (299, 4), (300, 4), (301, 4), (303, 3), (305, 4), (306, 4), (307, 4), (308, 2), (309, 2), (310, 4), (311, 2), (314, 3), (317, 1), (318, 2), (320, 2), (322, 2), (323, 4), (324, 4), (326, 4), (327, 4), (328, 4), (332, 3), (335, 4), (338, 4), (339, 4), (343, 3), (346, 2), (348, 2), (350, 2), (351, 4), (352, 2), (354, 2), (356, 2), (358, 2), (360, 2), (362, 2), (363, 4), (364, 2), (366, 2), (368, 2), (369, 4), (370, 2), (372, 2), (374, 2), (376, 2), (378, 2), (380, 2), (381, 4), (382, 4), (385, 4), (386, 4), (388, 4), (390, 3), (393, 4), (396, 4), (397, 4), (399, 4), (401, 3), (402, 4), (404, 2), (406, 2), (408, 2), (410, 2), (412, 2), (414, 2), (416, 4), (417, 4), (419, 4), (420, 4), (424, 3), (427, 4), (429, 4), (430, 4), (431, 4), (435, 3), (438, 1), (439, 2), (441, 4), (444, 4), (445, 4), (448, 4), (449, 4), (450, 4), (452, 2), (453, 4), (454, 4), (457, 3), (460, 2), (462, 3), (466, 4), (469, 4), (470, 2), (471, 4), (472, 2), (474, 2), (476, 4), (480, 2), (482, 4), (484, 4), (486, 4), (487, 2), (490, 3), (493, 4), (497, 2), (499, 3), (500, 4), (503, 2), (504, 2), (505, 4), (509, 4), (513, 3), (516, 4), (520, 4), (524, 4), (526, 4), (527, 4), (528, 4), (530, 4), (531, 2), (532, 4), (536, 3), (539, 2), (541, 3), (542, 4), (544, 2), (546, 4), (548, 4), (550, 3), (553, 4), (557, 3), (560, 4), (564, 3), (567, 4), (571, 4), (575, 4), (577, 4), (578, 2), (579, 4), (581, 4), (583, 3), (584, 4), (586, 4), (590, 4), (594, 4), (598, 3), (601, 4), (605, 4), (609, 4), (613, 3), (616, 4), (619, 4), (620, 4), (621, 4), (624, 3), (627, 4), (631, 4), (635, 2), (637, 3)];
+
+#[inline(always)]
+#[allow(dead_code)]
+fn rel(rb: i64, offset: i64) -> Option<usize> {
+    match rb.checked_add(offset) {
+        Some(addr) if addr >= 0 => Some(addr as usize),
+        _ => None,
+    }
+}
+
+#[allow(unused_mut, clippy::all)]
+fn native(program: &mut intcode::Program, guard: &mut intcode::CodeGuard) -> intcode::Native {
+    use intcode::{Native, State};
+    let mut ip = program.ip();
+    let mut rb = program.rel_base();
+    let native = loop {
+        match ip {
+            0 => {
+                if guard.is_modified(0) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 8;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2;
+            }
+            1 => {
+                if guard.is_modified(1) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1005];
+                let rhs: i64 = program.mem()[8];
+                let addr = 318;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 5;
+            }
+            2 => {
+                if guard.is_modified(2) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[8];
+                let target = 318;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 5 };
+            }
+            3 => {
+                if guard.is_modified(3) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[318];
+                let rhs: i64 = program.mem()[1106];
+                let addr = 0;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 7;
+            }
+            5 => {
+                if guard.is_modified(5) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 11;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 8 };
+            }
+            11 => {
+                if guard.is_modified(11) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                program.count_instruction();
+                ip = 13;
+                break Native::Paused(State::Output(val));
+            }
+            12 => {
+                if guard.is_modified(12) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[104];
+                let rhs: i64 = program.mem()[0];
+                let addr = 3;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 16;
+            }
+            13 => {
+                if guard.is_modified(13) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 15;
+                break Native::Paused(State::Output(val));
+            }
+            15 => {
+                if guard.is_modified(15) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 8;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 17;
+            }
+            17 => {
+                if guard.is_modified(17) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[8];
+                let rhs: i64 = -1;
+                let addr = 10;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 21;
+            }
+            21 => {
+                if guard.is_modified(21) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = 1;
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 25;
+            }
+            23 => {
+                if guard.is_modified(23) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[4];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 27;
+            }
+            25 => {
+                if guard.is_modified(25) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 27;
+                break Native::Paused(State::Output(val));
+            }
+            29 => {
+                if guard.is_modified(29) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[4];
+                let addr = 10;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 33;
+            }
+            31 => {
+                if guard.is_modified(31) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 33;
+                break Native::Paused(State::Output(val));
+            }
+            33 => {
+                if guard.is_modified(33) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[8];
+                let rhs: i64 = 1;
+                let addr = 28;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 37;
+            }
+            34 => {
+                if guard.is_modified(34) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[28];
+                let addr = 1;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 38;
+            }
+            35 => {
+                if guard.is_modified(35) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[28];
+                let rhs: i64 = program.mem()[1];
+                let addr = 107;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 39;
+            }
+            37 => {
+                if guard.is_modified(37) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[107];
+                let rhs: i64 = program.mem()[14];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 41;
+            }
+            38 => {
+                if guard.is_modified(38) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 14;
+                let rhs: i64 = program.mem()[10];
+                let addr = 1;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 42;
+            }
+            41 => {
+                if guard.is_modified(41) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[107];
+                let rhs: i64 = program.mem()[18];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 45;
+            }
+            42 => {
+                if guard.is_modified(42) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 18;
+                let rhs: i64 = program.mem()[10];
+                let addr = 3;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 46;
+            }
+            45 => {
+                if guard.is_modified(45) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 8;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 47;
+            }
+            47 => {
+                if guard.is_modified(47) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = -1;
+                let rhs: i64 = program.mem()[8];
+                let addr = 10;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 51;
+            }
+            49 => {
+                if guard.is_modified(49) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[101];
+                let addr = 1;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 53;
+            }
+            51 => {
+                if guard.is_modified(51) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[10];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 55;
+            }
+            52 => {
+                if guard.is_modified(52) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[10];
+                let addr = 4;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 56;
+            }
+            55 => {
+                if guard.is_modified(55) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 57;
+                break Native::Paused(State::Output(val));
+            }
+            59 => {
+                if guard.is_modified(59) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[4];
+                let addr = 10;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 63;
+            }
+            61 => {
+                if guard.is_modified(61) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 63;
+                break Native::Paused(State::Output(val));
+            }
+            63 => {
+                if guard.is_modified(63) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[8];
+                let addr = 58;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 67;
+            }
+            64 => {
+                if guard.is_modified(64) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[8];
+                let rhs: i64 = program.mem()[58];
+                let addr = 1006;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 68;
+            }
+            65 => {
+                if guard.is_modified(65) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[58];
+                let rhs: i64 = program.mem()[1006];
+                let addr = 0;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 69;
+            }
+            67 => {
+                if guard.is_modified(67) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[0];
+                let target = 90;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 70 };
+            }
+            70 => {
+                if guard.is_modified(70) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1006];
+                let rhs: i64 = program.mem()[20];
+                let addr = 10;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 74;
+            }
+            71 => {
+                if guard.is_modified(71) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[20];
+                let target = 10;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 74 };
+            }
+            74 => {
+                if guard.is_modified(74) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 8;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 76;
+            }
+            76 => {
+                if guard.is_modified(76) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[8];
+                let rhs: i64 = -1;
+                let addr = 10;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 80;
+            }
+            80 => {
+                if guard.is_modified(80) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[10];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 84;
+            }
+            81 => {
+                if guard.is_modified(81) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[10];
+                let addr = 4;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 85;
+            }
+            84 => {
+                if guard.is_modified(84) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 86;
+                break Native::Paused(State::Output(val));
+            }
+            90 => {
+                if guard.is_modified(90) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 92;
+                break Native::Paused(State::Output(val));
+            }
+            92 => {
+                if guard.is_modified(92) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[8];
+                let rhs: i64 = 0;
+                let addr = 88;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 96;
+            }
+            93 => {
+                if guard.is_modified(93) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[88];
+                let addr = 2;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 97;
+            }
+            96 => {
+                if guard.is_modified(96) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[103];
+                let rhs: i64 = program.mem()[2];
+                let addr = 10;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 100;
+            }
+            98 => {
+                if guard.is_modified(98) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[2];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 102;
+            }
+            100 => {
+                if guard.is_modified(100) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[4];
+                let rhs: i64 = program.mem()[7];
+                let addr = 10;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 104;
+            }
+            101 => {
+                if guard.is_modified(101) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[7];
+                program.count_instruction();
+                ip = 103;
+                break Native::Paused(State::Output(val));
+            }
+            102 => {
+                if guard.is_modified(102) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[3];
+                let addr = 8;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 106;
+            }
+            104 => {
+                if guard.is_modified(104) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 8;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 106;
+            }
+            106 => {
+                if guard.is_modified(106) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[8];
+                let rhs: i64 = -1;
+                let addr = 10;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 110;
+            }
+            110 => {
+                if guard.is_modified(110) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[10];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 114;
+            }
+            111 => {
+                if guard.is_modified(111) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[10];
+                let addr = 4;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 115;
+            }
+            114 => {
+                if guard.is_modified(114) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 116;
+                break Native::Paused(State::Output(val));
+            }
+            120 => {
+                if guard.is_modified(120) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 122;
+                break Native::Paused(State::Output(val));
+            }
+            122 => {
+                if guard.is_modified(122) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[8];
+                let rhs: i64 = 0;
+                let addr = 118;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 126;
+            }
+            123 => {
+                if guard.is_modified(123) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[118];
+                let addr = 1;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 127;
+            }
+            126 => {
+                if guard.is_modified(126) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1009];
+                let rhs: i64 = program.mem()[14];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 130;
+            }
+            130 => {
+                if guard.is_modified(130) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1103];
+                let rhs: i64 = program.mem()[9];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 134;
+            }
+            132 => {
+                if guard.is_modified(132) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 134;
+            }
+            134 => {
+                if guard.is_modified(134) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 8;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 136;
+            }
+            136 => {
+                if guard.is_modified(136) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[8];
+                let rhs: i64 = -1;
+                let addr = 10;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 140;
+            }
+            140 => {
+                if guard.is_modified(140) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = 1;
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 144;
+            }
+            142 => {
+                if guard.is_modified(142) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[4];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 146;
+            }
+            144 => {
+                if guard.is_modified(144) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 146;
+                break Native::Paused(State::Output(val));
+            }
+            148 => {
+                if guard.is_modified(148) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[4];
+                let addr = 10;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 152;
+            }
+            150 => {
+                if guard.is_modified(150) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 152;
+                break Native::Paused(State::Output(val));
+            }
+            152 => {
+                if guard.is_modified(152) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[8];
+                let rhs: i64 = 1;
+                let addr = 147;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 156;
+            }
+            153 => {
+                if guard.is_modified(153) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[147];
+                let addr = 1006;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 157;
+            }
+            154 => {
+                if guard.is_modified(154) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[147];
+                let rhs: i64 = program.mem()[1006];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 158;
+            }
+            156 => {
+                if guard.is_modified(156) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[0];
+                let target = 59;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 159 };
+            }
+            159 => {
+                if guard.is_modified(159) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[104];
+                let rhs: i64 = program.mem()[4];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 163;
+            }
+            160 => {
+                if guard.is_modified(160) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 4;
+                program.count_instruction();
+                ip = 162;
+                break Native::Paused(State::Output(val));
+            }
+            161 => {
+                if guard.is_modified(161) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 163;
+                break Native::Paused(State::Output(val));
+            }
+            163 => {
+                if guard.is_modified(163) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[106];
+                let rhs: i64 = program.mem()[18];
+                let addr = 10;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 167;
+            }
+            164 => {
+                if guard.is_modified(164) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 18;
+                let target: i64 = program.mem()[10];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 167 };
+            }
+            167 => {
+                if guard.is_modified(167) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 8;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 169;
+            }
+            169 => {
+                if guard.is_modified(169) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = -1;
+                let rhs: i64 = program.mem()[8];
+                let addr = 10;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 173;
+            }
+            171 => {
+                if guard.is_modified(171) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[1001];
+                let addr = 10;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 175;
+            }
+            173 => {
+                if guard.is_modified(173) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = 1;
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 177;
+            }
+            175 => {
+                if guard.is_modified(175) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[4];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 179;
+            }
+            177 => {
+                if guard.is_modified(177) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 179;
+                break Native::Paused(State::Output(val));
+            }
+            183 => {
+                if guard.is_modified(183) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 185;
+                break Native::Paused(State::Output(val));
+            }
+            185 => {
+                if guard.is_modified(185) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = program.mem()[8];
+                let addr = 181;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 189;
+            }
+            187 => {
+                if guard.is_modified(187) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[181];
+                let rhs: i64 = program.mem()[2];
+                let addr = 4;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 191;
+            }
+            189 => {
+                if guard.is_modified(189) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[4];
+                let rhs: i64 = program.mem()[17];
+                let addr = 10;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 193;
+            }
+            190 => {
+                if guard.is_modified(190) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[17];
+                program.count_instruction();
+                ip = 192;
+                break Native::Paused(State::Output(val));
+            }
+            193 => {
+                if guard.is_modified(193) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[0];
+                let target = 36;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 196 };
+            }
+            196 => {
+                if guard.is_modified(196) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[107];
+                let rhs: i64 = program.mem()[7];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 200;
+            }
+            197 => {
+                if guard.is_modified(197) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 7;
+                let rhs: i64 = program.mem()[10];
+                let addr = 2;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 201;
+            }
+            198 => {
+                if guard.is_modified(198) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[2];
+                let addr = 1008;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 202;
+            }
+            200 => {
+                if guard.is_modified(200) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1008];
+                let rhs: i64 = program.mem()[0];
+                let addr = 10;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 204;
+            }
+            201 => {
+                if guard.is_modified(201) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = 10;
+                let addr = 3;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 205;
+            }
+            204 => {
+                if guard.is_modified(204) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 8;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 206;
+            }
+            206 => {
+                if guard.is_modified(206) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[8];
+                let rhs: i64 = -1;
+                let addr = 10;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 210;
+            }
+            210 => {
+                if guard.is_modified(210) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = 1;
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 214;
+            }
+            212 => {
+                if guard.is_modified(212) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[4];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 216;
+            }
+            214 => {
+                if guard.is_modified(214) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 216;
+                break Native::Paused(State::Output(val));
+            }
+            218 => {
+                if guard.is_modified(218) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[4];
+                let addr = 10;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 222;
+            }
+            220 => {
+                if guard.is_modified(220) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 222;
+                break Native::Paused(State::Output(val));
+            }
+            222 => {
+                if guard.is_modified(222) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = program.mem()[8];
+                let addr = 217;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 226;
+            }
+            224 => {
+                if guard.is_modified(224) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[217];
+                let rhs: i64 = program.mem()[3];
+                let addr = 8;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 228;
+            }
+            226 => {
+                if guard.is_modified(226) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 8;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 228;
+            }
+            228 => {
+                if guard.is_modified(228) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = -1;
+                let rhs: i64 = program.mem()[8];
+                let addr = 10;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 232;
+            }
+            230 => {
+                if guard.is_modified(230) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[1001];
+                let addr = 10;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 234;
+            }
+            232 => {
+                if guard.is_modified(232) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = 1;
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 236;
+            }
+            234 => {
+                if guard.is_modified(234) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[4];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 238;
+            }
+            236 => {
+                if guard.is_modified(236) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 238;
+                break Native::Paused(State::Output(val));
+            }
+            242 => {
+                if guard.is_modified(242) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 244;
+                break Native::Paused(State::Output(val));
+            }
+            244 => {
+                if guard.is_modified(244) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = program.mem()[8];
+                let addr = 240;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 248;
+            }
+            246 => {
+                if guard.is_modified(246) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[240];
+                let rhs: i64 = program.mem()[1006];
+                let addr = 0;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 250;
+            }
+            248 => {
+                if guard.is_modified(248) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[0];
+                let target = 64;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 251 };
+            }
+            251 => {
+                if guard.is_modified(251) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 8;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 253;
+            }
+            253 => {
+                if guard.is_modified(253) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = -1;
+                let rhs: i64 = program.mem()[8];
+                let addr = 10;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 257;
+            }
+            255 => {
+                if guard.is_modified(255) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[1001];
+                let addr = 10;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 259;
+            }
+            257 => {
+                if guard.is_modified(257) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = 1;
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 261;
+            }
+            259 => {
+                if guard.is_modified(259) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[4];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 263;
+            }
+            261 => {
+                if guard.is_modified(261) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 263;
+                break Native::Paused(State::Output(val));
+            }
+            265 => {
+                if guard.is_modified(265) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[4];
+                let addr = 10;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 269;
+            }
+            267 => {
+                if guard.is_modified(267) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 269;
+                break Native::Paused(State::Output(val));
+            }
+            269 => {
+                if guard.is_modified(269) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[8];
+                let rhs: i64 = 1;
+                let addr = 264;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 273;
+            }
+            270 => {
+                if guard.is_modified(270) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[264];
+                let addr = 3;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 274;
+            }
+            271 => {
+                if guard.is_modified(271) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[264];
+                let rhs: i64 = program.mem()[3];
+                let addr = 8;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 275;
+            }
+            273 => {
+                if guard.is_modified(273) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 8;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 275;
+            }
+            275 => {
+                if guard.is_modified(275) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[8];
+                let rhs: i64 = -1;
+                let addr = 10;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 279;
+            }
+            279 => {
+                if guard.is_modified(279) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = 1;
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 283;
+            }
+            281 => {
+                if guard.is_modified(281) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[4];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 285;
+            }
+            283 => {
+                if guard.is_modified(283) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 285;
+                break Native::Paused(State::Output(val));
+            }
+            289 => {
+                if guard.is_modified(289) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                program.count_instruction();
+                ip = 291;
+                break Native::Paused(State::Output(val));
+            }
+            291 => {
+                if guard.is_modified(291) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[8];
+                let rhs: i64 = 0;
+                let addr = 287;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 295;
+            }
+            292 => {
+                if guard.is_modified(292) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[287];
+                let addr = 1;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 296;
+            }
+            295 => {
+                if guard.is_modified(295) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1104];
+                let rhs: i64 = program.mem()[15];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 299;
+            }
+            299 => {
+                if guard.is_modified(299) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[102];
+                let rhs: i64 = program.mem()[8];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 303;
+            }
+            300 => {
+                if guard.is_modified(300) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 8;
+                let rhs: i64 = program.mem()[10];
+                let addr = 1006;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 304;
+            }
+            301 => {
+                if guard.is_modified(301) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[1006];
+                let addr = 0;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 305;
+            }
+            303 => {
+                if guard.is_modified(303) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[0];
+                let target = 2;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 306 };
+            }
+            305 => {
+                if guard.is_modified(305) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[101];
+                let rhs: i64 = program.mem()[1];
+                let addr = 9;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 309;
+            }
+            306 => {
+                if guard.is_modified(306) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[9];
+                let addr = 9;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 310;
+            }
+            307 => {
+                if guard.is_modified(307) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[9];
+                let addr = 1007;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 311;
+            }
+            308 => {
+                if guard.is_modified(308) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 310;
+            }
+            309 => {
+                if guard.is_modified(309) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1007];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 311;
+            }
+            310 => {
+                if guard.is_modified(310) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = 940;
+                let addr = 10;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 314;
+            }
+            311 => {
+                if guard.is_modified(311) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[940];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 313;
+            }
+            314 => {
+                if guard.is_modified(314) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                let target = 15;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 317 };
+            }
+            317 => {
+                if guard.is_modified(317) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            318 => {
+                if guard.is_modified(318) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 640;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 320;
+            }
+            320 => {
+                if guard.is_modified(320) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 322;
+                break Native::Paused(State::Output(val));
+            }
+            322 => {
+                if guard.is_modified(322) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                program.count_instruction();
+                ip = 324;
+                break Native::Paused(State::Output(val));
+            }
+            323 => {
+                if guard.is_modified(323) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21102];
+                let rhs: i64 = program.mem()[932700857236];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 327;
+            }
+            324 => {
+                if guard.is_modified(324) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 932700857236;
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 328;
+            }
+            326 => {
+                if guard.is_modified(326) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[21101];
+                let addr = 335;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 330;
+            }
+            327 => {
+                if guard.is_modified(327) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[335];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 331;
+            }
+            328 => {
+                if guard.is_modified(328) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 335;
+                let rhs: i64 = 0;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 332;
+            }
+            332 => {
+                if guard.is_modified(332) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 439;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 335 };
+            }
+            335 => {
+                if guard.is_modified(335) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 387511792424;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 339;
+            }
+            338 => {
+                if guard.is_modified(338) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[346];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 342;
+            }
+            339 => {
+                if guard.is_modified(339) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 346;
+                let rhs: i64 = 0;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 343;
+            }
+            343 => {
+                if guard.is_modified(343) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 439;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 346 };
+            }
+            346 => {
+                if guard.is_modified(346) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 10;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 348;
+            }
+            348 => {
+                if guard.is_modified(348) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 350;
+                break Native::Paused(State::Output(val));
+            }
+            350 => {
+                if guard.is_modified(350) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                program.count_instruction();
+                ip = 352;
+                break Native::Paused(State::Output(val));
+            }
+            351 => {
+                if guard.is_modified(351) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[3];
+                let rhs: i64 = program.mem()[10];
+                let addr = 104;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 355;
+            }
+            352 => {
+                if guard.is_modified(352) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 10;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 354;
+            }
+            354 => {
+                if guard.is_modified(354) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 356;
+                break Native::Paused(State::Output(val));
+            }
+            356 => {
+                if guard.is_modified(356) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 358;
+                break Native::Paused(State::Output(val));
+            }
+            358 => {
+                if guard.is_modified(358) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 10;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 360;
+            }
+            360 => {
+                if guard.is_modified(360) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 362;
+                break Native::Paused(State::Output(val));
+            }
+            362 => {
+                if guard.is_modified(362) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                program.count_instruction();
+                ip = 364;
+                break Native::Paused(State::Output(val));
+            }
+            363 => {
+                if guard.is_modified(363) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[3];
+                let rhs: i64 = program.mem()[10];
+                let addr = 104;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 367;
+            }
+            364 => {
+                if guard.is_modified(364) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 10;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 366;
+            }
+            366 => {
+                if guard.is_modified(366) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 368;
+                break Native::Paused(State::Output(val));
+            }
+            368 => {
+                if guard.is_modified(368) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                program.count_instruction();
+                ip = 370;
+                break Native::Paused(State::Output(val));
+            }
+            369 => {
+                if guard.is_modified(369) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[3];
+                let rhs: i64 = program.mem()[10];
+                let addr = 104;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 373;
+            }
+            370 => {
+                if guard.is_modified(370) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 10;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 372;
+            }
+            372 => {
+                if guard.is_modified(372) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 374;
+                break Native::Paused(State::Output(val));
+            }
+            374 => {
+                if guard.is_modified(374) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 376;
+                break Native::Paused(State::Output(val));
+            }
+            376 => {
+                if guard.is_modified(376) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 10;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 378;
+            }
+            378 => {
+                if guard.is_modified(378) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 380;
+                break Native::Paused(State::Output(val));
+            }
+            380 => {
+                if guard.is_modified(380) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                program.count_instruction();
+                ip = 382;
+                break Native::Paused(State::Output(val));
+            }
+            381 => {
+                if guard.is_modified(381) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[46372252675];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 385;
+            }
+            382 => {
+                if guard.is_modified(382) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 46372252675;
+                let rhs: i64 = 0;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 386;
+            }
+            385 => {
+                if guard.is_modified(385) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21102];
+                let rhs: i64 = program.mem()[393];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 389;
+            }
+            386 => {
+                if guard.is_modified(386) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 393;
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 390;
+            }
+            388 => {
+                if guard.is_modified(388) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[1106];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 392;
+            }
+            390 => {
+                if guard.is_modified(390) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 439;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 393 };
+            }
+            393 => {
+                if guard.is_modified(393) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 97806162983;
+                let rhs: i64 = 0;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 397;
+            }
+            396 => {
+                if guard.is_modified(396) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21102];
+                let rhs: i64 = program.mem()[404];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 400;
+            }
+            397 => {
+                if guard.is_modified(397) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 404;
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 401;
+            }
+            399 => {
+                if guard.is_modified(399) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[1105];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 403;
+            }
+            401 => {
+                if guard.is_modified(401) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 439;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 404 };
+            }
+            402 => {
+                if guard.is_modified(402) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[439];
+                let rhs: i64 = program.mem()[3];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 406;
+            }
+            404 => {
+                if guard.is_modified(404) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 10;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 406;
+            }
+            406 => {
+                if guard.is_modified(406) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 408;
+                break Native::Paused(State::Output(val));
+            }
+            408 => {
+                if guard.is_modified(408) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 410;
+                break Native::Paused(State::Output(val));
+            }
+            410 => {
+                if guard.is_modified(410) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 10;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 412;
+            }
+            412 => {
+                if guard.is_modified(412) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 414;
+                break Native::Paused(State::Output(val));
+            }
+            414 => {
+                if guard.is_modified(414) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 416;
+                break Native::Paused(State::Output(val));
+            }
+            416 => {
+                if guard.is_modified(416) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 825452438376;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 420;
+            }
+            417 => {
+                if guard.is_modified(417) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[825452438376];
+                let rhs: i64 = program.mem()[1];
+                let addr = 21101;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 421;
+            }
+            419 => {
+                if guard.is_modified(419) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[0];
+                let addr = 427;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 423;
+            }
+            420 => {
+                if guard.is_modified(420) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 427;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 424;
+            }
+            424 => {
+                if guard.is_modified(424) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 439;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 427 };
+            }
+            427 => {
+                if guard.is_modified(427) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 709475586836;
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 431;
+            }
+            429 => {
+                if guard.is_modified(429) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[21101];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 433;
+            }
+            430 => {
+                if guard.is_modified(430) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[0];
+                let addr = 438;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 434;
+            }
+            431 => {
+                if guard.is_modified(431) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 438;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 435;
+            }
+            435 => {
+                if guard.is_modified(435) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 439;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 438 };
+            }
+            438 => {
+                if guard.is_modified(438) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            439 => {
+                if guard.is_modified(439) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 2;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 441;
+            }
+            441 => {
+                if guard.is_modified(441) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = match rel(rb, -1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 445;
+            }
+            444 => {
+                if guard.is_modified(444) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[40];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 448;
+            }
+            445 => {
+                if guard.is_modified(445) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 40;
+                let rhs: i64 = 0;
+                let addr = match rel(rb, 2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 449;
+            }
+            448 => {
+                if guard.is_modified(448) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21102];
+                let rhs: i64 = program.mem()[1];
+                let addr = 470;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 452;
+            }
+            449 => {
+                if guard.is_modified(449) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 470;
+                let addr = match rel(rb, 3) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 453;
+            }
+            450 => {
+                if guard.is_modified(450) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[470];
+                let rhs: i64 = program.mem()[3];
+                let addr = 21102;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 454;
+            }
+            452 => {
+                if guard.is_modified(452) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 21102;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 454;
+            }
+            453 => {
+                if guard.is_modified(453) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 460;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 457;
+            }
+            454 => {
+                if guard.is_modified(454) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[460];
+                let rhs: i64 = program.mem()[0];
+                let addr = 1106;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 458;
+            }
+            457 => {
+                if guard.is_modified(457) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 503;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 460 };
+            }
+            460 => {
+                if guard.is_modified(460) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -2;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 462;
+            }
+            462 => {
+                if guard.is_modified(462) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target: i64 = match rel(rb, 0) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 465 };
+            }
+            466 => {
+                if guard.is_modified(466) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 470;
+            }
+            469 => {
+                if guard.is_modified(469) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[109];
+                let rhs: i64 = program.mem()[2];
+                let addr = 3;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 473;
+            }
+            470 => {
+                if guard.is_modified(470) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 2;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 472;
+            }
+            471 => {
+                if guard.is_modified(471) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[3];
+                let rhs: i64 = program.mem()[10];
+                let addr = 204;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 475;
+            }
+            472 => {
+                if guard.is_modified(472) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 10;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 474;
+            }
+            474 => {
+                if guard.is_modified(474) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, -1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                program.count_instruction();
+                ip = 476;
+                break Native::Paused(State::Output(val));
+            }
+            476 => {
+                if guard.is_modified(476) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[465];
+                let rhs: i64 = 466;
+                let addr = 481;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 480;
+            }
+            480 => {
+                if guard.is_modified(480) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[0];
+                program.count_instruction();
+                ip = 482;
+                break Native::Paused(State::Output(val));
+            }
+            482 => {
+                if guard.is_modified(482) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[465];
+                let rhs: i64 = 1;
+                let addr = 465;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 486;
+            }
+            484 => {
+                if guard.is_modified(484) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[465];
+                let rhs: i64 = program.mem()[108];
+                let addr = 4;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 488;
+            }
+            486 => {
+                if guard.is_modified(486) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 4;
+                let rhs: i64 = program.mem()[465];
+                let addr = 10;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 490;
+            }
+            487 => {
+                if guard.is_modified(487) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[465];
+                program.count_instruction();
+                ip = 489;
+                break Native::Paused(State::Output(val));
+            }
+            490 => {
+                if guard.is_modified(490) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                let target = 497;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 493 };
+            }
+            493 => {
+                if guard.is_modified(493) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 0;
+                let addr = 465;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 497;
+            }
+            497 => {
+                if guard.is_modified(497) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -2;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 499;
+            }
+            499 => {
+                if guard.is_modified(499) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target: i64 = match rel(rb, 0) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 502 };
+            }
+            500 => {
+                if guard.is_modified(500) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 109;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 504;
+            }
+            503 => {
+                if guard.is_modified(503) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 4;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 505;
+            }
+            504 => {
+                if guard.is_modified(504) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[2102];
+                program.count_instruction();
+                ip = 506;
+                break Native::Paused(State::Output(val));
+            }
+            505 => {
+                if guard.is_modified(505) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = match rel(rb, -1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = 502;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 509;
+            }
+            509 => {
+                if guard.is_modified(509) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 0;
+                let addr = 10;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 513;
+            }
+            513 => {
+                if guard.is_modified(513) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                let target = 520;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 516 };
+            }
+            516 => {
+                if guard.is_modified(516) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 0;
+                let addr = match rel(rb, -3) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 520;
+            }
+            520 => {
+                if guard.is_modified(520) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 524;
+            }
+            524 => {
+                if guard.is_modified(524) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 528;
+            }
+            526 => {
+                if guard.is_modified(526) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[21101];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 530;
+            }
+            527 => {
+                if guard.is_modified(527) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[0];
+                let addr = 1;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 531;
+            }
+            528 => {
+                if guard.is_modified(528) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 3) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 532;
+            }
+            530 => {
+                if guard.is_modified(530) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[3];
+                let rhs: i64 = program.mem()[21101];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 534;
+            }
+            531 => {
+                if guard.is_modified(531) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 21101;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 533;
+            }
+            532 => {
+                if guard.is_modified(532) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 539;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 536;
+            }
+            536 => {
+                if guard.is_modified(536) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 544;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 539 };
+            }
+            539 => {
+                if guard.is_modified(539) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -4;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 541;
+            }
+            541 => {
+                if guard.is_modified(541) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target: i64 = match rel(rb, 0) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 544 };
+            }
+            542 => {
+                if guard.is_modified(542) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[109];
+                let addr = 5;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 546;
+            }
+            544 => {
+                if guard.is_modified(544) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 5;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 546;
+            }
+            546 => {
+                if guard.is_modified(546) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 1;
+                let addr = 10;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 550;
+            }
+            548 => {
+                if guard.is_modified(548) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[10];
+                let rhs: i64 = program.mem()[1006];
+                let addr = 10;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 552;
+            }
+            550 => {
+                if guard.is_modified(550) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                let target = 567;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 553 };
+            }
+            553 => {
+                if guard.is_modified(553) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -4) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = 10;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 557;
+            }
+            557 => {
+                if guard.is_modified(557) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                let target = 567;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 560 };
+            }
+            560 => {
+                if guard.is_modified(560) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = match rel(rb, -4) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = match rel(rb, -4) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 564;
+            }
+            564 => {
+                if guard.is_modified(564) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 635;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 567 };
+            }
+            567 => {
+                if guard.is_modified(567) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -4) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 571;
+            }
+            571 => {
+                if guard.is_modified(571) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = -1;
+                let addr = match rel(rb, 2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 575;
+            }
+            575 => {
+                if guard.is_modified(575) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 2;
+                let addr = match rel(rb, 3) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 579;
+            }
+            577 => {
+                if guard.is_modified(577) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[3];
+                let rhs: i64 = program.mem()[21102];
+                let addr = 586;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 581;
+            }
+            578 => {
+                if guard.is_modified(578) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 21102;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 580;
+            }
+            579 => {
+                if guard.is_modified(579) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 586;
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 583;
+            }
+            581 => {
+                if guard.is_modified(581) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[1105];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 585;
+            }
+            583 => {
+                if guard.is_modified(583) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 544;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 586 };
+            }
+            584 => {
+                if guard.is_modified(584) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[544];
+                let rhs: i64 = program.mem()[22101];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 588;
+            }
+            586 => {
+                if guard.is_modified(586) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = match rel(rb, 1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = match rel(rb, -4) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 590;
+            }
+            590 => {
+                if guard.is_modified(590) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 1;
+                let addr = match rel(rb, -1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 594;
+            }
+            594 => {
+                if guard.is_modified(594) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -4) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = 10;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 598;
+            }
+            598 => {
+                if guard.is_modified(598) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                let target = 605;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 601 };
+            }
+            601 => {
+                if guard.is_modified(601) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 1;
+                let addr = match rel(rb, -1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 605;
+            }
+            605 => {
+                if guard.is_modified(605) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = match rel(rb, -1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = match rel(rb, -2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 609;
+            }
+            609 => {
+                if guard.is_modified(609) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = 10;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 613;
+            }
+            613 => {
+                if guard.is_modified(613) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[10];
+                let target = 627;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 616 };
+            }
+            616 => {
+                if guard.is_modified(616) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = match rel(rb, -1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 620;
+            }
+            619 => {
+                if guard.is_modified(619) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21102];
+                let rhs: i64 = program.mem()[1];
+                let addr = 627;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 623;
+            }
+            620 => {
+                if guard.is_modified(620) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 627;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 624;
+            }
+            621 => {
+                if guard.is_modified(621) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[627];
+                let rhs: i64 = program.mem()[0];
+                let addr = 106;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 625;
+            }
+            624 => {
+                if guard.is_modified(624) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target: i64 = program.mem()[502];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 627 };
+            }
+            627 => {
+                if guard.is_modified(627) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = -1;
+                let addr = match rel(rb, -2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 631;
+            }
+            631 => {
+                if guard.is_modified(631) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -4) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = match rel(rb, -4) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 635;
+            }
+            635 => {
+                if guard.is_modified(635) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -5;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 637;
+            }
+            637 => {
+                if guard.is_modified(637) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target: i64 = match rel(rb, 0) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 640 };
+            }
+            _ => break Native::Fallback,
+        }
+    };
+    program.set_ip(ip);
+    program.set_rel_base(rb);
+    native
+}
@@ -0,0 +1,6561 @@
+// Generated by intcode::translate, do not edit
+
+pub static TRANSLATED: intcode::Translated = intcode::Translated {
+    image: &IMAGE,
+    instrs: &INSTRS,
+    native,
+};
+
+static IMAGE: [i64; 2572] = [1, 380, 379, 385, 1008, 2571, 639943, 381, 1005, 381, 12, 99, 109, 2572, 1102, 0, 1, 383, 1102, 1, 0, 382, 20101, 0, 382, 1, 20102, 1, 383, 2, 21102, 37, 1, 0, 1105, 1, 578, 4, 382, 4, 383, 204, 1, 1001, 382, 1, 382, 1007, 382, 42, 381, 1005, 381, 22, 1001, 383, 1, 383, 1007, 383, 23, 381, 1005, 381, 18, 1006, 385, 69, 99, 104, -1, 104, 0, 4, 386, 3, 384, 1007, 384, 0, 381, 1005, 381, 94, 107, 0, 384, 381, 1005, 381, 108, 1105, 1, 161, 107, 1, 392, 381, 1006, 381, 161, 1102, -1, 1, 384, 1106, 0, 119, 1007, 392, 40, 381, 1006, 381, 161, 1101, 0, 1, 384, 21002, 392, 1, 1, 21102, 21, 1, 2, 21101, 0, 0, 3, 21102, 138, 1, 0, 1105, 1, 549, 1, 392, 384, 392, 21002, 392, 1, 1, 21102, 21, 1, 2, 21101, 3, 0, 3, 21101, 161, 0, 0, 1105, 1, 549, 1102, 1, 0, 384, 20001, 388, 390, 1, 20102, 1, 389, 2, 21102, 180, 1, 0, 1106, 0, 578, 1206, 1, 213, 1208, 1, 2, 381, 1006, 381, 205, 20001, 388, 390, 1, 21002, 389, 1, 2, 21101, 205, 0, 0, 1105, 1, 393, 1002, 390, -1, 390, 1102, 1, 1, 384, 20101, 0, 388, 1, 20001, 389, 391, 2, 21101, 0, 228, 0, 1105, 1, 578, 1206, 1, 261, 1208, 1, 2, 381, 1006, 381, 253, 21002, 388, 1, 1, 20001, 389, 391, 2, 21101, 253, 0, 0, 1105, 1, 393, 1002, 391, -1, 391, 1101, 0, 1, 384, 1005, 384, 161, 20001, 388, 390, 1, 20001, 389, 391, 2, 21101, 0, 279, 0, 1106, 0, 578, 1206, 1, 316, 1208, 1, 2, 381, 1006, 381, 304, 20001, 388, 390, 1, 20001, 389, 391, 2, 21102, 304, 1, 0, 1106, 0, 393, 1002, 390, -1, 390, 1002, 391, -1, 391, 1101, 1, 0, 384, 1005, 384, 161, 20102, 1, 388, 1, 20102, 1, 389, 2, 21102, 1, 0, 3, 21101, 338, 0, 0, 1106, 0, 549, 1, 388, 390, 388, 1, 389, 391, 389, 21002, 388, 1, 1, 21001, 389, 0, 2, 21101, 0, 4, 3, 21101, 0, 365, 0, 1105, 1, 549, 1007, 389, 22, 381, 1005, 381, 75, 104, -1, 104, 0, 104, 0, 99, 0, 1, 0, 0, 0, 0, 0, 0, 233, 19, 18, 1, 1, 21, 109, 3, 21202, -2, 1, 1, 22102, 1, -1, 2, 21101, 0, 0, 3, 21101, 414, 0, 0, 1106, 0, 549, 21202, -2, 1, 1, 21201, -1, 0, 2, 21101, 0, 429, 0, 1106, 0, 601, 2101, 0, 1, 435, 1, 386, 0, 386, 104, -1, 104, 0, 4, 386, 1001, 387, -1, 387, 1005, 387, 451, 99, 109, -3, 2106, 0, 0, 109, 8, 22202, -7, -6, -3, 22201, -3, -5, -3, 21202, -4, 64, -2, 2207, -3, -2, 381, 1005, 381, 492, 21202, -2, -1, -1, 22201, -3, -1, -3, 2207, -3, -2, 381, 1006, 381, 481, 21202, -4, 8, -2, 2207, -3, -2, 381, 1005, 381, 518, 21202, -2, -1, -1, 22201, -3, -1, -3, 2207, -3, -2, 381, 1006, 381, 507, 2207, -3, -4, 381, 1005, 381, 540, 21202, -4, -1, -1, 22201, -3, -1, -3, 2207, -3, -4, 381, 1006, 381, 529, 21201, -3, 0, -7, 109, -8, 2105, 1, 0, 109, 4, 1202, -2, 42, 566, 201, -3, 566, 566, 101, 639, 566, 566, 2101, 0, -1, 0, 204, -3, 204, -2, 204, -1, 109, -4, 2105, 1, 0, 109, 3, 1202, -1, 42, 593, 201, -2, 593, 593, 101, 639, 593, 593, 21001, 0, 0, -2, 109, -3, 2105, 1, 0, 109, 3, 22102, 23, -2, 1, 22201, 1, -1, 1, 21102, 487, 1, 2, 21101, 326, 0, 3, 21102, 1, 966, 4, 21101, 630, 0, 0, 1106, 0, 456, 21201, 1, 1605, -2, 109, -3, 2106, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 2, 2, 2, 0, 0, 0, 2, 2, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 2, 0, 0, 0, 0, 2, 2, 2, 0, 1, 1, 0, 2, 0, 2, 0, 2, 0, 0, 2, 2, 0, 2, 2, 2, 2, 0, 0, 0, 2, 0, 0, 2, 2, 2, 0, 2, 2, 0, 2, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 0, 2, 2, 0, 2, 0, 2, 2, 0, 2, 2, 0, 2, 0, 2, 2, 2, 0, 0, 2, 0, 1, 1, 0, 0, 2, 2, 2, 2, 2, 2, 2, 0, 0, 2, 0, 2, 0, 0, 0, 2, 0, 2, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 2, 2, 0, 2, 0, 0, 2, 2, 0, 0, 1, 1, 0, 2, 2, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 2, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0, 2, 2, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 2, 0, 2, 2, 2, 0, 2, 2, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 2, 2, 0, 0, 0, 2, 0, 2, 2, 0, 2, 0, 0, 0, 2, 0, 1, 1, 0, 0, 0, 0, 2, 0, 2, 2, 2, 2, 2, 0, 2, 0, 2, 2, 0, 0, 2, 2, 0, 2, 2, 2, 0, 2, 0, 2, 2, 0, 2, 2, 0, 2, 2, 2, 2, 2, 0, 0, 1, 1, 0, 0, 2, 0, 0, 0, 2, 0, 2, 2, 2, 2, 2, 0, 2, 0, 0, 2, 2, 0, 0, 2, 0, 2, 2, 0, 0, 2, 0, 2, 0, 2, 2, 2, 0, 2, 2, 2, 2, 0, 1, 1, 0, 2, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0, 2, 0, 2, 0, 2, 2, 0, 0, 0, 0, 2, 2, 2, 2, 2, 2, 0, 0, 2, 2, 0, 0, 2, 2, 0, 2, 0, 0, 1, 1, 0, 2, 0, 0, 0, 2, 0, 0, 0, 0, 2, 2, 0, 0, 2, 2, 2, 0, 0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 2, 0, 0, 2, 0, 2, 2, 2, 2, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 2, 2, 0, 2, 0, 2, 2, 0, 2, 0, 2, 2, 0, 2, 0, 0, 0, 0, 2, 0, 0, 0, 2, 2, 0, 2, 2, 0, 0, 0, 2, 2, 0, 0, 1, 1, 0, 0, 2, 0, 2, 2, 0, 2, 2, 2, 0, 0, 2, 2, 0, 0, 2, 2, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 0, 2, 0, 0, 1, 1, 0, 0, 2, 0, 0, 2, 0, 2, 0, 2, 0, 0, 2, 2, 2, 2, 2, 0, 2, 0, 0, 2, 2, 0, 0, 0, 2, 0, 0, 0, 2, 2, 0, 2, 0, 0, 2, 0, 0, 0, 1, 1, 0, 2, 2, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 0, 2, 0, 0, 0, 2, 0, 2, 0, 2, 2, 0, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 85, 93, 24, 97, 81, 73, 90, 65, 8, 89, 80, 17, 22, 56, 24, 44, 93, 59, 34, 85, 16, 26, 8, 85, 58, 86, 32, 75, 36, 78, 65, 80, 67, 83, 36, 22, 47, 30, 4, 17, 49, 44, 80, 79, 66, 53, 32, 98, 63, 63, 38, 69, 39, 8, 74, 77, 35, 93, 58, 2, 40, 74, 19, 95, 85, 34, 13, 26, 46, 94, 7, 77, 1, 80, 44, 80, 57, 25, 40, 36, 5, 66, 24, 4, 26, 69, 28, 34, 96, 28, 14, 47, 60, 2, 76, 36, 90, 20, 84, 9, 72, 34, 80, 30, 81, 91, 19, 50, 38, 65, 78, 80, 91, 43, 52, 59, 32, 98, 13, 11, 74, 84, 5, 64, 4, 92, 11, 51, 11, 36, 12, 94, 80, 7, 36, 15, 19, 78, 76, 81, 21, 88, 8, 31, 50, 23, 22, 80, 54, 72, 6, 6, 12, 81, 79, 80, 85, 33, 60, 67, 53, 8, 98, 35, 34, 11, 10, 34, 18, 42, 65, 14, 30, 89, 21, 85, 32, 50, 73, 4, 28, 81, 10, 94, 65, 65, 5, 14, 25, 47, 62, 4, 35, 62, 70, 66, 71, 88, 94, 83, 13, 28, 92, 93, 75, 86, 4, 7, 94, 15, 43, 74, 47, 3, 46, 82, 71, 56, 37, 95, 45, 29, 90, 48, 51, 19, 16, 65, 10, 7, 81, 98, 13, 96, 49, 90, 64, 94, 14, 68, 73, 35, 19, 34, 75, 37, 7, 5, 87, 44, 71, 52, 12, 27, 92, 27, 81, 63, 95, 38, 49, 61, 7, 92, 65, 37, 47, 63, 13, 2, 62, 70, 65, 24, 17, 59, 31, 7, 1, 15, 9, 48, 52, 74, 38, 27, 8, 53, 80, 66, 6, 72, 35, 39, 79, 11, 89, 80, 90, 53, 84, 7, 84, 64, 75, 15, 40, 48, 82, 30, 28, 98, 23, 90, 88, 92, 5, 34, 7, 23, 41, 87, 93, 65, 46, 90, 29, 24, 38, 56, 15, 62, 6, 89, 68, 81, 93, 43, 31, 60, 51, 45, 25, 15, 88, 25, 11, 88, 93, 60, 87, 4, 3, 93, 8, 50, 62, 27, 54, 7, 6, 18, 44, 65, 33, 69, 23, 45, 44, 69, 49, 11, 73, 81, 64, 18, 6, 74, 7, 77, 53, 18, 6, 98, 62, 35, 81, 48, 33, 64, 26, 16, 55, 17, 71, 75, 72, 39, 6, 54, 67, 97, 68, 59, 65, 3, 70, 75, 91, 98, 87, 41, 94, 16, 1, 14, 27, 11, 19, 52, 53, 18, 47, 27, 29, 59, 6, 37, 86, 71, 12, 62, 84, 26, 36, 22, 74, 95, 77, 59, 66, 70, 95, 91, 2, 59, 39, 50, 81, 51, 75, 87, 30, 22, 30, 64, 54, 53, 64, 33, 37, 88, 24, 90, 24, 32, 36, 95, 39, 54, 6, 85, 10, 15, 53, 82, 13, 41, 50, 10, 67, 87, 51, 87, 15, 80, 27, 10, 32, 97, 77, 90, 41, 34, 45, 14, 11, 95, 79, 58, 31, 49, 59, 86, 50, 89, 22, 92, 68, 51, 74, 28, 38, 16, 68, 29, 6, 23, 37, 19, 55, 71, 5, 73, 38, 66, 12, 21, 45, 51, 69, 89, 51, 2, 9, 61, 62, 97, 92, 74, 23, 8, 23, 82, 56, 74, 9, 9, 73, 69, 3, 25, 81, 18, 67, 64, 5, 86, 96, 49, 22, 86, 93, 36, 15, 28, 31, 55, 62, 81, 78, 66, 65, 55, 68, 71, 92, 66, 65, 48, 64, 75, 16, 42, 26, 12, 49, 27, 33, 40, 66, 43, 37, 51, 90, 32, 96, 63, 96, 42, 61, 70, 97, 96, 79, 67, 59, 12, 17, 81, 12, 39, 64, 80, 1, 28, 2, 83, 35, 20, 54, 69, 97, 11, 96, 96, 22, 60, 92, 79, 28, 35, 32, 24, 19, 96, 85, 26, 20, 38, 72, 60, 41, 62, 14, 51, 54, 60, 45, 35, 37, 17, 85, 53, 27, 1, 87, 55, 25, 48, 46, 10, 70, 16, 13, 16, 77, 10, 73, 70, 65, 58, 57, 62, 16, 59, 51, 70, 93, 55, 42, 79, 46, 12, 88, 28, 96, 53, 82, 61, 75, 67, 58, 13, 42, 11, 64, 3, 76, 15, 60, 40, 6, 57, 71, 68, 26, 48, 57, 80, 61, 28, 88, 44, 92, 49, 49, 38, 46, 37, 40, 34, 1, 18, 97, 72, 49, 53, 49, 35, 88, 44, 5, 29, 81, 6, 21, 87, 77, 25, 36, 91, 33, 22, 74, 22, 85, 55, 39, 98, 21, 6, 43, 26, 2, 60, 36, 34, 72, 39, 25, 40, 51, 37, 65, 69, 48, 4, 18, 42, 49, 13, 52, 80, 39, 65, 94, 92, 27, 34, 58, 83, 93, 5, 34, 2, 11, 90, 37, 73, 77, 84, 68, 63, 78, 63, 34, 32, 47, 26, 90, 72, 61, 89, 5, 78, 26, 80, 92, 29, 36, 76, 48, 53, 61, 53, 14, 42, 1, 2, 34, 37, 36, 16, 70, 27, 1, 50, 26, 29, 92, 53, 58, 19, 90, 47, 27, 35, 30, 72, 76, 54, 85, 33, 26, 65, 77, 39, 7, 3, 8, 67, 87, 25, 12, 43, 74, 95, 97, 12, 23, 47, 62, 70, 62, 22, 98, 76, 17, 14, 77, 50, 91, 20, 15, 42, 34, 20, 65, 79, 87, 27, 44, 12, 95, 2, 97, 97, 43, 62, 76, 65, 48, 71, 83, 72, 33, 44, 17, 65, 40, 86, 12, 89, 97, 54, 36, 62, 78, 65, 15, 7, 37, 25, 31, 3, 16, 33, 91, 56, 67, 66, 29, 61, 40, 91, 32, 27, 13, 8, 55, 89, 54, 69, 76, 16, 67, 66, 54, 7, 75, 84, 6, 64, 15, 29, 56, 88, 57, 49, 26, 57, 70, 19, 3, 62, 79, 61, 62, 55, 49, 16, 94, 94, 53, 79, 639943];
+
+// addr and size of the translated instructions
+static INSTRS: [(usize, usize); 687] = [(4, 4), (8, 3), (11, 1), (12, 2), (14, 4), (16, 4), (18, 4), (19, 4), (22, 4), (25, 4), (26, 4), (27, 4), (29, 4), (30, 4), (32, 4), (34, 3), (35, 4), (37, 2), (39, 2), (41, 2), (42, 4), (43, 4), (45, 4), (47, 4), (51, 3), (54, 4), (56, 4), (58, 4), (62, 3), (65, 3), (68, 1), (69, 2), (71, 2), (73, 2), (75, 2), (77, 4), (81, 3), (84, 4), (88, 3), (90, 4), (91, 3), (92, 4), (94, 4), (95, 4), (98, 3), (101, 4), (103, 4), (105, 3), (108, 4), (112, 3), (115, 4), (117, 4), (119, 4), (121, 4), (122, 4), (123, 4), (125, 4), (126, 4), (127, 4), (130, 2), (131, 4), (133, 4), (135, 3), (136, 4), (138, 4), (142, 4), (144, 4), (145, 4), (146, 4), (148, 4), (149, 4), (150, 4), (151, 2), (153, 2), (154, 4), (158, 3), (159, 4), (161, 4), (162, 4), (165, 4), (168, 4), (169, 4), (170, 4), (172, 4), (173, 4), (175, 4), (177, 3), (180, 3), (181, 4), (183, 4), (184, 4), (185, 4), (187, 3), (189, 3), (190, 4), (193, 4), (194, 4), (196, 4), (197, 4), (198, 4), (199, 3), (202, 3), (203, 4), (205, 4), (209, 4), (210, 4), (211, 4), (213, 4), (216, 4), (217, 4), (220, 4), (221, 4), (225, 3), (226, 4), (228, 3), (229, 4), (231, 4), (232, 4), (233, 4), (235, 3), (238, 4), (240, 4), (241, 4), (242, 4), (245, 4), (246, 4), (250, 3), (251, 4), (253, 4), (257, 4), (259, 4), (261, 3), (264, 4), (267, 4), (268, 4), (271, 4), (272, 4), (276, 3), (279, 3), (280, 4), (282, 4), (283, 4), (284, 4), (286, 3), (289, 4), (292, 4), (293, 4), (296, 4), (297, 4), (299, 4), (301, 3), (304, 4), (308, 4), (312, 4), (313, 4), (316, 3), (319, 4), (320, 4), (322, 4), (323, 4), (324, 4), (326, 4), (327, 4), (328, 4), (330, 2), (331, 4), (335, 3), (338, 4), (342, 4), (346, 4), (348, 4), (349, 4), (350, 4), (353, 4), (354, 4), (356, 2), (357, 2), (358, 4), (362, 3), (363, 4), (365, 4), (369, 3), (372, 2), (374, 2), (376, 2), (378, 1), (380, 4), (390, 4), (391, 4), (393, 2), (394, 2), (395, 4), (397, 4), (399, 4), (402, 4), (403, 4), (406, 2), (407, 4), (411, 3), (414, 4), (418, 4), (421, 4), (422, 4), (426, 3), (429, 4), (431, 4), (437, 2), (439, 2), (441, 2), (443, 4), (447, 3), (450, 1), (451, 2), (453, 3), (456, 2), (458, 4), (462, 4), (466, 4), (470, 4), (474, 3), (477, 4), (481, 4), (485, 4), (489, 3), (492, 4), (496, 4), (500, 3), (503, 4), (507, 4), (511, 4), (515, 3), (518, 4), (522, 3), (525, 4), (529, 4), (533, 4), (537, 3), (540, 4), (544, 2), (546, 3), (547, 4), (549, 2), (550, 2), (551, 4), (555, 4), (559, 4), (567, 2), (569, 2), (571, 2), (573, 2), (575, 3), (576, 4), (578, 2), (579, 2), (580, 4), (584, 4), (588, 4), (596, 2), (598, 3), (599, 4), (601, 2), (602, 2), (603, 4), (607, 4), (610, 4), (611, 4), (613, 4), (614, 4), (615, 4), (618, 2), (619, 4), (620, 4), (622, 2), (623, 4), (627, 3), (630, 4), (634, 2), (636, 3), (639, 4), (640, 4), (641, 4), (642, 4), (643, 4), (644, 4), (645, 4), (646, 4), (647, 4), (648, 4), (649, 4), (650, 4), (651, 4), (652, 4), (653, 4), (654, 4), (655, 4), (656, 4), (657, 4), (658, 4), (659, 4), (660, 4), (661, 4), (662, 4), (663, 4), (664, 4), (665, 4), (666, 4), (667, 4), (668, 4), (669, 4), (670, 4), (671, 4), (672, 4), (673, 4), (674, 4), (675, 4), (676, 4), (677, 4), (678, 4), (679, 4), (680, 4), (681, 4), (722, 4), (723, 4), (728, 4), (729, 4), (730, 4), (734, 4), (735, 4), (740, 4), (751, 4), (753, 4), (755, 4), (760, 4), (761, 4), (762, 4), (764, 4), (765, 4), (767, 4), (769, 4), (771, 4), (774, 4), (775, 4), (777, 4), (778, 4), (779, 4), (780, 4), (784, 4), (787, 4), (788, 4), (789, 4), (791, 4), (792, 4), (794, 4), (799, 4), (806, 4), (807, 4), (812, 4), (816, 4), (819, 4), (824, 4), (828, 4), (829, 4), (831, 4), (833, 4), (834, 4), (836, 4), (837, 4), (839, 4), (841, 4), (842, 4), (843, 4), (846, 4), (848, 4), (849, 4), (852, 4), (853, 4), (854, 4), (855, 4), (856, 4), (857, 4), (858, 4), (861, 4), (863, 4), (867, 4), (869, 4), (872, 4), (877, 4), (880, 4), (881, 4), (883, 4), (886, 4), (887, 4), (890, 4), (891, 4), (893, 4), (894, 4), (900, 4), (901, 4), (910, 4), (911, 4), (924, 4), (926, 4), (929, 4), (932, 4), (933, 4), (937, 4), (940, 4), (946, 4), (949, 4), (950, 4), (953, 4), (955, 4), (974, 4), (975, 4), (981, 4), (983, 4), (984, 4), (985, 4), (987, 4), (988, 4), (995, 4), (997, 4), (1000, 4), (1001, 4), (1005, 4), (1007, 4), (1008, 4), (1010, 4), (1014, 4), (1016, 4), (1017, 4), (1022, 4), (1024, 4), (1025, 4), (1026, 4), (1027, 4), (1028, 4), (1030, 4), (1032, 4), (1033, 4), (1036, 4), (1037, 4), (1039, 4), (1040, 4), (1041, 4), (1043, 4), (1045, 4), (1046, 4), (1048, 4), (1049, 4), (1051, 4), (1052, 4), (1053, 4), (1054, 4), (1055, 4), (1058, 4), (1059, 4), (1062, 4), (1066, 4), (1068, 4), (1069, 4), (1070, 4), (1071, 4), (1072, 4), (1074, 4), (1077, 4), (1078, 4), (1081, 4), (1083, 4), (1084, 4), (1087, 4), (1089, 4), (1091, 4), (1092, 4), (1093, 4), (1095, 4), (1096, 4), (1097, 4), (1098, 4), (1100, 4), (1101, 4), (1103, 4), (1107, 4), (1110, 4), (1114, 4), (1116, 4), (1118, 4), (1119, 4), (1124, 4), (1125, 4), (1126, 4), (1127, 4), (1128, 4), (1129, 4), (1132, 4), (1133, 4), (1136, 4), (1137, 4), (1139, 4), (1142, 4), (1143, 4), (1145, 4), (1149, 4), (1154, 4), (1155, 4), (1158, 4), (1159, 4), (1160, 4), (1166, 4), (1170, 4), (1172, 4), (1175, 4), (1177, 4), (1178, 4), (1179, 4), (1180, 4), (1184, 4), (1185, 4), (1192, 4), (1193, 4), (1195, 4), (1197, 4), (1198, 4), (1200, 4), (1202, 4), (1203, 4), (1205, 4), (1210, 4), (1214, 4), (1215, 4), (1217, 4), (1218, 4), (1222, 4), (1223, 4), (1226, 4), (1227, 4), (1230, 4), (1232, 4), (1233, 4), (1235, 4), (1236, 4), (1237, 4), (1240, 4), (1241, 4), (1244, 4), (1245, 4), (1246, 4), (1247, 4), (1259, 4), (1261, 4), (1265, 4), (1268, 4), (1269, 4), (1272, 4), (1275, 4), (1277, 4), (1279, 4), (1282, 4), (1283, 4), (1284, 4), (1285, 4), (1286, 4), (1288, 4), (1291, 4), (1292, 4), (1296, 4), (1300, 4), (1301, 4), (1303, 4), (1306, 4), (1310, 4), (1311, 4), (1313, 4), (1314, 4), (1317, 4), (1324, 4), (1326, 4), (1329, 4), (1333, 4), (1335, 4), (1337, 4), (1338, 4), (1341, 4), (1347, 4), (1352, 4), (1353, 4), (1394, 4), (1395, 4), (1414, 2), (1436, 4), (1437, 4), (1478, 4), (1479, 4), (1520, 4), (1521, 4), (1542, 2), (1562, 4), (1563, 4), (1604, 4), (1613, 4), (1627, 4), (1643, 2), (1658, 4), (1664, 4), (1675, 4), (1677, 4), (1685, 3), (1688, 2), (1698, 4), (1704, 2), (1727, 3), (1729, 2), (1738, 4), (1747, 4), (1755, 3), (1756, 3), (1766, 4), (1784, 2), (1791, 3), (1796, 2), (1811, 2), (1812, 4), (1818, 2), (1834, 4), (1851, 4), (1852, 3), (1867, 4), (1874, 4), (1882, 4), (1883, 4), (1885, 2), (1891, 4), (1895, 3), (1906, 4), (1921, 3), (1923, 4), (1937, 3), (1956, 2), (1957, 2), (1959, 4), (1964, 4), (1965, 3), (1981, 3), (1983, 4), (1987, 3), (2003, 3), (2010, 2), (2019, 4), (2031, 3), (2049, 4), (2075, 3), (2121, 3), (2127, 3), (2138, 4), (2139, 2), (2146, 4), (2151, 2), (2152, 2), (2155, 2), (2161, 3), (2219, 4), (2221, 4), (2260, 4), (2302, 2), (2307, 3), (2327, 4), (2337, 3), (2340, 3), (2356, 3), (2359, 4), (2372, 2), (2388, 3), (2390, 4), (2409, 3), (2423, 4), (2424, 4), (2431, 4), (2453, 4), (2454, 2), (2455, 4), (2490, 4), (2516, 4), (2520, 2), (2534, 4), (2544, 4), (2547, 3), (2559, 2)];
+
+#[inline(always)]
+#[allow(dead_code)]
+fn rel(rb: i64, offset: i64) -> Option<usize> {
+    match rb.checked_add(offset) {
+        Some(addr) if addr >= 0 => Some(addr as usize),
+        _ => None,
+    }
+}
+
+#[allow(unused_mut, clippy::all)]
+fn native(program: &mut intcode::Program, guard: &mut intcode::CodeGuard) -> intcode::Native {
+    use intcode::{Native, State};
+    let mut ip = program.ip();
+    let mut rb = program.rel_base();
+    let native = loop {
+        match ip {
+            4 => {
+                if guard.is_modified(4) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2571];
+                let rhs: i64 = 639943;
+                let addr = 381;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 8;
+            }
+            8 => {
+                if guard.is_modified(8) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[381];
+                let target = 12;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 11 };
+            }
+            11 => {
+                if guard.is_modified(11) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            12 => {
+                if guard.is_modified(12) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 2572;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 14;
+            }
+            14 => {
+                if guard.is_modified(14) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 1;
+                let addr = 383;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 18;
+            }
+            16 => {
+                if guard.is_modified(16) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[383];
+                let rhs: i64 = program.mem()[1102];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 20;
+            }
+            18 => {
+                if guard.is_modified(18) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 0;
+                let addr = 382;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 22;
+            }
+            19 => {
+                if guard.is_modified(19) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[382];
+                let addr = 20101;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 23;
+            }
+            22 => {
+                if guard.is_modified(22) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = program.mem()[382];
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 26;
+            }
+            25 => {
+                if guard.is_modified(25) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[20102];
+                let rhs: i64 = program.mem()[1];
+                let addr = 383;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 29;
+            }
+            26 => {
+                if guard.is_modified(26) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[383];
+                let addr = match rel(rb, 2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 30;
+            }
+            27 => {
+                if guard.is_modified(27) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[383];
+                let rhs: i64 = program.mem()[2];
+                let addr = 21102;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 31;
+            }
+            29 => {
+                if guard.is_modified(29) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21102];
+                let rhs: i64 = program.mem()[37];
+                let addr = 1;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 33;
+            }
+            30 => {
+                if guard.is_modified(30) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 37;
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 34;
+            }
+            32 => {
+                if guard.is_modified(32) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[1105];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 36;
+            }
+            34 => {
+                if guard.is_modified(34) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 578;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 37 };
+            }
+            35 => {
+                if guard.is_modified(35) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[578];
+                let rhs: i64 = program.mem()[4];
+                let addr = 382;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 39;
+            }
+            37 => {
+                if guard.is_modified(37) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[382];
+                program.count_instruction();
+                ip = 39;
+                break Native::Paused(State::Output(val));
+            }
+            39 => {
+                if guard.is_modified(39) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[383];
+                program.count_instruction();
+                ip = 41;
+                break Native::Paused(State::Output(val));
+            }
+            41 => {
+                if guard.is_modified(41) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, 1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                program.count_instruction();
+                ip = 43;
+                break Native::Paused(State::Output(val));
+            }
+            42 => {
+                if guard.is_modified(42) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1001];
+                let rhs: i64 = program.mem()[382];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 46;
+            }
+            43 => {
+                if guard.is_modified(43) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[382];
+                let rhs: i64 = 1;
+                let addr = 382;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 47;
+            }
+            45 => {
+                if guard.is_modified(45) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[382];
+                let rhs: i64 = program.mem()[1007];
+                let addr = 382;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 49;
+            }
+            47 => {
+                if guard.is_modified(47) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[382];
+                let rhs: i64 = 42;
+                let addr = 381;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 51;
+            }
+            51 => {
+                if guard.is_modified(51) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[381];
+                let target = 22;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 54 };
+            }
+            54 => {
+                if guard.is_modified(54) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[383];
+                let rhs: i64 = 1;
+                let addr = 383;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 58;
+            }
+            56 => {
+                if guard.is_modified(56) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[383];
+                let rhs: i64 = program.mem()[1007];
+                let addr = 383;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 60;
+            }
+            58 => {
+                if guard.is_modified(58) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[383];
+                let rhs: i64 = 23;
+                let addr = 381;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 62;
+            }
+            62 => {
+                if guard.is_modified(62) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[381];
+                let target = 18;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 65 };
+            }
+            65 => {
+                if guard.is_modified(65) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[385];
+                let target = 69;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 68 };
+            }
+            68 => {
+                if guard.is_modified(68) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            69 => {
+                if guard.is_modified(69) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -1;
+                program.count_instruction();
+                ip = 71;
+                break Native::Paused(State::Output(val));
+            }
+            71 => {
+                if guard.is_modified(71) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 73;
+                break Native::Paused(State::Output(val));
+            }
+            73 => {
+                if guard.is_modified(73) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[386];
+                program.count_instruction();
+                ip = 75;
+                break Native::Paused(State::Output(val));
+            }
+            75 => {
+                if guard.is_modified(75) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 384;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 77;
+            }
+            77 => {
+                if guard.is_modified(77) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[384];
+                let rhs: i64 = 0;
+                let addr = 381;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 81;
+            }
+            81 => {
+                if guard.is_modified(81) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[381];
+                let target = 94;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 84 };
+            }
+            84 => {
+                if guard.is_modified(84) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = program.mem()[384];
+                let addr = 381;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 88;
+            }
+            88 => {
+                if guard.is_modified(88) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[381];
+                let target = 108;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 91 };
+            }
+            90 => {
+                if guard.is_modified(90) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1105;
+                let rhs: i64 = program.mem()[1];
+                let addr = 161;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 94;
+            }
+            91 => {
+                if guard.is_modified(91) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 161;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 94 };
+            }
+            92 => {
+                if guard.is_modified(92) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[161];
+                let rhs: i64 = program.mem()[107];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 96;
+            }
+            94 => {
+                if guard.is_modified(94) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[392];
+                let addr = 381;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 98;
+            }
+            95 => {
+                if guard.is_modified(95) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[392];
+                let rhs: i64 = program.mem()[381];
+                let addr = 1006;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 99;
+            }
+            98 => {
+                if guard.is_modified(98) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[381];
+                let target = 161;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 101 };
+            }
+            101 => {
+                if guard.is_modified(101) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = -1;
+                let rhs: i64 = 1;
+                let addr = 384;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 105;
+            }
+            103 => {
+                if guard.is_modified(103) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[384];
+                let rhs: i64 = program.mem()[1106];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 107;
+            }
+            105 => {
+                if guard.is_modified(105) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 119;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 108 };
+            }
+            108 => {
+                if guard.is_modified(108) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[392];
+                let rhs: i64 = 40;
+                let addr = 381;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 112;
+            }
+            112 => {
+                if guard.is_modified(112) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[381];
+                let target = 161;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 115 };
+            }
+            115 => {
+                if guard.is_modified(115) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 1;
+                let addr = 384;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 119;
+            }
+            117 => {
+                if guard.is_modified(117) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[384];
+                let rhs: i64 = program.mem()[21002];
+                let addr = 392;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 121;
+            }
+            119 => {
+                if guard.is_modified(119) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[392];
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 123;
+            }
+            121 => {
+                if guard.is_modified(121) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[21102];
+                let addr = 21;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 125;
+            }
+            122 => {
+                if guard.is_modified(122) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21102];
+                let rhs: i64 = program.mem()[21];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 126;
+            }
+            123 => {
+                if guard.is_modified(123) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 21;
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 127;
+            }
+            125 => {
+                if guard.is_modified(125) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[21101];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 129;
+            }
+            126 => {
+                if guard.is_modified(126) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 130;
+            }
+            127 => {
+                if guard.is_modified(127) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 0;
+                let addr = match rel(rb, 3) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 131;
+            }
+            130 => {
+                if guard.is_modified(130) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 21102;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 132;
+            }
+            131 => {
+                if guard.is_modified(131) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 138;
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 135;
+            }
+            133 => {
+                if guard.is_modified(133) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[1105];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 137;
+            }
+            135 => {
+                if guard.is_modified(135) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 549;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 138 };
+            }
+            136 => {
+                if guard.is_modified(136) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[549];
+                let rhs: i64 = program.mem()[1];
+                let addr = 392;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 140;
+            }
+            138 => {
+                if guard.is_modified(138) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[392];
+                let rhs: i64 = program.mem()[384];
+                let addr = 392;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 142;
+            }
+            142 => {
+                if guard.is_modified(142) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[392];
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 146;
+            }
+            144 => {
+                if guard.is_modified(144) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[21102];
+                let addr = 21;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 148;
+            }
+            145 => {
+                if guard.is_modified(145) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21102];
+                let rhs: i64 = program.mem()[21];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 149;
+            }
+            146 => {
+                if guard.is_modified(146) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 21;
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 150;
+            }
+            148 => {
+                if guard.is_modified(148) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[21101];
+                let addr = 3;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 152;
+            }
+            149 => {
+                if guard.is_modified(149) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[3];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 153;
+            }
+            150 => {
+                if guard.is_modified(150) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 3;
+                let rhs: i64 = 0;
+                let addr = match rel(rb, 3) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 154;
+            }
+            151 => {
+                if guard.is_modified(151) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 0;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 153;
+            }
+            153 => {
+                if guard.is_modified(153) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 21101;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 155;
+            }
+            154 => {
+                if guard.is_modified(154) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 161;
+                let rhs: i64 = 0;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 158;
+            }
+            158 => {
+                if guard.is_modified(158) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 549;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 161 };
+            }
+            159 => {
+                if guard.is_modified(159) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[549];
+                let rhs: i64 = program.mem()[1102];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 163;
+            }
+            161 => {
+                if guard.is_modified(161) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 0;
+                let addr = 384;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 165;
+            }
+            162 => {
+                if guard.is_modified(162) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[384];
+                let addr = 20001;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 166;
+            }
+            165 => {
+                if guard.is_modified(165) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[388];
+                let rhs: i64 = program.mem()[390];
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 169;
+            }
+            168 => {
+                if guard.is_modified(168) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[20102];
+                let rhs: i64 = program.mem()[1];
+                let addr = 389;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 172;
+            }
+            169 => {
+                if guard.is_modified(169) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[389];
+                let addr = match rel(rb, 2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 173;
+            }
+            170 => {
+                if guard.is_modified(170) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[389];
+                let rhs: i64 = program.mem()[2];
+                let addr = 21102;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 174;
+            }
+            172 => {
+                if guard.is_modified(172) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21102];
+                let rhs: i64 = program.mem()[180];
+                let addr = 1;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 176;
+            }
+            173 => {
+                if guard.is_modified(173) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 180;
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 177;
+            }
+            175 => {
+                if guard.is_modified(175) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[1106];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 179;
+            }
+            177 => {
+                if guard.is_modified(177) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 578;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 180 };
+            }
+            180 => {
+                if guard.is_modified(180) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, 1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let target = 213;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 183 };
+            }
+            181 => {
+                if guard.is_modified(181) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[213];
+                let rhs: i64 = program.mem()[1208];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 185;
+            }
+            183 => {
+                if guard.is_modified(183) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, 1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 2;
+                let addr = 381;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 187;
+            }
+            184 => {
+                if guard.is_modified(184) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[381];
+                let addr = 1006;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 188;
+            }
+            185 => {
+                if guard.is_modified(185) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[381];
+                let rhs: i64 = program.mem()[1006];
+                let addr = 381;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 189;
+            }
+            187 => {
+                if guard.is_modified(187) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[381];
+                let target = 205;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 190 };
+            }
+            189 => {
+                if guard.is_modified(189) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, 20001) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let target: i64 = program.mem()[388];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 192 };
+            }
+            190 => {
+                if guard.is_modified(190) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[388];
+                let rhs: i64 = program.mem()[390];
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 194;
+            }
+            193 => {
+                if guard.is_modified(193) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21002];
+                let rhs: i64 = program.mem()[389];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 197;
+            }
+            194 => {
+                if guard.is_modified(194) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[389];
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 198;
+            }
+            196 => {
+                if guard.is_modified(196) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[21101];
+                let addr = 205;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 200;
+            }
+            197 => {
+                if guard.is_modified(197) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[205];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 201;
+            }
+            198 => {
+                if guard.is_modified(198) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 205;
+                let rhs: i64 = 0;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 202;
+            }
+            199 => {
+                if guard.is_modified(199) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, 0) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let target: i64 = program.mem()[0];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 202 };
+            }
+            202 => {
+                if guard.is_modified(202) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 393;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 205 };
+            }
+            203 => {
+                if guard.is_modified(203) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[393];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 390;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 207;
+            }
+            205 => {
+                if guard.is_modified(205) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[390];
+                let rhs: i64 = -1;
+                let addr = 390;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 209;
+            }
+            209 => {
+                if guard.is_modified(209) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 1;
+                let addr = 384;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 213;
+            }
+            210 => {
+                if guard.is_modified(210) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[384];
+                let addr = 20101;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 214;
+            }
+            211 => {
+                if guard.is_modified(211) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[384];
+                let rhs: i64 = program.mem()[20101];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 215;
+            }
+            213 => {
+                if guard.is_modified(213) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = program.mem()[388];
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 217;
+            }
+            216 => {
+                if guard.is_modified(216) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[20001];
+                let rhs: i64 = program.mem()[389];
+                let addr = 391;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 220;
+            }
+            217 => {
+                if guard.is_modified(217) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[389];
+                let rhs: i64 = program.mem()[391];
+                let addr = match rel(rb, 2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 221;
+            }
+            220 => {
+                if guard.is_modified(220) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[0];
+                let addr = 228;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 224;
+            }
+            221 => {
+                if guard.is_modified(221) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 228;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 225;
+            }
+            225 => {
+                if guard.is_modified(225) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 578;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 228 };
+            }
+            226 => {
+                if guard.is_modified(226) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[578];
+                let rhs: i64 = program.mem()[1206];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 230;
+            }
+            228 => {
+                if guard.is_modified(228) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, 1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let target = 261;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 231 };
+            }
+            229 => {
+                if guard.is_modified(229) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[261];
+                let rhs: i64 = program.mem()[1208];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 233;
+            }
+            231 => {
+                if guard.is_modified(231) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, 1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 2;
+                let addr = 381;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 235;
+            }
+            232 => {
+                if guard.is_modified(232) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[381];
+                let addr = 1006;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 236;
+            }
+            233 => {
+                if guard.is_modified(233) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[381];
+                let rhs: i64 = program.mem()[1006];
+                let addr = 381;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 237;
+            }
+            235 => {
+                if guard.is_modified(235) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[381];
+                let target = 253;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 238 };
+            }
+            238 => {
+                if guard.is_modified(238) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[388];
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 242;
+            }
+            240 => {
+                if guard.is_modified(240) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[20001];
+                let addr = 389;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 244;
+            }
+            241 => {
+                if guard.is_modified(241) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[20001];
+                let rhs: i64 = program.mem()[389];
+                let addr = 391;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 245;
+            }
+            242 => {
+                if guard.is_modified(242) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[389];
+                let rhs: i64 = program.mem()[391];
+                let addr = match rel(rb, 2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 246;
+            }
+            245 => {
+                if guard.is_modified(245) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[253];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 249;
+            }
+            246 => {
+                if guard.is_modified(246) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 253;
+                let rhs: i64 = 0;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 250;
+            }
+            250 => {
+                if guard.is_modified(250) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 393;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 253 };
+            }
+            251 => {
+                if guard.is_modified(251) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[393];
+                let rhs: i64 = program.mem()[1002];
+                let addr = 391;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 255;
+            }
+            253 => {
+                if guard.is_modified(253) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[391];
+                let rhs: i64 = -1;
+                let addr = 391;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 257;
+            }
+            257 => {
+                if guard.is_modified(257) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 1;
+                let addr = 384;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 261;
+            }
+            259 => {
+                if guard.is_modified(259) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[384];
+                let rhs: i64 = program.mem()[1005];
+                let addr = 384;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 263;
+            }
+            261 => {
+                if guard.is_modified(261) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[384];
+                let target = 161;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 264 };
+            }
+            264 => {
+                if guard.is_modified(264) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[388];
+                let rhs: i64 = program.mem()[390];
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 268;
+            }
+            267 => {
+                if guard.is_modified(267) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[20001];
+                let rhs: i64 = program.mem()[389];
+                let addr = 391;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 271;
+            }
+            268 => {
+                if guard.is_modified(268) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[389];
+                let rhs: i64 = program.mem()[391];
+                let addr = match rel(rb, 2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 272;
+            }
+            271 => {
+                if guard.is_modified(271) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[0];
+                let addr = 279;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 275;
+            }
+            272 => {
+                if guard.is_modified(272) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 279;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 276;
+            }
+            276 => {
+                if guard.is_modified(276) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 578;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 279 };
+            }
+            279 => {
+                if guard.is_modified(279) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, 1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let target = 316;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 282 };
+            }
+            280 => {
+                if guard.is_modified(280) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[316];
+                let rhs: i64 = program.mem()[1208];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 284;
+            }
+            282 => {
+                if guard.is_modified(282) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, 1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 2;
+                let addr = 381;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 286;
+            }
+            283 => {
+                if guard.is_modified(283) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[381];
+                let addr = 1006;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 287;
+            }
+            284 => {
+                if guard.is_modified(284) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[381];
+                let rhs: i64 = program.mem()[1006];
+                let addr = 381;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 288;
+            }
+            286 => {
+                if guard.is_modified(286) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[381];
+                let target = 304;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 289 };
+            }
+            289 => {
+                if guard.is_modified(289) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[388];
+                let rhs: i64 = program.mem()[390];
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 293;
+            }
+            292 => {
+                if guard.is_modified(292) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[20001];
+                let rhs: i64 = program.mem()[389];
+                let addr = 391;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 296;
+            }
+            293 => {
+                if guard.is_modified(293) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[389];
+                let rhs: i64 = program.mem()[391];
+                let addr = match rel(rb, 2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 297;
+            }
+            296 => {
+                if guard.is_modified(296) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21102];
+                let rhs: i64 = program.mem()[304];
+                let addr = 1;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 300;
+            }
+            297 => {
+                if guard.is_modified(297) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 304;
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 301;
+            }
+            299 => {
+                if guard.is_modified(299) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[1106];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 303;
+            }
+            301 => {
+                if guard.is_modified(301) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 393;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 304 };
+            }
+            304 => {
+                if guard.is_modified(304) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[390];
+                let rhs: i64 = -1;
+                let addr = 390;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 308;
+            }
+            308 => {
+                if guard.is_modified(308) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[391];
+                let rhs: i64 = -1;
+                let addr = 391;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 312;
+            }
+            312 => {
+                if guard.is_modified(312) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 0;
+                let addr = 384;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 316;
+            }
+            313 => {
+                if guard.is_modified(313) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[384];
+                let addr = 1005;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 317;
+            }
+            316 => {
+                if guard.is_modified(316) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[384];
+                let target = 161;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 319 };
+            }
+            319 => {
+                if guard.is_modified(319) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[388];
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 323;
+            }
+            320 => {
+                if guard.is_modified(320) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[388];
+                let rhs: i64 = program.mem()[1];
+                let addr = 20102;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 324;
+            }
+            322 => {
+                if guard.is_modified(322) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[20102];
+                let rhs: i64 = program.mem()[1];
+                let addr = 389;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 326;
+            }
+            323 => {
+                if guard.is_modified(323) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = program.mem()[389];
+                let addr = match rel(rb, 2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 327;
+            }
+            324 => {
+                if guard.is_modified(324) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[389];
+                let rhs: i64 = program.mem()[2];
+                let addr = 21102;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 328;
+            }
+            326 => {
+                if guard.is_modified(326) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21102];
+                let rhs: i64 = program.mem()[1];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 330;
+            }
+            327 => {
+                if guard.is_modified(327) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 0;
+                let addr = match rel(rb, 3) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 331;
+            }
+            328 => {
+                if guard.is_modified(328) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[3];
+                let addr = 21101;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 332;
+            }
+            330 => {
+                if guard.is_modified(330) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 21101;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 332;
+            }
+            331 => {
+                if guard.is_modified(331) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 338;
+                let rhs: i64 = 0;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 335;
+            }
+            335 => {
+                if guard.is_modified(335) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 549;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 338 };
+            }
+            338 => {
+                if guard.is_modified(338) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[388];
+                let rhs: i64 = program.mem()[390];
+                let addr = 388;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 342;
+            }
+            342 => {
+                if guard.is_modified(342) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[389];
+                let rhs: i64 = program.mem()[391];
+                let addr = 389;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 346;
+            }
+            346 => {
+                if guard.is_modified(346) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[388];
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 350;
+            }
+            348 => {
+                if guard.is_modified(348) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[21001];
+                let addr = 389;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 352;
+            }
+            349 => {
+                if guard.is_modified(349) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21001];
+                let rhs: i64 = program.mem()[389];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 353;
+            }
+            350 => {
+                if guard.is_modified(350) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[389];
+                let rhs: i64 = 0;
+                let addr = match rel(rb, 2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 354;
+            }
+            353 => {
+                if guard.is_modified(353) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[0];
+                let addr = 4;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 357;
+            }
+            354 => {
+                if guard.is_modified(354) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 4;
+                let addr = match rel(rb, 3) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 358;
+            }
+            356 => {
+                if guard.is_modified(356) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                program.count_instruction();
+                ip = 358;
+                break Native::Paused(State::Output(val));
+            }
+            357 => {
+                if guard.is_modified(357) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 21101;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 359;
+            }
+            358 => {
+                if guard.is_modified(358) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 365;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 362;
+            }
+            362 => {
+                if guard.is_modified(362) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target = 549;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 365 };
+            }
+            363 => {
+                if guard.is_modified(363) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[549];
+                let rhs: i64 = program.mem()[1007];
+                let addr = 389;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 367;
+            }
+            365 => {
+                if guard.is_modified(365) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[389];
+                let rhs: i64 = 22;
+                let addr = 381;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 369;
+            }
+            369 => {
+                if guard.is_modified(369) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[381];
+                let target = 75;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 372 };
+            }
+            372 => {
+                if guard.is_modified(372) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -1;
+                program.count_instruction();
+                ip = 374;
+                break Native::Paused(State::Output(val));
+            }
+            374 => {
+                if guard.is_modified(374) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 376;
+                break Native::Paused(State::Output(val));
+            }
+            376 => {
+                if guard.is_modified(376) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 378;
+                break Native::Paused(State::Output(val));
+            }
+            378 => {
+                if guard.is_modified(378) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            380 => {
+                if guard.is_modified(380) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 384;
+            }
+            390 => {
+                if guard.is_modified(390) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[21];
+                let addr = 109;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 394;
+            }
+            391 => {
+                if guard.is_modified(391) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21];
+                let rhs: i64 = program.mem()[109];
+                let addr = 3;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 395;
+            }
+            393 => {
+                if guard.is_modified(393) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 3;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 395;
+            }
+            394 => {
+                if guard.is_modified(394) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 21202;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 396;
+            }
+            395 => {
+                if guard.is_modified(395) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 399;
+            }
+            397 => {
+                if guard.is_modified(397) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[22102];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 401;
+            }
+            399 => {
+                if guard.is_modified(399) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = match rel(rb, -1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = match rel(rb, 2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 403;
+            }
+            402 => {
+                if guard.is_modified(402) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 406;
+            }
+            403 => {
+                if guard.is_modified(403) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 0;
+                let addr = match rel(rb, 3) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 407;
+            }
+            406 => {
+                if guard.is_modified(406) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 21101;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 408;
+            }
+            407 => {
+                if guard.is_modified(407) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 414;
+                let rhs: i64 = 0;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 411;
+            }
+            411 => {
+                if guard.is_modified(411) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 549;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 414 };
+            }
+            414 => {
+                if guard.is_modified(414) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 418;
+            }
+            418 => {
+                if guard.is_modified(418) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 0;
+                let addr = match rel(rb, 2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 422;
+            }
+            421 => {
+                if guard.is_modified(421) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[0];
+                let addr = 429;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 425;
+            }
+            422 => {
+                if guard.is_modified(422) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = 429;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 426;
+            }
+            426 => {
+                if guard.is_modified(426) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 601;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 429 };
+            }
+            429 => {
+                if guard.is_modified(429) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 0;
+                let rhs: i64 = match rel(rb, 1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = 435;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 433;
+            }
+            431 => {
+                if guard.is_modified(431) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[435];
+                let rhs: i64 = program.mem()[1];
+                let addr = 386;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 435;
+            }
+            437 => {
+                if guard.is_modified(437) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -1;
+                program.count_instruction();
+                ip = 439;
+                break Native::Paused(State::Output(val));
+            }
+            439 => {
+                if guard.is_modified(439) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                program.count_instruction();
+                ip = 441;
+                break Native::Paused(State::Output(val));
+            }
+            441 => {
+                if guard.is_modified(441) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[386];
+                program.count_instruction();
+                ip = 443;
+                break Native::Paused(State::Output(val));
+            }
+            443 => {
+                if guard.is_modified(443) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[387];
+                let rhs: i64 = -1;
+                let addr = 387;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 447;
+            }
+            447 => {
+                if guard.is_modified(447) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[387];
+                let target = 451;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 450 };
+            }
+            450 => {
+                if guard.is_modified(450) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            451 => {
+                if guard.is_modified(451) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -3;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 453;
+            }
+            453 => {
+                if guard.is_modified(453) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target: i64 = match rel(rb, 0) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 456 };
+            }
+            456 => {
+                if guard.is_modified(456) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 8;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 458;
+            }
+            458 => {
+                if guard.is_modified(458) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -7) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = match rel(rb, -6) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = match rel(rb, -3) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 462;
+            }
+            462 => {
+                if guard.is_modified(462) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = match rel(rb, -5) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = match rel(rb, -3) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 466;
+            }
+            466 => {
+                if guard.is_modified(466) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -4) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 64;
+                let addr = match rel(rb, -2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 470;
+            }
+            470 => {
+                if guard.is_modified(470) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = 381;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 474;
+            }
+            474 => {
+                if guard.is_modified(474) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[381];
+                let target = 492;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 477 };
+            }
+            477 => {
+                if guard.is_modified(477) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = -1;
+                let addr = match rel(rb, -1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 481;
+            }
+            481 => {
+                if guard.is_modified(481) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = match rel(rb, -1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = match rel(rb, -3) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 485;
+            }
+            485 => {
+                if guard.is_modified(485) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = 381;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 489;
+            }
+            489 => {
+                if guard.is_modified(489) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[381];
+                let target = 481;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 492 };
+            }
+            492 => {
+                if guard.is_modified(492) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -4) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 8;
+                let addr = match rel(rb, -2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 496;
+            }
+            496 => {
+                if guard.is_modified(496) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = 381;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 500;
+            }
+            500 => {
+                if guard.is_modified(500) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[381];
+                let target = 518;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 503 };
+            }
+            503 => {
+                if guard.is_modified(503) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = -1;
+                let addr = match rel(rb, -1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 507;
+            }
+            507 => {
+                if guard.is_modified(507) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = match rel(rb, -1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = match rel(rb, -3) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 511;
+            }
+            511 => {
+                if guard.is_modified(511) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = 381;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 515;
+            }
+            515 => {
+                if guard.is_modified(515) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[381];
+                let target = 507;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 518 };
+            }
+            518 => {
+                if guard.is_modified(518) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = match rel(rb, -4) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = 381;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 522;
+            }
+            522 => {
+                if guard.is_modified(522) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[381];
+                let target = 540;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 525 };
+            }
+            525 => {
+                if guard.is_modified(525) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -4) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = -1;
+                let addr = match rel(rb, -1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 529;
+            }
+            529 => {
+                if guard.is_modified(529) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = match rel(rb, -1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = match rel(rb, -3) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 533;
+            }
+            533 => {
+                if guard.is_modified(533) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = match rel(rb, -4) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = 381;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 537;
+            }
+            537 => {
+                if guard.is_modified(537) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[381];
+                let target = 529;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 540 };
+            }
+            540 => {
+                if guard.is_modified(540) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 0;
+                let addr = match rel(rb, -7) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 544;
+            }
+            544 => {
+                if guard.is_modified(544) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -8;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 546;
+            }
+            546 => {
+                if guard.is_modified(546) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target: i64 = match rel(rb, 0) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 549 };
+            }
+            547 => {
+                if guard.is_modified(547) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[109];
+                let addr = 4;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 551;
+            }
+            549 => {
+                if guard.is_modified(549) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 4;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 551;
+            }
+            550 => {
+                if guard.is_modified(550) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[1202];
+                program.count_instruction();
+                ip = 552;
+                break Native::Paused(State::Output(val));
+            }
+            551 => {
+                if guard.is_modified(551) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 42;
+                let addr = 566;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 555;
+            }
+            555 => {
+                if guard.is_modified(555) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = program.mem()[566];
+                let addr = 566;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 559;
+            }
+            559 => {
+                if guard.is_modified(559) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 639;
+                let rhs: i64 = program.mem()[566];
+                let addr = 566;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 563;
+            }
+            567 => {
+                if guard.is_modified(567) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, -3) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                program.count_instruction();
+                ip = 569;
+                break Native::Paused(State::Output(val));
+            }
+            569 => {
+                if guard.is_modified(569) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                program.count_instruction();
+                ip = 571;
+                break Native::Paused(State::Output(val));
+            }
+            571 => {
+                if guard.is_modified(571) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, -1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                program.count_instruction();
+                ip = 573;
+                break Native::Paused(State::Output(val));
+            }
+            573 => {
+                if guard.is_modified(573) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -4;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 575;
+            }
+            575 => {
+                if guard.is_modified(575) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target: i64 = match rel(rb, 0) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 578 };
+            }
+            576 => {
+                if guard.is_modified(576) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[109];
+                let addr = 3;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 580;
+            }
+            578 => {
+                if guard.is_modified(578) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 3;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 580;
+            }
+            579 => {
+                if guard.is_modified(579) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 1202;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 581;
+            }
+            580 => {
+                if guard.is_modified(580) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 42;
+                let addr = 593;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 584;
+            }
+            584 => {
+                if guard.is_modified(584) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = program.mem()[593];
+                let addr = 593;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 588;
+            }
+            588 => {
+                if guard.is_modified(588) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 639;
+                let rhs: i64 = program.mem()[593];
+                let addr = 593;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 592;
+            }
+            596 => {
+                if guard.is_modified(596) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -3;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 598;
+            }
+            598 => {
+                if guard.is_modified(598) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                let target: i64 = match rel(rb, 0) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 601 };
+            }
+            599 => {
+                if guard.is_modified(599) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[109];
+                let addr = 3;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 603;
+            }
+            601 => {
+                if guard.is_modified(601) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 3;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 603;
+            }
+            602 => {
+                if guard.is_modified(602) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 22102;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 604;
+            }
+            603 => {
+                if guard.is_modified(603) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 23;
+                let rhs: i64 = match rel(rb, -2) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 607;
+            }
+            607 => {
+                if guard.is_modified(607) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, 1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = match rel(rb, -1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let addr = match rel(rb, 1) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 611;
+            }
+            610 => {
+                if guard.is_modified(610) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21102];
+                let rhs: i64 = program.mem()[487];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 614;
+            }
+            611 => {
+                if guard.is_modified(611) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 487;
+                let rhs: i64 = 1;
+                let addr = match rel(rb, 2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 615;
+            }
+            613 => {
+                if guard.is_modified(613) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[21101];
+                let addr = 326;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 617;
+            }
+            614 => {
+                if guard.is_modified(614) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[21101];
+                let rhs: i64 = program.mem()[326];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 618;
+            }
+            615 => {
+                if guard.is_modified(615) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 326;
+                let rhs: i64 = 0;
+                let addr = match rel(rb, 3) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 619;
+            }
+            618 => {
+                if guard.is_modified(618) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 21102;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 620;
+            }
+            619 => {
+                if guard.is_modified(619) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1;
+                let rhs: i64 = 966;
+                let addr = match rel(rb, 4) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 623;
+            }
+            620 => {
+                if guard.is_modified(620) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[966];
+                let rhs: i64 = program.mem()[4];
+                let addr = 21101;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 624;
+            }
+            622 => {
+                if guard.is_modified(622) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[21101];
+                program.count_instruction();
+                ip = 624;
+                break Native::Paused(State::Output(val));
+            }
+            623 => {
+                if guard.is_modified(623) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 630;
+                let rhs: i64 = 0;
+                let addr = match rel(rb, 0) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 627;
+            }
+            627 => {
+                if guard.is_modified(627) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target = 456;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 630 };
+            }
+            630 => {
+                if guard.is_modified(630) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = match rel(rb, 1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                let rhs: i64 = 1605;
+                let addr = match rel(rb, -2) { Some(addr) => addr, None => break Native::Fallback };
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 634;
+            }
+            634 => {
+                if guard.is_modified(634) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = -3;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 636;
+            }
+            636 => {
+                if guard.is_modified(636) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 0;
+                let target: i64 = match rel(rb, 0) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 639 };
+            }
+            639 => {
+                if guard.is_modified(639) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 643;
+            }
+            640 => {
+                if guard.is_modified(640) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 644;
+            }
+            641 => {
+                if guard.is_modified(641) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 645;
+            }
+            642 => {
+                if guard.is_modified(642) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 646;
+            }
+            643 => {
+                if guard.is_modified(643) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 647;
+            }
+            644 => {
+                if guard.is_modified(644) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 648;
+            }
+            645 => {
+                if guard.is_modified(645) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 649;
+            }
+            646 => {
+                if guard.is_modified(646) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 650;
+            }
+            647 => {
+                if guard.is_modified(647) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 651;
+            }
+            648 => {
+                if guard.is_modified(648) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 652;
+            }
+            649 => {
+                if guard.is_modified(649) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 653;
+            }
+            650 => {
+                if guard.is_modified(650) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 654;
+            }
+            651 => {
+                if guard.is_modified(651) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 655;
+            }
+            652 => {
+                if guard.is_modified(652) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 656;
+            }
+            653 => {
+                if guard.is_modified(653) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 657;
+            }
+            654 => {
+                if guard.is_modified(654) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 658;
+            }
+            655 => {
+                if guard.is_modified(655) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 659;
+            }
+            656 => {
+                if guard.is_modified(656) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 660;
+            }
+            657 => {
+                if guard.is_modified(657) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 661;
+            }
+            658 => {
+                if guard.is_modified(658) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 662;
+            }
+            659 => {
+                if guard.is_modified(659) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 663;
+            }
+            660 => {
+                if guard.is_modified(660) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 664;
+            }
+            661 => {
+                if guard.is_modified(661) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 665;
+            }
+            662 => {
+                if guard.is_modified(662) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 666;
+            }
+            663 => {
+                if guard.is_modified(663) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 667;
+            }
+            664 => {
+                if guard.is_modified(664) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 668;
+            }
+            665 => {
+                if guard.is_modified(665) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 669;
+            }
+            666 => {
+                if guard.is_modified(666) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 670;
+            }
+            667 => {
+                if guard.is_modified(667) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 671;
+            }
+            668 => {
+                if guard.is_modified(668) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 672;
+            }
+            669 => {
+                if guard.is_modified(669) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 673;
+            }
+            670 => {
+                if guard.is_modified(670) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 674;
+            }
+            671 => {
+                if guard.is_modified(671) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 675;
+            }
+            672 => {
+                if guard.is_modified(672) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 676;
+            }
+            673 => {
+                if guard.is_modified(673) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 677;
+            }
+            674 => {
+                if guard.is_modified(674) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 678;
+            }
+            675 => {
+                if guard.is_modified(675) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 679;
+            }
+            676 => {
+                if guard.is_modified(676) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 680;
+            }
+            677 => {
+                if guard.is_modified(677) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 681;
+            }
+            678 => {
+                if guard.is_modified(678) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 682;
+            }
+            679 => {
+                if guard.is_modified(679) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[1];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 683;
+            }
+            680 => {
+                if guard.is_modified(680) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 684;
+            }
+            681 => {
+                if guard.is_modified(681) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 685;
+            }
+            722 => {
+                if guard.is_modified(722) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 726;
+            }
+            723 => {
+                if guard.is_modified(723) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 727;
+            }
+            728 => {
+                if guard.is_modified(728) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 732;
+            }
+            729 => {
+                if guard.is_modified(729) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 733;
+            }
+            730 => {
+                if guard.is_modified(730) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 734;
+            }
+            734 => {
+                if guard.is_modified(734) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 738;
+            }
+            735 => {
+                if guard.is_modified(735) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 739;
+            }
+            740 => {
+                if guard.is_modified(740) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 744;
+            }
+            751 => {
+                if guard.is_modified(751) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 755;
+            }
+            753 => {
+                if guard.is_modified(753) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 757;
+            }
+            755 => {
+                if guard.is_modified(755) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 759;
+            }
+            760 => {
+                if guard.is_modified(760) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 764;
+            }
+            761 => {
+                if guard.is_modified(761) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 1;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 765;
+            }
+            762 => {
+                if guard.is_modified(762) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 766;
+            }
+            764 => {
+                if guard.is_modified(764) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 768;
+            }
+            765 => {
+                if guard.is_modified(765) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 769;
+            }
+            767 => {
+                if guard.is_modified(767) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 771;
+            }
+            769 => {
+                if guard.is_modified(769) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 773;
+            }
+            771 => {
+                if guard.is_modified(771) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 775;
+            }
+            774 => {
+                if guard.is_modified(774) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 778;
+            }
+            775 => {
+                if guard.is_modified(775) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 779;
+            }
+            777 => {
+                if guard.is_modified(777) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 781;
+            }
+            778 => {
+                if guard.is_modified(778) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 782;
+            }
+            779 => {
+                if guard.is_modified(779) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 783;
+            }
+            780 => {
+                if guard.is_modified(780) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 784;
+            }
+            784 => {
+                if guard.is_modified(784) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 788;
+            }
+            787 => {
+                if guard.is_modified(787) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 791;
+            }
+            788 => {
+                if guard.is_modified(788) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 792;
+            }
+            789 => {
+                if guard.is_modified(789) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 793;
+            }
+            791 => {
+                if guard.is_modified(791) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 795;
+            }
+            792 => {
+                if guard.is_modified(792) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 796;
+            }
+            794 => {
+                if guard.is_modified(794) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 798;
+            }
+            799 => {
+                if guard.is_modified(799) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 803;
+            }
+            806 => {
+                if guard.is_modified(806) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 810;
+            }
+            807 => {
+                if guard.is_modified(807) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 811;
+            }
+            812 => {
+                if guard.is_modified(812) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 816;
+            }
+            816 => {
+                if guard.is_modified(816) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 820;
+            }
+            819 => {
+                if guard.is_modified(819) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 823;
+            }
+            824 => {
+                if guard.is_modified(824) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 828;
+            }
+            828 => {
+                if guard.is_modified(828) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 832;
+            }
+            829 => {
+                if guard.is_modified(829) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 833;
+            }
+            831 => {
+                if guard.is_modified(831) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 835;
+            }
+            833 => {
+                if guard.is_modified(833) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 837;
+            }
+            834 => {
+                if guard.is_modified(834) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 838;
+            }
+            836 => {
+                if guard.is_modified(836) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 840;
+            }
+            837 => {
+                if guard.is_modified(837) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 841;
+            }
+            839 => {
+                if guard.is_modified(839) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 843;
+            }
+            841 => {
+                if guard.is_modified(841) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 845;
+            }
+            842 => {
+                if guard.is_modified(842) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 846;
+            }
+            843 => {
+                if guard.is_modified(843) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 847;
+            }
+            846 => {
+                if guard.is_modified(846) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 850;
+            }
+            848 => {
+                if guard.is_modified(848) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 852;
+            }
+            849 => {
+                if guard.is_modified(849) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 853;
+            }
+            852 => {
+                if guard.is_modified(852) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 856;
+            }
+            853 => {
+                if guard.is_modified(853) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 857;
+            }
+            854 => {
+                if guard.is_modified(854) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 858;
+            }
+            855 => {
+                if guard.is_modified(855) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 859;
+            }
+            856 => {
+                if guard.is_modified(856) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 860;
+            }
+            857 => {
+                if guard.is_modified(857) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 861;
+            }
+            858 => {
+                if guard.is_modified(858) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 862;
+            }
+            861 => {
+                if guard.is_modified(861) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 865;
+            }
+            863 => {
+                if guard.is_modified(863) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 867;
+            }
+            867 => {
+                if guard.is_modified(867) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 871;
+            }
+            869 => {
+                if guard.is_modified(869) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 873;
+            }
+            872 => {
+                if guard.is_modified(872) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 876;
+            }
+            877 => {
+                if guard.is_modified(877) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 881;
+            }
+            880 => {
+                if guard.is_modified(880) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 884;
+            }
+            881 => {
+                if guard.is_modified(881) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 885;
+            }
+            883 => {
+                if guard.is_modified(883) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 887;
+            }
+            886 => {
+                if guard.is_modified(886) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 890;
+            }
+            887 => {
+                if guard.is_modified(887) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 1;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 891;
+            }
+            890 => {
+                if guard.is_modified(890) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 894;
+            }
+            891 => {
+                if guard.is_modified(891) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 895;
+            }
+            893 => {
+                if guard.is_modified(893) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 897;
+            }
+            894 => {
+                if guard.is_modified(894) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 898;
+            }
+            900 => {
+                if guard.is_modified(900) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 904;
+            }
+            901 => {
+                if guard.is_modified(901) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 905;
+            }
+            910 => {
+                if guard.is_modified(910) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 914;
+            }
+            911 => {
+                if guard.is_modified(911) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 915;
+            }
+            924 => {
+                if guard.is_modified(924) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 928;
+            }
+            926 => {
+                if guard.is_modified(926) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 930;
+            }
+            929 => {
+                if guard.is_modified(929) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 1;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 933;
+            }
+            932 => {
+                if guard.is_modified(932) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 936;
+            }
+            933 => {
+                if guard.is_modified(933) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 937;
+            }
+            937 => {
+                if guard.is_modified(937) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 941;
+            }
+            940 => {
+                if guard.is_modified(940) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 944;
+            }
+            946 => {
+                if guard.is_modified(946) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 950;
+            }
+            949 => {
+                if guard.is_modified(949) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 953;
+            }
+            950 => {
+                if guard.is_modified(950) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 954;
+            }
+            953 => {
+                if guard.is_modified(953) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 957;
+            }
+            955 => {
+                if guard.is_modified(955) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 959;
+            }
+            974 => {
+                if guard.is_modified(974) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 978;
+            }
+            975 => {
+                if guard.is_modified(975) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 979;
+            }
+            981 => {
+                if guard.is_modified(981) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 985;
+            }
+            983 => {
+                if guard.is_modified(983) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 987;
+            }
+            984 => {
+                if guard.is_modified(984) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 988;
+            }
+            985 => {
+                if guard.is_modified(985) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 989;
+            }
+            987 => {
+                if guard.is_modified(987) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 991;
+            }
+            988 => {
+                if guard.is_modified(988) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 992;
+            }
+            995 => {
+                if guard.is_modified(995) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 999;
+            }
+            997 => {
+                if guard.is_modified(997) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1001;
+            }
+            1000 => {
+                if guard.is_modified(1000) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1004;
+            }
+            1001 => {
+                if guard.is_modified(1001) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1005;
+            }
+            1005 => {
+                if guard.is_modified(1005) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1009;
+            }
+            1007 => {
+                if guard.is_modified(1007) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1011;
+            }
+            1008 => {
+                if guard.is_modified(1008) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1012;
+            }
+            1010 => {
+                if guard.is_modified(1010) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1014;
+            }
+            1014 => {
+                if guard.is_modified(1014) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1018;
+            }
+            1016 => {
+                if guard.is_modified(1016) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1020;
+            }
+            1017 => {
+                if guard.is_modified(1017) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1021;
+            }
+            1022 => {
+                if guard.is_modified(1022) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1026;
+            }
+            1024 => {
+                if guard.is_modified(1024) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1028;
+            }
+            1025 => {
+                if guard.is_modified(1025) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1029;
+            }
+            1026 => {
+                if guard.is_modified(1026) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1030;
+            }
+            1027 => {
+                if guard.is_modified(1027) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1031;
+            }
+            1028 => {
+                if guard.is_modified(1028) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1032;
+            }
+            1030 => {
+                if guard.is_modified(1030) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1034;
+            }
+            1032 => {
+                if guard.is_modified(1032) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1036;
+            }
+            1033 => {
+                if guard.is_modified(1033) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1037;
+            }
+            1036 => {
+                if guard.is_modified(1036) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1040;
+            }
+            1037 => {
+                if guard.is_modified(1037) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1041;
+            }
+            1039 => {
+                if guard.is_modified(1039) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1043;
+            }
+            1040 => {
+                if guard.is_modified(1040) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1044;
+            }
+            1041 => {
+                if guard.is_modified(1041) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1045;
+            }
+            1043 => {
+                if guard.is_modified(1043) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1047;
+            }
+            1045 => {
+                if guard.is_modified(1045) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1049;
+            }
+            1046 => {
+                if guard.is_modified(1046) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1050;
+            }
+            1048 => {
+                if guard.is_modified(1048) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1052;
+            }
+            1049 => {
+                if guard.is_modified(1049) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1053;
+            }
+            1051 => {
+                if guard.is_modified(1051) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1055;
+            }
+            1052 => {
+                if guard.is_modified(1052) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1056;
+            }
+            1053 => {
+                if guard.is_modified(1053) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1057;
+            }
+            1054 => {
+                if guard.is_modified(1054) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1058;
+            }
+            1055 => {
+                if guard.is_modified(1055) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 1;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1059;
+            }
+            1058 => {
+                if guard.is_modified(1058) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1062;
+            }
+            1059 => {
+                if guard.is_modified(1059) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1063;
+            }
+            1062 => {
+                if guard.is_modified(1062) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1066;
+            }
+            1066 => {
+                if guard.is_modified(1066) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1070;
+            }
+            1068 => {
+                if guard.is_modified(1068) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1072;
+            }
+            1069 => {
+                if guard.is_modified(1069) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1073;
+            }
+            1070 => {
+                if guard.is_modified(1070) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1074;
+            }
+            1071 => {
+                if guard.is_modified(1071) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1075;
+            }
+            1072 => {
+                if guard.is_modified(1072) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1076;
+            }
+            1074 => {
+                if guard.is_modified(1074) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1078;
+            }
+            1077 => {
+                if guard.is_modified(1077) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1081;
+            }
+            1078 => {
+                if guard.is_modified(1078) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1082;
+            }
+            1081 => {
+                if guard.is_modified(1081) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1085;
+            }
+            1083 => {
+                if guard.is_modified(1083) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1087;
+            }
+            1084 => {
+                if guard.is_modified(1084) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1088;
+            }
+            1087 => {
+                if guard.is_modified(1087) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1091;
+            }
+            1089 => {
+                if guard.is_modified(1089) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1093;
+            }
+            1091 => {
+                if guard.is_modified(1091) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1095;
+            }
+            1092 => {
+                if guard.is_modified(1092) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1096;
+            }
+            1093 => {
+                if guard.is_modified(1093) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1097;
+            }
+            1095 => {
+                if guard.is_modified(1095) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1099;
+            }
+            1096 => {
+                if guard.is_modified(1096) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1100;
+            }
+            1097 => {
+                if guard.is_modified(1097) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 1;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1101;
+            }
+            1098 => {
+                if guard.is_modified(1098) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[1];
+                let addr = 1;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1102;
+            }
+            1100 => {
+                if guard.is_modified(1100) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1104;
+            }
+            1101 => {
+                if guard.is_modified(1101) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1105;
+            }
+            1103 => {
+                if guard.is_modified(1103) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1107;
+            }
+            1107 => {
+                if guard.is_modified(1107) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1111;
+            }
+            1110 => {
+                if guard.is_modified(1110) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1114;
+            }
+            1114 => {
+                if guard.is_modified(1114) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1118;
+            }
+            1116 => {
+                if guard.is_modified(1116) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1120;
+            }
+            1118 => {
+                if guard.is_modified(1118) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1122;
+            }
+            1119 => {
+                if guard.is_modified(1119) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1123;
+            }
+            1124 => {
+                if guard.is_modified(1124) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1128;
+            }
+            1125 => {
+                if guard.is_modified(1125) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1129;
+            }
+            1126 => {
+                if guard.is_modified(1126) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1130;
+            }
+            1127 => {
+                if guard.is_modified(1127) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1131;
+            }
+            1128 => {
+                if guard.is_modified(1128) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1132;
+            }
+            1129 => {
+                if guard.is_modified(1129) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1133;
+            }
+            1132 => {
+                if guard.is_modified(1132) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1136;
+            }
+            1133 => {
+                if guard.is_modified(1133) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1137;
+            }
+            1136 => {
+                if guard.is_modified(1136) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1140;
+            }
+            1137 => {
+                if guard.is_modified(1137) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1141;
+            }
+            1139 => {
+                if guard.is_modified(1139) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 1;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1143;
+            }
+            1142 => {
+                if guard.is_modified(1142) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1146;
+            }
+            1143 => {
+                if guard.is_modified(1143) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1147;
+            }
+            1145 => {
+                if guard.is_modified(1145) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1149;
+            }
+            1149 => {
+                if guard.is_modified(1149) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1153;
+            }
+            1154 => {
+                if guard.is_modified(1154) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1158;
+            }
+            1155 => {
+                if guard.is_modified(1155) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1159;
+            }
+            1158 => {
+                if guard.is_modified(1158) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1162;
+            }
+            1159 => {
+                if guard.is_modified(1159) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1163;
+            }
+            1160 => {
+                if guard.is_modified(1160) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1164;
+            }
+            1166 => {
+                if guard.is_modified(1166) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1170;
+            }
+            1170 => {
+                if guard.is_modified(1170) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1174;
+            }
+            1172 => {
+                if guard.is_modified(1172) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1176;
+            }
+            1175 => {
+                if guard.is_modified(1175) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1179;
+            }
+            1177 => {
+                if guard.is_modified(1177) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1181;
+            }
+            1178 => {
+                if guard.is_modified(1178) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1182;
+            }
+            1179 => {
+                if guard.is_modified(1179) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1183;
+            }
+            1180 => {
+                if guard.is_modified(1180) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1184;
+            }
+            1184 => {
+                if guard.is_modified(1184) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1188;
+            }
+            1185 => {
+                if guard.is_modified(1185) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1189;
+            }
+            1192 => {
+                if guard.is_modified(1192) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1196;
+            }
+            1193 => {
+                if guard.is_modified(1193) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1197;
+            }
+            1195 => {
+                if guard.is_modified(1195) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1199;
+            }
+            1197 => {
+                if guard.is_modified(1197) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1201;
+            }
+            1198 => {
+                if guard.is_modified(1198) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1202;
+            }
+            1200 => {
+                if guard.is_modified(1200) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1204;
+            }
+            1202 => {
+                if guard.is_modified(1202) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1206;
+            }
+            1203 => {
+                if guard.is_modified(1203) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1207;
+            }
+            1205 => {
+                if guard.is_modified(1205) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1209;
+            }
+            1210 => {
+                if guard.is_modified(1210) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1214;
+            }
+            1214 => {
+                if guard.is_modified(1214) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1218;
+            }
+            1215 => {
+                if guard.is_modified(1215) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1219;
+            }
+            1217 => {
+                if guard.is_modified(1217) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1221;
+            }
+            1218 => {
+                if guard.is_modified(1218) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1222;
+            }
+            1222 => {
+                if guard.is_modified(1222) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1226;
+            }
+            1223 => {
+                if guard.is_modified(1223) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 1;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1227;
+            }
+            1226 => {
+                if guard.is_modified(1226) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1230;
+            }
+            1227 => {
+                if guard.is_modified(1227) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1231;
+            }
+            1230 => {
+                if guard.is_modified(1230) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1234;
+            }
+            1232 => {
+                if guard.is_modified(1232) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1236;
+            }
+            1233 => {
+                if guard.is_modified(1233) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1237;
+            }
+            1235 => {
+                if guard.is_modified(1235) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1239;
+            }
+            1236 => {
+                if guard.is_modified(1236) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1240;
+            }
+            1237 => {
+                if guard.is_modified(1237) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1241;
+            }
+            1240 => {
+                if guard.is_modified(1240) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1244;
+            }
+            1241 => {
+                if guard.is_modified(1241) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1245;
+            }
+            1244 => {
+                if guard.is_modified(1244) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1248;
+            }
+            1245 => {
+                if guard.is_modified(1245) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1249;
+            }
+            1246 => {
+                if guard.is_modified(1246) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1250;
+            }
+            1247 => {
+                if guard.is_modified(1247) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1251;
+            }
+            1259 => {
+                if guard.is_modified(1259) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1263;
+            }
+            1261 => {
+                if guard.is_modified(1261) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1265;
+            }
+            1265 => {
+                if guard.is_modified(1265) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 1;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1269;
+            }
+            1268 => {
+                if guard.is_modified(1268) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1272;
+            }
+            1269 => {
+                if guard.is_modified(1269) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1273;
+            }
+            1272 => {
+                if guard.is_modified(1272) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1276;
+            }
+            1275 => {
+                if guard.is_modified(1275) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1279;
+            }
+            1277 => {
+                if guard.is_modified(1277) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1281;
+            }
+            1279 => {
+                if guard.is_modified(1279) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1283;
+            }
+            1282 => {
+                if guard.is_modified(1282) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1286;
+            }
+            1283 => {
+                if guard.is_modified(1283) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1287;
+            }
+            1284 => {
+                if guard.is_modified(1284) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1288;
+            }
+            1285 => {
+                if guard.is_modified(1285) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1289;
+            }
+            1286 => {
+                if guard.is_modified(1286) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1290;
+            }
+            1288 => {
+                if guard.is_modified(1288) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1292;
+            }
+            1291 => {
+                if guard.is_modified(1291) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1295;
+            }
+            1292 => {
+                if guard.is_modified(1292) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1296;
+            }
+            1296 => {
+                if guard.is_modified(1296) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1300;
+            }
+            1300 => {
+                if guard.is_modified(1300) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1304;
+            }
+            1301 => {
+                if guard.is_modified(1301) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1305;
+            }
+            1303 => {
+                if guard.is_modified(1303) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1307;
+            }
+            1306 => {
+                if guard.is_modified(1306) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1310;
+            }
+            1310 => {
+                if guard.is_modified(1310) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1314;
+            }
+            1311 => {
+                if guard.is_modified(1311) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1315;
+            }
+            1313 => {
+                if guard.is_modified(1313) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1317;
+            }
+            1314 => {
+                if guard.is_modified(1314) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1318;
+            }
+            1317 => {
+                if guard.is_modified(1317) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1321;
+            }
+            1324 => {
+                if guard.is_modified(1324) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1328;
+            }
+            1326 => {
+                if guard.is_modified(1326) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1330;
+            }
+            1329 => {
+                if guard.is_modified(1329) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1333;
+            }
+            1333 => {
+                if guard.is_modified(1333) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1337;
+            }
+            1335 => {
+                if guard.is_modified(1335) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[2];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1339;
+            }
+            1337 => {
+                if guard.is_modified(1337) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1341;
+            }
+            1338 => {
+                if guard.is_modified(1338) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 2;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1342;
+            }
+            1341 => {
+                if guard.is_modified(1341) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1345;
+            }
+            1347 => {
+                if guard.is_modified(1347) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1351;
+            }
+            1352 => {
+                if guard.is_modified(1352) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1356;
+            }
+            1353 => {
+                if guard.is_modified(1353) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1357;
+            }
+            1394 => {
+                if guard.is_modified(1394) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1398;
+            }
+            1395 => {
+                if guard.is_modified(1395) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1399;
+            }
+            1414 => {
+                if guard.is_modified(1414) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[0];
+                program.count_instruction();
+                ip = 1416;
+                break Native::Paused(State::Output(val));
+            }
+            1436 => {
+                if guard.is_modified(1436) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1440;
+            }
+            1437 => {
+                if guard.is_modified(1437) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1441;
+            }
+            1478 => {
+                if guard.is_modified(1478) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1482;
+            }
+            1479 => {
+                if guard.is_modified(1479) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1483;
+            }
+            1520 => {
+                if guard.is_modified(1520) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1524;
+            }
+            1521 => {
+                if guard.is_modified(1521) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1525;
+            }
+            1542 => {
+                if guard.is_modified(1542) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 0;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1544;
+            }
+            1562 => {
+                if guard.is_modified(1562) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1566;
+            }
+            1563 => {
+                if guard.is_modified(1563) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[0];
+                let rhs: i64 = program.mem()[0];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1567;
+            }
+            1604 => {
+                if guard.is_modified(1604) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[85];
+                let rhs: i64 = program.mem()[93];
+                let addr = 24;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1608;
+            }
+            1613 => {
+                if guard.is_modified(1613) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[89];
+                let rhs: i64 = program.mem()[80];
+                let addr = 17;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1617;
+            }
+            1627 => {
+                if guard.is_modified(1627) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[85];
+                let rhs: i64 = program.mem()[58];
+                let addr = 86;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1631;
+            }
+            1643 => {
+                if guard.is_modified(1643) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[17];
+                program.count_instruction();
+                ip = 1645;
+                break Native::Paused(State::Output(val));
+            }
+            1658 => {
+                if guard.is_modified(1658) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[74];
+                let rhs: i64 = program.mem()[77];
+                let addr = 35;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1662;
+            }
+            1664 => {
+                if guard.is_modified(1664) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[40];
+                let rhs: i64 = program.mem()[74];
+                let addr = 19;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1668;
+            }
+            1675 => {
+                if guard.is_modified(1675) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[77];
+                let rhs: i64 = program.mem()[1];
+                let addr = 80;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1679;
+            }
+            1677 => {
+                if guard.is_modified(1677) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[80];
+                let rhs: i64 = program.mem()[44];
+                let addr = 80;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1681;
+            }
+            1685 => {
+                if guard.is_modified(1685) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[66];
+                let target: i64 = program.mem()[24];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 1688 };
+            }
+            1688 => {
+                if guard.is_modified(1688) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[26];
+                program.count_instruction();
+                ip = 1690;
+                break Native::Paused(State::Output(val));
+            }
+            1698 => {
+                if guard.is_modified(1698) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[76];
+                let rhs: i64 = program.mem()[36];
+                let addr = 90;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1702;
+            }
+            1704 => {
+                if guard.is_modified(1704) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[72];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 1706;
+            }
+            1727 => {
+                if guard.is_modified(1727) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[64];
+                let target: i64 = program.mem()[4];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 1730 };
+            }
+            1729 => {
+                if guard.is_modified(1729) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[92];
+                program.count_instruction();
+                ip = 1731;
+                break Native::Paused(State::Output(val));
+            }
+            1738 => {
+                if guard.is_modified(1738) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[36];
+                let rhs: i64 = program.mem()[15];
+                let addr = 19;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1742;
+            }
+            1747 => {
+                if guard.is_modified(1747) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[31];
+                let rhs: i64 = program.mem()[50];
+                let addr = 23;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1751;
+            }
+            1755 => {
+                if guard.is_modified(1755) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[6];
+                let target: i64 = program.mem()[12];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 1758 };
+            }
+            1756 => {
+                if guard.is_modified(1756) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[12];
+                let target: i64 = program.mem()[81];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 1759 };
+            }
+            1766 => {
+                if guard.is_modified(1766) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[98];
+                let rhs: i64 = program.mem()[35];
+                let addr = 34;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1770;
+            }
+            1784 => {
+                if guard.is_modified(1784) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[28];
+                program.count_instruction();
+                ip = 1786;
+                break Native::Paused(State::Output(val));
+            }
+            1791 => {
+                if guard.is_modified(1791) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[14];
+                let target: i64 = program.mem()[25];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 1794 };
+            }
+            1796 => {
+                if guard.is_modified(1796) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[35];
+                program.count_instruction();
+                ip = 1798;
+                break Native::Paused(State::Output(val));
+            }
+            1811 => {
+                if guard.is_modified(1811) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[7];
+                program.count_instruction();
+                ip = 1813;
+                break Native::Paused(State::Output(val));
+            }
+            1812 => {
+                if guard.is_modified(1812) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[94];
+                let rhs: i64 = program.mem()[15];
+                let addr = 43;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1816;
+            }
+            1818 => {
+                if guard.is_modified(1818) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 46;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1820;
+            }
+            1834 => {
+                if guard.is_modified(1834) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[81];
+                let rhs: i64 = program.mem()[98];
+                let addr = 13;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1838;
+            }
+            1851 => {
+                if guard.is_modified(1851) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[5];
+                let rhs: i64 = program.mem()[87];
+                let addr = 44;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1855;
+            }
+            1852 => {
+                if guard.is_modified(1852) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[87];
+                let target: i64 = program.mem()[44];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 1855 };
+            }
+            1867 => {
+                if guard.is_modified(1867) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[92];
+                let rhs: i64 = program.mem()[65];
+                let addr = 37;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1871;
+            }
+            1874 => {
+                if guard.is_modified(1874) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[62];
+                let rhs: i64 = program.mem()[70];
+                let addr = 65;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1878;
+            }
+            1882 => {
+                if guard.is_modified(1882) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[1];
+                let rhs: i64 = program.mem()[15];
+                let addr = 9;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1886;
+            }
+            1883 => {
+                if guard.is_modified(1883) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[15];
+                let rhs: i64 = program.mem()[9];
+                let addr = 48;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1887;
+            }
+            1885 => {
+                if guard.is_modified(1885) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[48];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 1887;
+            }
+            1891 => {
+                if guard.is_modified(1891) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[53];
+                let rhs: i64 = program.mem()[80];
+                let addr = 66;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1895;
+            }
+            1895 => {
+                if guard.is_modified(1895) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[72];
+                let target: i64 = program.mem()[35];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 1898 };
+            }
+            1906 => {
+                if guard.is_modified(1906) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[84];
+                let rhs: i64 = program.mem()[64];
+                let addr = 75;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1910;
+            }
+            1921 => {
+                if guard.is_modified(1921) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[34];
+                let target: i64 = program.mem()[7];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 1924 };
+            }
+            1923 => {
+                if guard.is_modified(1923) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[23];
+                let rhs: i64 = program.mem()[41];
+                let addr = 87;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1927;
+            }
+            1937 => {
+                if guard.is_modified(1937) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[89];
+                let target: i64 = program.mem()[68];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 1940 };
+            }
+            1956 => {
+                if guard.is_modified(1956) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[3];
+                program.count_instruction();
+                ip = 1958;
+                break Native::Paused(State::Output(val));
+            }
+            1957 => {
+                if guard.is_modified(1957) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 93;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1959;
+            }
+            1959 => {
+                if guard.is_modified(1959) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[50];
+                let rhs: i64 = program.mem()[62];
+                let addr = 27;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1963;
+            }
+            1964 => {
+                if guard.is_modified(1964) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[6];
+                let rhs: i64 = program.mem()[18];
+                let addr = 44;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1968;
+            }
+            1965 => {
+                if guard.is_modified(1965) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[18];
+                let target: i64 = program.mem()[44];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 1968 };
+            }
+            1981 => {
+                if guard.is_modified(1981) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[74];
+                let target: i64 = program.mem()[7];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 1984 };
+            }
+            1983 => {
+                if guard.is_modified(1983) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[77];
+                let rhs: i64 = program.mem()[53];
+                let addr = 18;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 1987;
+            }
+            1987 => {
+                if guard.is_modified(1987) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[98];
+                let target: i64 = program.mem()[62];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 1990 };
+            }
+            2003 => {
+                if guard.is_modified(2003) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[54];
+                let target: i64 = program.mem()[67];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 2006 };
+            }
+            2010 => {
+                if guard.is_modified(2010) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 70;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2012;
+            }
+            2019 => {
+                if guard.is_modified(2019) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[14];
+                let rhs: i64 = program.mem()[27];
+                let addr = 11;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2023;
+            }
+            2031 => {
+                if guard.is_modified(2031) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[37];
+                let target: i64 = program.mem()[86];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 2034 };
+            }
+            2049 => {
+                if guard.is_modified(2049) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[59];
+                let rhs: i64 = program.mem()[39];
+                let addr = 50;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2053;
+            }
+            2075 => {
+                if guard.is_modified(2075) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[85];
+                let target: i64 = program.mem()[10];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 2078 };
+            }
+            2121 => {
+                if guard.is_modified(2121) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[23];
+                let target: i64 = program.mem()[37];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 2124 };
+            }
+            2127 => {
+                if guard.is_modified(2127) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[73];
+                let target: i64 = program.mem()[38];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 2130 };
+            }
+            2138 => {
+                if guard.is_modified(2138) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[9];
+                let rhs: i64 = program.mem()[61];
+                let addr = 62;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2142;
+            }
+            2139 => {
+                if guard.is_modified(2139) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[61];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 2141;
+            }
+            2146 => {
+                if guard.is_modified(2146) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[23];
+                let rhs: i64 = program.mem()[82];
+                let addr = 56;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2150;
+            }
+            2151 => {
+                if guard.is_modified(2151) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[9];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 2153;
+            }
+            2152 => {
+                if guard.is_modified(2152) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[73];
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 2154;
+            }
+            2155 => {
+                if guard.is_modified(2155) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 25;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2157;
+            }
+            2161 => {
+                if guard.is_modified(2161) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[86];
+                let target: i64 = program.mem()[96];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 2164 };
+            }
+            2219 => {
+                if guard.is_modified(2219) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[28];
+                let rhs: i64 = program.mem()[2];
+                let addr = 83;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2223;
+            }
+            2221 => {
+                if guard.is_modified(2221) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[83];
+                let rhs: i64 = program.mem()[35];
+                let addr = 20;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2225;
+            }
+            2260 => {
+                if guard.is_modified(2260) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[87];
+                let rhs: i64 = program.mem()[55];
+                let addr = 25;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2264;
+            }
+            2302 => {
+                if guard.is_modified(2302) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 76;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2304;
+            }
+            2307 => {
+                if guard.is_modified(2307) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[57];
+                let target: i64 = program.mem()[71];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 2310 };
+            }
+            2327 => {
+                if guard.is_modified(2327) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[18];
+                let rhs: i64 = program.mem()[97];
+                let addr = 72;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2331;
+            }
+            2337 => {
+                if guard.is_modified(2337) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[29];
+                let target: i64 = program.mem()[81];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 2340 };
+            }
+            2340 => {
+                if guard.is_modified(2340) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[21];
+                let target: i64 = program.mem()[87];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 2343 };
+            }
+            2356 => {
+                if guard.is_modified(2356) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[43];
+                let target: i64 = program.mem()[26];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 2359 };
+            }
+            2359 => {
+                if guard.is_modified(2359) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[60];
+                let rhs: i64 = program.mem()[36];
+                let addr = 34;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2363;
+            }
+            2372 => {
+                if guard.is_modified(2372) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[18];
+                program.count_instruction();
+                ip = 2374;
+                break Native::Paused(State::Output(val));
+            }
+            2388 => {
+                if guard.is_modified(2388) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[34];
+                let target: i64 = program.mem()[2];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 2391 };
+            }
+            2390 => {
+                if guard.is_modified(2390) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[11];
+                let rhs: i64 = program.mem()[90];
+                let addr = 37;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2394;
+            }
+            2409 => {
+                if guard.is_modified(2409) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[78];
+                let target: i64 = program.mem()[26];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val != 0 { target } else { 2412 };
+            }
+            2423 => {
+                if guard.is_modified(2423) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[2];
+                let rhs: i64 = program.mem()[34];
+                let addr = 37;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2427;
+            }
+            2424 => {
+                if guard.is_modified(2424) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[34];
+                let rhs: i64 = program.mem()[37];
+                let addr = 36;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2428;
+            }
+            2431 => {
+                if guard.is_modified(2431) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[50];
+                let rhs: i64 = program.mem()[26];
+                let addr = 29;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2435;
+            }
+            2453 => {
+                if guard.is_modified(2453) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[3];
+                let rhs: i64 = program.mem()[8];
+                let addr = 67;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2457;
+            }
+            2454 => {
+                if guard.is_modified(2454) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 8;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2456;
+            }
+            2455 => {
+                if guard.is_modified(2455) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[67];
+                let rhs: i64 = program.mem()[87];
+                let addr = 25;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2459;
+            }
+            2490 => {
+                if guard.is_modified(2490) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[97];
+                let rhs: i64 = program.mem()[97];
+                let addr = 43;
+                let val = match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2494;
+            }
+            2516 => {
+                if guard.is_modified(2516) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[37];
+                let rhs: i64 = program.mem()[25];
+                let addr = 31;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2520;
+            }
+            2520 => {
+                if guard.is_modified(2520) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 16;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2522;
+            }
+            2534 => {
+                if guard.is_modified(2534) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[55];
+                let rhs: i64 = program.mem()[89];
+                let addr = 54;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2538;
+            }
+            2544 => {
+                if guard.is_modified(2544) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[75];
+                let rhs: i64 = program.mem()[84];
+                let addr = 6;
+                let val = (lhs < rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2548;
+            }
+            2547 => {
+                if guard.is_modified(2547) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[64];
+                let target: i64 = program.mem()[15];
+                if target < 0 { break Native::Fallback; }
+                let target = target as usize;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 2550 };
+            }
+            2559 => {
+                if guard.is_modified(2559) || program.check_budgets().is_err() { break Native::Fallback; }
+                let addr = 62;
+                let val = match program.pop_input() { Some(val) => val, None => break Native::Paused(State::NeedsInput) };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 2561;
+            }
+            _ => break Native::Fallback,
+        }
+    };
+    program.set_ip(ip);
+    program.set_rel_base(rb);
+    native
+}
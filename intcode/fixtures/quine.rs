@@ -0,0 +1,101 @@
+// Generated by intcode::translate, do not edit
+
+pub static TRANSLATED: intcode::Translated = intcode::Translated {
+    image: &IMAGE,
+    instrs: &INSTRS,
+    native,
+};
+
+static IMAGE: [i64; 16] = [109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99];
+
+// addr and size of the translated instructions
+static INSTRS: [(usize, usize); 8] = [(0, 2), (2, 2), (4, 4), (6, 4), (8, 4), (11, 4), (12, 3), (15, 1)];
+
+#[inline(always)]
+#[allow(dead_code)]
+fn rel(rb: i64, offset: i64) -> Option<usize> {
+    match rb.checked_add(offset) {
+        Some(addr) if addr >= 0 => Some(addr as usize),
+        _ => None,
+    }
+}
+
+#[allow(unused_mut, clippy::all)]
+fn native(program: &mut intcode::Program, guard: &mut intcode::CodeGuard) -> intcode::Native {
+    use intcode::{Native, State};
+    let mut ip = program.ip();
+    let mut rb = program.rel_base();
+    let native = loop {
+        match ip {
+            0 => {
+                if guard.is_modified(0) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = 1;
+                rb = rb.saturating_add(val);
+                program.count_instruction();
+                ip = 2;
+            }
+            2 => {
+                if guard.is_modified(2) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = match rel(rb, -1) { Some(addr) => program.mem()[addr], None => break Native::Fallback };
+                program.count_instruction();
+                ip = 4;
+                break Native::Paused(State::Output(val));
+            }
+            4 => {
+                if guard.is_modified(4) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[100];
+                let rhs: i64 = 1;
+                let addr = 100;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 8;
+            }
+            6 => {
+                if guard.is_modified(6) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[100];
+                let rhs: i64 = program.mem()[1008];
+                let addr = 100;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 10;
+            }
+            8 => {
+                if guard.is_modified(8) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = program.mem()[100];
+                let rhs: i64 = 16;
+                let addr = 101;
+                let val = (lhs == rhs) as i64;
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 12;
+            }
+            11 => {
+                if guard.is_modified(11) || program.check_budgets().is_err() { break Native::Fallback; }
+                let lhs: i64 = 1006;
+                let rhs: i64 = program.mem()[101];
+                let addr = 0;
+                let val = match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback };
+                guard.write(program, addr, val);
+                program.count_instruction();
+                ip = 15;
+            }
+            12 => {
+                if guard.is_modified(12) || program.check_budgets().is_err() { break Native::Fallback; }
+                let val: i64 = program.mem()[101];
+                let target = 0;
+                program.count_instruction();
+                ip = if val == 0 { target } else { 15 };
+            }
+            15 => {
+                if guard.is_modified(15) { break Native::Fallback; }
+                break Native::Paused(State::Halted);
+            }
+            _ => break Native::Fallback,
+        }
+    };
+    program.set_ip(ip);
+    program.set_rel_base(rb);
+    native
+}
@@ -0,0 +1,388 @@
+use crate::disasm::{decode, Instr};
+use crate::op::{Op, ParamMode};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+// Control flow graph of a program image
+//
+// Code is discovered from addr 0 by following direct jumps (immediate jump targets) and
+// fall through. Jumps with Addr or Rel target can not be followed, they are reported as
+// indirect jumps (usually returns from a function).
+//
+// A call is an instruction storing a direct value, followed by a jump which is always
+// taken, e.g.
+//
+//   ADD #42, #0 -> [r+1]
+//   JT #1, #func
+//
+// If the value is the addr of a valid instruction, it is treated as return site, it is
+// discovered as code and connected to the block of the call with an Edge::Return.
+//
+// Writes with Addr mode target into discovered code are reported as code writes, the
+// modified blocks are colored red in the DOT export. Writes with Rel mode target are not
+// known statically and not reported.
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Edge {
+    Taken,
+    FallThrough,
+    // from a call to its return site
+    Return,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    pub start: usize,
+    pub instrs: Vec<(usize, Instr)>,
+    pub succs: Vec<(usize, Edge)>,
+}
+
+impl Block {
+    // Addr behind the last instruction
+    pub fn end(&self) -> usize {
+        self.instrs
+            .last()
+            .map(|(addr, instr)| addr + instr.size())
+            .unwrap_or(self.start)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CodeWrite {
+    // addr of the writing instruction
+    pub addr: usize,
+    pub target: usize,
+    // addr of the instruction containing target
+    pub instr: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cfg {
+    pub blocks: BTreeMap<usize, Block>,
+    // addrs of jumps with Addr or Rel target
+    pub indirect_jumps: BTreeSet<usize>,
+    pub code_writes: Vec<CodeWrite>,
+}
+
+fn is_jump(op: Op) -> bool {
+    op == Op::JumpIfTrue || op == Op::JumpIfFalse
+}
+
+// Return site of a call at addr
+fn return_site(mem: &[i64], addr: usize, instr: &Instr) -> Option<usize> {
+    let (lhs, rhs) = match instr.op {
+        Op::Add | Op::Mul => (instr.params[0], instr.params[1]),
+        _ => return None,
+    };
+    let neutral = if instr.op == Op::Add { 0 } else { 1 };
+    if lhs.mode != ParamMode::Val || rhs.mode != ParamMode::Val {
+        return None;
+    }
+    let val = if rhs.val == neutral {
+        lhs.val
+    } else if lhs.val == neutral {
+        rhs.val
+    } else {
+        return None;
+    };
+    let next = decode(mem, addr + instr.size())?;
+    if !is_jump(next.op) || next.jump_taken() != Some(true) || val < 0 {
+        return None;
+    }
+    decode(mem, val as usize).map(|_| val as usize)
+}
+
+pub fn analyze(mem: &[i64]) -> Cfg {
+    // discover code
+    let mut instrs = BTreeMap::new();
+    let mut leaders = BTreeSet::new();
+    // addr of the jump of a call and its return site
+    let mut returns = BTreeMap::new();
+    let mut todo = vec![0];
+    leaders.insert(0);
+    while let Some(addr) = todo.pop() {
+        if instrs.contains_key(&addr) {
+            continue;
+        }
+        let instr = match decode(mem, addr) {
+            Some(instr) => instr,
+            None => continue,
+        };
+        let next = addr + instr.size();
+        if let Some(site) = return_site(mem, addr, &instr) {
+            returns.insert(next, site);
+            leaders.insert(site);
+            todo.push(site);
+        }
+        if let Some(target) = instr.jump_target() {
+            if instr.jump_taken() != Some(false) {
+                leaders.insert(target);
+                todo.push(target);
+            }
+        }
+        if is_jump(instr.op) || instr.op == Op::Halt {
+            leaders.insert(next);
+        }
+        if instr.falls_through() {
+            todo.push(next);
+        }
+        instrs.insert(addr, instr);
+    }
+
+    // split into blocks
+    let mut blocks = BTreeMap::<usize, Block>::new();
+    let mut current: Option<Block> = None;
+    for (&addr, instr) in &instrs {
+        let mut block = match current.take() {
+            Some(block) if block.end() == addr && !leaders.contains(&addr) => block,
+            prev => {
+                if let Some(prev) = prev {
+                    blocks.insert(prev.start, prev);
+                }
+                Block {
+                    start: addr,
+                    instrs: Vec::new(),
+                    succs: Vec::new(),
+                }
+            }
+        };
+        block.instrs.push((addr, instr.clone()));
+        if is_jump(instr.op) || instr.op == Op::Halt {
+            blocks.insert(block.start, block);
+        } else {
+            current = Some(block);
+        }
+    }
+    if let Some(block) = current {
+        blocks.insert(block.start, block);
+    }
+
+    // connect blocks
+    let mut indirect_jumps = BTreeSet::new();
+    for block in blocks.values_mut() {
+        let (addr, instr) = block.instrs.last().unwrap();
+        let next = block.end();
+        if is_jump(instr.op) {
+            match instr.jump_target() {
+                Some(target) if instr.jump_taken() != Some(false) => {
+                    block.succs.push((target, Edge::Taken))
+                }
+                Some(_) => (),
+                None if instr.params[1].mode == ParamMode::Val => (),
+                None => {
+                    indirect_jumps.insert(*addr);
+                }
+            }
+            if let Some(&site) = returns.get(addr) {
+                block.succs.push((site, Edge::Return));
+            }
+        }
+        if instr.falls_through() && instrs.contains_key(&next) {
+            block.succs.push((next, Edge::FallThrough));
+        }
+    }
+
+    // writes into code
+    let mut cells = BTreeMap::new();
+    for (&addr, instr) in &instrs {
+        for cell in addr..addr + instr.size() {
+            cells.insert(cell, addr);
+        }
+    }
+    let mut code_writes = Vec::new();
+    for (&addr, instr) in &instrs {
+        if !instr.op.writes() {
+            continue;
+        }
+        let param = instr.params.last().unwrap();
+        if param.mode != ParamMode::Addr || param.val < 0 {
+            continue;
+        }
+        let target = param.val as usize;
+        if let Some(&instr) = cells.get(&target) {
+            code_writes.push(CodeWrite {
+                addr,
+                target,
+                instr,
+            });
+        }
+    }
+
+    Cfg {
+        blocks,
+        indirect_jumps,
+        code_writes,
+    }
+}
+
+impl Cfg {
+    // Block containing the instruction at addr
+    pub fn block_of(&self, addr: usize) -> Option<&Block> {
+        self.blocks
+            .range(..=addr)
+            .next_back()
+            .map(|(_, block)| block)
+            .filter(|block| block.instrs.iter().any(|(a, _)| *a == addr))
+    }
+
+    // Graphviz DOT format, one node per block
+    pub fn to_dot(&self) -> String {
+        let modified = self
+            .code_writes
+            .iter()
+            .filter_map(|write| self.block_of(write.instr))
+            .map(|block| block.start)
+            .collect::<BTreeSet<_>>();
+
+        let mut dot = String::new();
+        writeln!(dot, "digraph cfg {{").unwrap();
+        writeln!(dot, "    node [shape=box, fontname=\"monospace\"];").unwrap();
+        for block in self.blocks.values() {
+            let mut label = String::new();
+            for (addr, instr) in &block.instrs {
+                write!(label, "{:04}: {}\\l", addr, instr).unwrap();
+            }
+            let color = if modified.contains(&block.start) {
+                ", color=red"
+            } else {
+                ""
+            };
+            writeln!(
+                dot,
+                "    b{:04} [label=\"{}\"{}];",
+                block.start, label, color
+            )
+            .unwrap();
+        }
+        if !self.indirect_jumps.is_empty() {
+            writeln!(dot, "    indirect [shape=ellipse, label=\"?\"];").unwrap();
+        }
+        // successors without a block, e.g. jumps out of the image
+        let unknown = self
+            .blocks
+            .values()
+            .flat_map(|block| block.succs.iter().map(|&(succ, _)| succ))
+            .filter(|succ| !self.blocks.contains_key(succ))
+            .collect::<BTreeSet<_>>();
+        for addr in &unknown {
+            writeln!(
+                dot,
+                "    unknown_{:04} [shape=ellipse, label=\"{:04}: ?\"];",
+                addr, addr
+            )
+            .unwrap();
+        }
+        for block in self.blocks.values() {
+            for (succ, edge) in &block.succs {
+                let style = match edge {
+                    Edge::Taken => "label=\"taken\"",
+                    Edge::FallThrough => "",
+                    Edge::Return => "style=dashed, label=\"return\"",
+                };
+                let node = if unknown.contains(succ) {
+                    "unknown_"
+                } else {
+                    "b"
+                };
+                writeln!(
+                    dot,
+                    "    b{:04} -> {}{:04} [{}];",
+                    block.start, node, succ, style
+                )
+                .unwrap();
+            }
+            let (addr, _) = block.instrs.last().unwrap();
+            if self.indirect_jumps.contains(addr) {
+                writeln!(dot, "    b{:04} -> indirect [style=dotted];", block.start).unwrap();
+            }
+        }
+        for write in &self.code_writes {
+            if let (Some(from), Some(to)) = (self.block_of(write.addr), self.block_of(write.instr))
+            {
+                writeln!(
+                    dot,
+                    "    b{:04} -> b{:04} [color=red, style=dotted, label=\"writes {}\"];",
+                    from.start, to.start, write.target
+                )
+                .unwrap();
+            }
+        }
+        writeln!(dot, "}}").unwrap();
+        dot
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::asm::assemble;
+
+    #[test]
+    fn test_analyze() {
+        let mem = assemble(
+            "
+                in x
+                add #ret, #0, r+1
+                jt #1, #func
+            ret:
+                out x
+                hlt
+            func:
+                add x, #1, patch
+            patch:
+                out 0
+                jf #0, r+1
+            x:  data 0
+            ",
+        )
+        .unwrap();
+        let cfg = analyze(&mem);
+        assert_eq!(
+            cfg.blocks.keys().copied().collect::<Vec<_>>(),
+            vec![0, 9, 12]
+        );
+        assert_eq!(
+            cfg.blocks[&0].succs,
+            vec![(12, Edge::Taken), (9, Edge::Return)]
+        );
+        assert_eq!(cfg.blocks[&9].end(), 12);
+        assert!(cfg.blocks[&12].succs.is_empty());
+        assert_eq!(cfg.indirect_jumps, vec![18].into_iter().collect());
+        assert_eq!(
+            cfg.code_writes,
+            vec![CodeWrite {
+                addr: 12,
+                target: 16,
+                instr: 16
+            }]
+        );
+        assert_eq!(cfg.block_of(16).map(|block| block.start), Some(12));
+
+        let dot = cfg.to_dot();
+        assert!(dot.contains(
+            "b0012 [label=\"0012: ADD [21], #1 -> [16]\\l0016: OUT [0]\\l\
+             0018: JF #0, [r+1]\\l\", color=red];"
+        ));
+        assert!(dot.contains("b0000 -> b0009 [style=dashed, label=\"return\"];"));
+        assert!(dot.contains("b0012 -> indirect [style=dotted];"));
+        assert!(dot.contains("b0012 -> b0012 [color=red, style=dotted, label=\"writes 16\"];"));
+    }
+
+    #[test]
+    fn test_dot_unknown_target() {
+        // the jump leaves the image
+        let cfg = analyze(&[1105, 1, 100]);
+        let dot = cfg.to_dot();
+        assert!(dot.contains("unknown_0100 [shape=ellipse, label=\"0100: ?\"];"));
+        assert!(dot.contains("b0000 -> unknown_0100 [label=\"taken\"];"));
+        assert!(!dot.contains("b0100"));
+    }
+
+    #[test]
+    fn test_day02_writes_code() {
+        let mem = crate::program::parse_program(include_str!("../../day02/src/input.txt")).unwrap();
+        let cfg = analyze(&mem);
+        assert!(!cfg.code_writes.is_empty());
+        assert!(cfg.indirect_jumps.is_empty());
+    }
+}
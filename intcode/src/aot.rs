@@ -0,0 +1,312 @@
+use crate::analysis::analyze;
+use crate::disasm::{decode, Instr, Param};
+use crate::op::{Op, ParamMode};
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+// Ahead of time translation of a program image into Rust code
+//
+// Every addr of the image which holds a valid instruction is translated into an arm of a
+// `loop { match ip { .. } }` state machine, with the params inlined. Most programs jump
+// through tables or modify their code before running it, so only following the static
+// control flow (see analyze) would miss most of the code.
+//
+// The generated module is included into a crate, written by the aot tool or by a build
+// script of a crate which depends on intcode:
+//
+//   // build.rs
+//   fs::write(out_dir.join("program.rs"), intcode::translate(&mem))
+//
+//   // main.rs
+//   mod program {
+//       include!(concat!(env!("OUT_DIR"), "/program.rs"));
+//   }
+//   NativeProgram::new(mem, &program::TRANSLATED)
+//
+// Not translated are instructions which the reachable code overwrites with static writes,
+// and instructions with a statically invalid param (negative addr or jump target). The native
+// code leaves everything it can not handle to the interpreter, see NativeProgram:
+// - ips without an arm
+// - instructions which were modified at runtime
+// - faults, overflows and exhausted budgets, the interpreter reports the exact error
+//
+// The tests and benches run the day inputs from intcode/fixtures, the fuzzer the small
+// programs compare and quine. test_fixtures fails when they are out of date, regenerate
+// them in intcode/ after changes of the translation:
+//
+//   for day in 02 05 07 09 11 13; do
+//       cargo run --bin aot -- ../day$day/src/input.txt fixtures/day$day.rs
+//   done
+//   cargo run --bin aot -- fixtures/compare.txt fixtures/compare.rs
+//   cargo run --bin aot -- fixtures/quine.txt fixtures/quine.rs
+
+fn is_static(instr: &Instr) -> bool {
+    let negative = |param: &Param| param.mode == ParamMode::Addr && param.val < 0;
+    let bad_target = match instr.op {
+        Op::JumpIfTrue | Op::JumpIfFalse => {
+            instr.params[1].mode == ParamMode::Val && instr.params[1].val < 0
+        }
+        _ => false,
+    };
+    !instr.params.iter().any(negative) && !bad_target
+}
+
+// Literal of val, i64::MIN has no literal
+fn literal(val: i64) -> String {
+    if val == i64::MIN {
+        "i64::MIN".to_string()
+    } else {
+        val.to_string()
+    }
+}
+
+// Expression reading the value of param
+fn read(param: Param) -> String {
+    match param.mode {
+        ParamMode::Val => literal(param.val),
+        ParamMode::Addr => format!("program.mem()[{}]", param.val),
+        ParamMode::Rel => format!(
+            "match rel(rb, {}) {{ Some(addr) => program.mem()[addr], None => break Native::Fallback }}",
+            literal(param.val)
+        ),
+    }
+}
+
+// Expression for the addr to write to
+fn target(param: Param) -> String {
+    match param.mode {
+        ParamMode::Rel => format!(
+            "match rel(rb, {}) {{ Some(addr) => addr, None => break Native::Fallback }}",
+            literal(param.val)
+        ),
+        _ => param.val.to_string(),
+    }
+}
+
+// Body of the arm for the instruction at addr
+fn arm(addr: usize, instr: &Instr) -> String {
+    let params = &instr.params;
+    let next = addr + instr.size();
+    let mut body = String::new();
+    if instr.op == Op::Halt {
+        writeln!(
+            body,
+            "if guard.is_modified({}) {{ break Native::Fallback; }}",
+            addr
+        )
+        .unwrap();
+        writeln!(body, "break Native::Paused(State::Halted);").unwrap();
+        return body;
+    }
+    writeln!(body, "if guard.is_modified({}) || program.check_budgets().is_err() {{ break Native::Fallback; }}", addr).unwrap();
+    match instr.op {
+        Op::Add | Op::Mul | Op::Less | Op::Equals => {
+            writeln!(body, "let lhs: i64 = {};", read(params[0])).unwrap();
+            writeln!(body, "let rhs: i64 = {};", read(params[1])).unwrap();
+            writeln!(body, "let addr = {};", target(params[2])).unwrap();
+            let val = match instr.op {
+                Op::Add => "match lhs.checked_add(rhs) { Some(val) => val, None => break Native::Fallback }",
+                Op::Mul => "match lhs.checked_mul(rhs) { Some(val) => val, None => break Native::Fallback }",
+                Op::Less => "(lhs < rhs) as i64",
+                _ => "(lhs == rhs) as i64",
+            };
+            writeln!(body, "let val = {};", val).unwrap();
+            writeln!(body, "guard.write(program, addr, val);").unwrap();
+        }
+        Op::In => {
+            writeln!(body, "let addr = {};", target(params[0])).unwrap();
+            writeln!(body, "let val = match program.pop_input() {{ Some(val) => val, None => break Native::Paused(State::NeedsInput) }};").unwrap();
+            writeln!(body, "guard.write(program, addr, val);").unwrap();
+        }
+        Op::Out => {
+            writeln!(body, "let val: i64 = {};", read(params[0])).unwrap();
+            writeln!(body, "program.count_instruction();").unwrap();
+            writeln!(body, "ip = {};", next).unwrap();
+            writeln!(body, "break Native::Paused(State::Output(val));").unwrap();
+            return body;
+        }
+        Op::JumpIfTrue | Op::JumpIfFalse => {
+            writeln!(body, "let val: i64 = {};", read(params[0])).unwrap();
+            match params[1].mode {
+                ParamMode::Val => writeln!(body, "let target = {};", params[1].val).unwrap(),
+                _ => {
+                    writeln!(body, "let target: i64 = {};", read(params[1])).unwrap();
+                    writeln!(body, "if target < 0 {{ break Native::Fallback; }}").unwrap();
+                    writeln!(body, "let target = target as usize;").unwrap();
+                }
+            }
+            let cond = if instr.op == Op::JumpIfTrue {
+                "!="
+            } else {
+                "=="
+            };
+            writeln!(body, "program.count_instruction();").unwrap();
+            writeln!(
+                body,
+                "ip = if val {} 0 {{ target }} else {{ {} }};",
+                cond, next
+            )
+            .unwrap();
+            return body;
+        }
+        Op::SetRelBase => {
+            writeln!(body, "let val: i64 = {};", read(params[0])).unwrap();
+            writeln!(body, "rb = rb.saturating_add(val);").unwrap();
+        }
+        Op::Halt => unreachable!(),
+    }
+    writeln!(body, "program.count_instruction();").unwrap();
+    writeln!(body, "ip = {};", next).unwrap();
+    body
+}
+
+// Translate the program image mem into a Rust module, which defines
+// `pub static TRANSLATED: intcode::Translated`
+pub fn translate(mem: &[i64]) -> String {
+    // static writes of the reachable code into itself
+    let targets = analyze(mem)
+        .code_writes
+        .iter()
+        .map(|write| write.target)
+        .collect::<BTreeSet<_>>();
+    let instrs = (0..mem.len())
+        .filter_map(|addr| decode(mem, addr).map(|instr| (addr, instr)))
+        .filter(|(addr, instr)| {
+            is_static(instr) && targets.range(addr..&(addr + instr.size())).next().is_none()
+        })
+        .collect::<Vec<_>>();
+
+    let mut out = String::new();
+    writeln!(out, "// Generated by intcode::translate, do not edit").unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "pub static TRANSLATED: intcode::Translated = intcode::Translated {{"
+    )
+    .unwrap();
+    writeln!(out, "    image: &IMAGE,").unwrap();
+    writeln!(out, "    instrs: &INSTRS,").unwrap();
+    writeln!(out, "    native,").unwrap();
+    writeln!(out, "}};").unwrap();
+    writeln!(out).unwrap();
+    let image = mem.iter().map(|&val| literal(val)).collect::<Vec<_>>();
+    writeln!(
+        out,
+        "static IMAGE: [i64; {}] = [{}];",
+        mem.len(),
+        image.join(", ")
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    let sizes = instrs
+        .iter()
+        .map(|(addr, instr)| format!("({}, {})", addr, instr.size()))
+        .collect::<Vec<_>>();
+    writeln!(out, "// addr and size of the translated instructions").unwrap();
+    writeln!(
+        out,
+        "static INSTRS: [(usize, usize); {}] = [{}];",
+        instrs.len(),
+        sizes.join(", ")
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "#[inline(always)]").unwrap();
+    writeln!(out, "#[allow(dead_code)]").unwrap();
+    writeln!(out, "fn rel(rb: i64, offset: i64) -> Option<usize> {{").unwrap();
+    writeln!(out, "    match rb.checked_add(offset) {{").unwrap();
+    writeln!(
+        out,
+        "        Some(addr) if addr >= 0 => Some(addr as usize),"
+    )
+    .unwrap();
+    writeln!(out, "        _ => None,").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "#[allow(unused_mut, clippy::all)]").unwrap();
+    writeln!(out, "fn native(program: &mut intcode::Program, guard: &mut intcode::CodeGuard) -> intcode::Native {{").unwrap();
+    writeln!(out, "    use intcode::{{Native, State}};").unwrap();
+    writeln!(out, "    let mut ip = program.ip();").unwrap();
+    writeln!(out, "    let mut rb = program.rel_base();").unwrap();
+    writeln!(out, "    let native = loop {{").unwrap();
+    writeln!(out, "        match ip {{").unwrap();
+    for (addr, instr) in &instrs {
+        writeln!(out, "            {} => {{", addr).unwrap();
+        for line in arm(*addr, instr).lines() {
+            writeln!(out, "                {}", line).unwrap();
+        }
+        writeln!(out, "            }}").unwrap();
+    }
+    writeln!(out, "            _ => break Native::Fallback,").unwrap();
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "    }};").unwrap();
+    writeln!(out, "    program.set_ip(ip);").unwrap();
+    writeln!(out, "    program.set_rel_base(rb);").unwrap();
+    writeln!(out, "    native").unwrap();
+    writeln!(out, "}}").unwrap();
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::program::parse_program;
+
+    #[test]
+    fn test_translate() {
+        // the add at 0 overwrites the param of the out at 8, the add at 4 a param of the
+        // add at 0, every other addr is decoded
+        let mem = vec![1101, 1, 2, 9, 1101, 3, 4, 1, 4, 9, 99];
+        let out = translate(&mem);
+        assert!(out.contains("static IMAGE: [i64; 11] = [1101, 1, 2, 9, 1101, 3, 4, 1, 4, 9, 99];"));
+        assert!(out.contains(
+            "static INSTRS: [(usize, usize); 6] = [(2, 4), (3, 2), (4, 4), (5, 2), (6, 2), (10, 1)];"
+        ));
+        assert!(!out.contains("            0 => {"));
+        assert!(out.contains("            4 => {"));
+        assert!(translate(&[i64::MIN, 99]).contains("[i64::MIN, 99]"));
+    }
+
+    #[test]
+    fn test_fixtures() {
+        // regenerate with the aot tool, see the top of the file
+        for (input, fixture) in &[
+            (
+                include_str!("../../day02/src/input.txt"),
+                include_str!("../fixtures/day02.rs"),
+            ),
+            (
+                include_str!("../../day05/src/input.txt"),
+                include_str!("../fixtures/day05.rs"),
+            ),
+            (
+                include_str!("../../day07/src/input.txt"),
+                include_str!("../fixtures/day07.rs"),
+            ),
+            (
+                include_str!("../../day09/src/input.txt"),
+                include_str!("../fixtures/day09.rs"),
+            ),
+            (
+                include_str!("../../day11/src/input.txt"),
+                include_str!("../fixtures/day11.rs"),
+            ),
+            (
+                include_str!("../../day13/src/input.txt"),
+                include_str!("../fixtures/day13.rs"),
+            ),
+            (
+                include_str!("../fixtures/compare.txt"),
+                include_str!("../fixtures/compare.rs"),
+            ),
+            (
+                include_str!("../fixtures/quine.txt"),
+                include_str!("../fixtures/quine.rs"),
+            ),
+        ] {
+            let mem = parse_program(input).unwrap();
+            assert!(translate(&mem) == *fixture, "Fixture is out of date");
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use crate::error::VmError;
+use crate::io::{Input, Output};
+use crate::program::{Program, State};
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+// ASCII I/O of programs which read and write text
+//
+// - outputs are collected into lines, a 10 ends a line
+// - outputs outside of 0..=127 are not text (e.g. a final score), they are passed through as
+//   numbers, an unterminated line before them is ended
+// - inputs are lines of text, each char is sent as its code point followed by a 10
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Chunk {
+    Line(String),
+    Value(i64),
+}
+
+fn is_ascii(val: i64) -> bool {
+    (0..=127).contains(&val)
+}
+
+// Code points of line followed by a newline
+pub fn encode_line(line: &str) -> Vec<i64> {
+    line.chars()
+        .map(|c| c as i64)
+        .chain(std::iter::once(10))
+        .collect()
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AsciiInput {
+    queue: VecDeque<i64>,
+}
+
+impl AsciiInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_lines(lines: &[&str]) -> Self {
+        let mut input = Self::new();
+        for line in lines {
+            input.push_line(line);
+        }
+        input
+    }
+
+    pub fn push_line(&mut self, line: &str) {
+        self.queue.extend(encode_line(line));
+    }
+}
+
+impl Input for AsciiInput {
+    fn read(&mut self) -> Option<i64> {
+        self.queue.pop_front()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AsciiOutput {
+    chunks: Vec<Chunk>,
+    // text after the last newline
+    partial: String,
+}
+
+impl AsciiOutput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Complete lines and values so far
+    pub fn take(&mut self) -> Vec<Chunk> {
+        std::mem::take(&mut self.chunks)
+    }
+
+    // Text which is not terminated yet, e.g. a prompt
+    pub fn partial(&self) -> &str {
+        &self.partial
+    }
+
+    // All chunks, an unterminated line is returned as the last line
+    pub fn finish(mut self) -> Vec<Chunk> {
+        if !self.partial.is_empty() {
+            self.chunks.push(Chunk::Line(self.partial));
+        }
+        self.chunks
+    }
+}
+
+impl Output for AsciiOutput {
+    fn write(&mut self, val: i64) {
+        match val {
+            10 => self
+                .chunks
+                .push(Chunk::Line(std::mem::take(&mut self.partial))),
+            _ if is_ascii(val) => self.partial.push(val as u8 as char),
+            _ => {
+                if !self.partial.is_empty() {
+                    self.chunks
+                        .push(Chunk::Line(std::mem::take(&mut self.partial)));
+                }
+                self.chunks.push(Chunk::Value(val));
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AsciiError {
+    Vm(VmError),
+    Io(io::Error),
+}
+
+impl From<VmError> for AsciiError {
+    fn from(err: VmError) -> Self {
+        AsciiError::Vm(err)
+    }
+}
+
+impl From<io::Error> for AsciiError {
+    fn from(err: io::Error) -> Self {
+        AsciiError::Io(err)
+    }
+}
+
+impl fmt::Display for AsciiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AsciiError::Vm(err) => write!(f, "{}", err),
+            AsciiError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for AsciiError {}
+
+impl Program {
+    // Run until halt with text I/O, every time the program needs input a line is read from
+    // reader. Text is written to writer as is, values outside of ASCII on a line of their own.
+    pub fn run_ascii<R, W>(&mut self, mut reader: R, mut writer: W) -> Result<(), AsciiError>
+    where
+        R: BufRead,
+        W: Write,
+    {
+        let mut at_line_start = true;
+        loop {
+            match self.resume()? {
+                State::NeedsInput => {
+                    writer.flush()?;
+                    let mut line = String::new();
+                    if reader.read_line(&mut line)? == 0 {
+                        return Err(AsciiError::Vm(VmError::UnexpectedEof {
+                            ip: self.ip(),
+                            opcode: self.opcode(),
+                        }));
+                    }
+                    for val in encode_line(line.trim_end_matches(&['\r', '\n'][..])) {
+                        self.push_input(val);
+                    }
+                }
+                State::Output(val) if is_ascii(val) => {
+                    writer.write_all(&[val as u8])?;
+                    at_line_start = val == 10;
+                }
+                State::Output(val) => {
+                    if !at_line_start {
+                        writeln!(writer)?;
+                    }
+                    writeln!(writer, "{}", val)?;
+                    at_line_start = true;
+                }
+                State::Halted => {
+                    writer.flush()?;
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    // Interactive text mode on the terminal
+    pub fn run_ascii_stdio(&mut self) -> Result<(), AsciiError> {
+        let stdin = io::stdin();
+        let stdout = io::stdout();
+        self.run_ascii(stdin.lock(), stdout.lock())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // echo a line of text, then output 1000
+    const ECHO: [i64; 22] = [
+        3, 20, 4, 20, 1008, 20, 10, 21, 1006, 21, 0, 104, 1000, 99, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+
+    #[test]
+    fn test_adapter() {
+        assert_eq!(encode_line("a1"), vec![97, 49, 10]);
+        let mut output = AsciiOutput::new();
+        Program::new(ECHO.to_vec())
+            .run(&mut AsciiInput::from_lines(&["hi"]), &mut output)
+            .unwrap();
+        assert_eq!(
+            output.take(),
+            vec![Chunk::Line("hi".to_string()), Chunk::Value(1000)]
+        );
+
+        let mut output = AsciiOutput::new();
+        output.write(62);
+        output.write(32);
+        assert_eq!(output.partial(), "> ");
+        for &val in &[500, 10, 120] {
+            output.write(val);
+        }
+        assert_eq!(
+            output.finish(),
+            vec![
+                Chunk::Line("> ".to_string()),
+                Chunk::Value(500),
+                Chunk::Line("".to_string()),
+                Chunk::Line("x".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_run_ascii() {
+        let mut writer = Vec::new();
+        Program::new(ECHO.to_vec())
+            .run_ascii(&b"hello\r\n"[..], &mut writer)
+            .unwrap();
+        assert_eq!(String::from_utf8(writer).unwrap(), "hello\n1000\n");
+
+        let result = Program::new(ECHO.to_vec()).run_ascii(&b""[..], Vec::new());
+        assert!(matches!(
+            result,
+            Err(AsciiError::Vm(VmError::UnexpectedEof { .. }))
+        ));
+    }
+}
@@ -0,0 +1,295 @@
+use crate::op::{Op, ParamMode};
+use std::collections::HashMap;
+use std::fmt;
+
+// Assembler
+//
+// Source format, one statement per line:
+//
+//   ; comment
+//   loop:                        label
+//       add counter, #1, counter mnemonic with params
+//       jt [r+0], #loop          labels can be used as values
+//   counter:
+//       data 0, 1, -1            data directive
+//
+// Params:
+//
+// - 120 or [120] param specifies mem addr (ParamMode::Addr)
+// - #7 param is a direct value (ParamMode::Val)
+// - r+3, r-3 or [r+3] param specifies rel mem addr from rel base (ParamMode::Rel)
+//
+// Mnemonics and directives are case insensitive. To assemble listings of the disassembler,
+// -> is accepted as param separator and numeric labels (0042:) are checked against the
+// current addr.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AsmError {
+    pub line: usize,
+    pub msg: String,
+}
+
+impl fmt::Display for AsmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Line {}: {}", self.line, self.msg)
+    }
+}
+
+impl std::error::Error for AsmError {}
+
+enum Value {
+    Num(i64),
+    Label(String),
+}
+
+struct Assembler {
+    mem: Vec<i64>,
+    labels: HashMap<String, usize>,
+    // addr in mem, label and line of every label reference
+    fixups: Vec<(usize, String, usize)>,
+    line: usize,
+}
+
+fn is_label(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+impl Assembler {
+    fn error<T>(&self, msg: String) -> Result<T, AsmError> {
+        Err(AsmError {
+            line: self.line,
+            msg,
+        })
+    }
+
+    fn parse_value(&self, text: &str) -> Result<Value, AsmError> {
+        if let Ok(val) = text.parse() {
+            Ok(Value::Num(val))
+        } else if is_label(text) {
+            Ok(Value::Label(text.to_string()))
+        } else {
+            self.error(format!("Invalid value {:?}", text))
+        }
+    }
+
+    fn parse_param(&self, text: &str) -> Result<(ParamMode, Value), AsmError> {
+        if let Some(val) = text.strip_prefix('#') {
+            return Ok((ParamMode::Val, self.parse_value(val.trim())?));
+        }
+        let text = match text.strip_prefix('[') {
+            Some(inner) => match inner.strip_suffix(']') {
+                Some(inner) => inner.trim(),
+                None => return self.error(format!("Missing ] in {:?}", text)),
+            },
+            None => text,
+        };
+        match text.strip_prefix('r') {
+            Some(offset) if offset.starts_with('+') || offset.starts_with('-') => {
+                let offset = offset.replace(' ', "");
+                let val = offset.trim_start_matches('+');
+                Ok((ParamMode::Rel, self.parse_value(val)?))
+            }
+            _ => Ok((ParamMode::Addr, self.parse_value(text)?)),
+        }
+    }
+
+    fn emit(&mut self, val: Value) {
+        match val {
+            Value::Num(val) => self.mem.push(val),
+            Value::Label(label) => {
+                self.fixups.push((self.mem.len(), label, self.line));
+                self.mem.push(0);
+            }
+        }
+    }
+
+    fn define_label(&mut self, label: &str) -> Result<(), AsmError> {
+        if let Ok(addr) = label.parse::<usize>() {
+            if addr != self.mem.len() {
+                return self.error(format!(
+                    "Addr {} does not match current addr {}",
+                    addr,
+                    self.mem.len()
+                ));
+            }
+        } else if !is_label(label) {
+            return self.error(format!("Invalid label {:?}", label));
+        } else if self
+            .labels
+            .insert(label.to_string(), self.mem.len())
+            .is_some()
+        {
+            return self.error(format!("Duplicate label {:?}", label));
+        }
+        Ok(())
+    }
+
+    fn assemble_line(&mut self, line: &str) -> Result<(), AsmError> {
+        let mut line = match line.find(';') {
+            Some(pos) => &line[..pos],
+            None => line,
+        }
+        .trim();
+        while let Some(pos) = line.find(':') {
+            self.define_label(line[..pos].trim())?;
+            line = line[pos + 1..].trim();
+        }
+        if line.is_empty() {
+            return Ok(());
+        }
+
+        let (mnemonic, rest) = match line.find(char::is_whitespace) {
+            Some(pos) => (&line[..pos], line[pos..].trim()),
+            None => (line, ""),
+        };
+        let rest = rest.replace("->", ",");
+        let params = rest
+            .split(',')
+            .map(str::trim)
+            .filter(|param| !param.is_empty())
+            .collect::<Vec<_>>();
+
+        if mnemonic.eq_ignore_ascii_case("data") {
+            for param in params {
+                let val = self.parse_value(param)?;
+                self.emit(val);
+            }
+            return Ok(());
+        }
+
+        let op = match Op::from_mnemonic(mnemonic) {
+            Some(op) => op,
+            None => return self.error(format!("Unknown mnemonic {:?}", mnemonic)),
+        };
+        if params.len() != op.num_params() {
+            return self.error(format!(
+                "{} expects {} params, got {}",
+                op.mnemonic(),
+                op.num_params(),
+                params.len()
+            ));
+        }
+        let params = params
+            .iter()
+            .map(|param| self.parse_param(param))
+            .collect::<Result<Vec<_>, _>>()?;
+        if op.writes() && params.last().map(|(mode, _)| *mode) == Some(ParamMode::Val) {
+            return self.error(format!("{} can not write to a direct value", op.mnemonic()));
+        }
+        let opcode = params
+            .iter()
+            .enumerate()
+            .fold(op.code(), |opcode, (i, (mode, _))| {
+                opcode + mode.code() * 10_i64.pow(i as u32 + 2)
+            });
+        self.mem.push(opcode);
+        for (_, val) in params {
+            self.emit(val);
+        }
+        Ok(())
+    }
+}
+
+// Assemble source into a program image
+pub fn assemble(src: &str) -> Result<Vec<i64>, AsmError> {
+    let mut assembler = Assembler {
+        mem: Vec::new(),
+        labels: HashMap::new(),
+        fixups: Vec::new(),
+        line: 0,
+    };
+    for (i, line) in src.lines().enumerate() {
+        assembler.line = i + 1;
+        assembler.assemble_line(line)?;
+    }
+    for (addr, label, line) in &assembler.fixups {
+        match assembler.labels.get(label) {
+            Some(&val) => assembler.mem[*addr] = val as i64,
+            None => {
+                return Err(AsmError {
+                    line: *line,
+                    msg: format!("Unknown label {:?}", label),
+                })
+            }
+        }
+    }
+    Ok(assembler.mem)
+}
+
+// Comma separated format of the input.txt files
+pub fn to_program_string(mem: &[i64]) -> String {
+    mem.iter()
+        .map(|val| val.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::disasm::{disassemble, disassemble_with_labels};
+    use crate::program::{parse_program, Program};
+
+    #[test]
+    fn test_assemble() {
+        let src = "
+            ; count down from the input
+                in counter
+            loop:
+                out counter
+                add counter, #-1, counter
+                jt counter, #loop
+                arb #5
+                out [r-1]   ; outputs the opcode at addr 4
+                HLT
+            counter: data 0
+        ";
+        let mem = assemble(src).unwrap();
+        assert_eq!(
+            to_program_string(&mem),
+            "3,16,4,16,1001,16,-1,16,1005,16,2,109,5,204,-1,99,0"
+        );
+        assert_eq!(Program::new(mem).run_collect(&[3]), Ok(vec![3, 2, 1, 1001]));
+    }
+
+    #[test]
+    fn test_assemble_errors() {
+        assert_eq!(
+            assemble("add #1, #2, #3"),
+            Err(AsmError {
+                line: 1,
+                msg: "add can not write to a direct value".to_string()
+            })
+        );
+        assert_eq!(assemble("\nfoo 1").unwrap_err().line, 2);
+        assert_eq!(assemble("out 1, 2").unwrap_err().line, 1);
+        assert_eq!(assemble("jt #1, #nowhere").unwrap_err().line, 1);
+        assert_eq!(assemble("a: a: hlt").unwrap_err().line, 1);
+        assert_eq!(assemble("0001: hlt").unwrap_err().line, 1);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for input in &[
+            include_str!("../../day02/src/input.txt"),
+            include_str!("../../day05/src/input.txt"),
+            include_str!("../../day07/src/input.txt"),
+            include_str!("../../day09/src/input.txt"),
+            include_str!("../../day11/src/input.txt"),
+            include_str!("../../day13/src/input.txt"),
+        ] {
+            let mem = parse_program(input).unwrap();
+            assert_eq!(assemble(&disassemble(&mem).to_string()), Ok(mem.clone()));
+            assert_eq!(
+                assemble(&disassemble_with_labels(&mem).to_string()),
+                Ok(mem.clone())
+            );
+        }
+    }
+}
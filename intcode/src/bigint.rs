@@ -0,0 +1,283 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+// Arbitrary precision integer, only the operations needed by the VM
+//
+// Sign and magnitude, the magnitude is stored as base 2^32 digits, least significant first,
+// without leading zero digits. Zero has no digits and is never negative.
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    digits: Vec<u32>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ParseBigIntError;
+
+impl fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid digit found in string")
+    }
+}
+
+impl std::error::Error for ParseBigIntError {}
+
+fn trim(digits: &mut Vec<u32>) {
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+}
+
+fn cmp_digits(lhs: &[u32], rhs: &[u32]) -> Ordering {
+    lhs.len()
+        .cmp(&rhs.len())
+        .then_with(|| lhs.iter().rev().cmp(rhs.iter().rev()))
+}
+
+fn add_digits(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(lhs.len().max(rhs.len()) + 1);
+    let mut carry = 0_u64;
+    for i in 0..lhs.len().max(rhs.len()) {
+        let sum = *lhs.get(i).unwrap_or(&0) as u64 + *rhs.get(i).unwrap_or(&0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+    result
+}
+
+// lhs - rhs, lhs must not be smaller than rhs
+fn sub_digits(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(lhs.len());
+    let mut borrow = 0_i64;
+    for (i, &digit) in lhs.iter().enumerate() {
+        let mut diff = digit as i64 - *rhs.get(i).unwrap_or(&0) as i64 - borrow;
+        borrow = if diff < 0 { 1 } else { 0 };
+        diff += borrow << 32;
+        result.push(diff as u32);
+    }
+    trim(&mut result);
+    result
+}
+
+fn mul_digits(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut result = vec![0_u32; lhs.len() + rhs.len()];
+    for (i, &l) in lhs.iter().enumerate() {
+        let mut carry = 0_u64;
+        for (j, &r) in rhs.iter().enumerate() {
+            let cell = result[i + j] as u64 + l as u64 * r as u64 + carry;
+            result[i + j] = cell as u32;
+            carry = cell >> 32;
+        }
+        result[i + rhs.len()] = carry as u32;
+    }
+    trim(&mut result);
+    result
+}
+
+// Divide in place by a single digit, returns the remainder
+fn div_rem_digit(digits: &mut Vec<u32>, divisor: u32) -> u32 {
+    let mut rem = 0_u64;
+    for digit in digits.iter_mut().rev() {
+        let cur = (rem << 32) | *digit as u64;
+        *digit = (cur / divisor as u64) as u32;
+        rem = cur % divisor as u64;
+    }
+    trim(digits);
+    rem as u32
+}
+
+impl BigInt {
+    fn from_parts(negative: bool, mut digits: Vec<u32>) -> Self {
+        trim(&mut digits);
+        let negative = negative && !digits.is_empty();
+        Self { negative, digits }
+    }
+
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    // None if the value does not fit
+    pub fn to_i64(&self) -> Option<i64> {
+        if self.digits.len() > 2 {
+            return None;
+        }
+        let mag = self
+            .digits
+            .iter()
+            .rev()
+            .fold(0_u64, |acc, &digit| (acc << 32) | digit as u64);
+        if self.negative {
+            if mag <= i64::MAX as u64 + 1 {
+                Some((mag as i64).wrapping_neg())
+            } else {
+                None
+            }
+        } else if mag <= i64::MAX as u64 {
+            Some(mag as i64)
+        } else {
+            None
+        }
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(val: i64) -> Self {
+        let mag = val.unsigned_abs();
+        Self::from_parts(val < 0, vec![mag as u32, (mag >> 32) as u32])
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_digits(&self.digits, &other.digits),
+            (true, true) => cmp_digits(&other.digits, &self.digits),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.digits.clone())
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &BigInt) -> BigInt {
+        if self.negative == rhs.negative {
+            return BigInt::from_parts(self.negative, add_digits(&self.digits, &rhs.digits));
+        }
+        // different signs, subtract the smaller magnitude from the larger one
+        match cmp_digits(&self.digits, &rhs.digits) {
+            Ordering::Less => {
+                BigInt::from_parts(rhs.negative, sub_digits(&rhs.digits, &self.digits))
+            }
+            _ => BigInt::from_parts(self.negative, sub_digits(&self.digits, &rhs.digits)),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self + &-rhs
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_parts(
+            self.negative != rhs.negative,
+            mul_digits(&self.digits, &rhs.digits),
+        )
+    }
+}
+
+// Decimal
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        // chunks of 9 decimal digits, least significant first
+        let mut digits = self.digits.clone();
+        let mut chunks = Vec::new();
+        while !digits.is_empty() {
+            chunks.push(div_rem_digit(&mut digits, 1_000_000_000));
+        }
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", chunks.pop().unwrap())?;
+        for chunk in chunks.iter().rev() {
+            write!(f, "{:09}", chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if s.is_empty() {
+            return Err(ParseBigIntError);
+        }
+        let mut digits = Vec::new();
+        for c in s.chars() {
+            let digit = c.to_digit(10).ok_or(ParseBigIntError)?;
+            digits = mul_digits(&digits, &[10]);
+            digits = add_digits(&digits, &[digit]);
+        }
+        Ok(Self::from_parts(negative, digits))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn big(s: &str) -> BigInt {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_bigint() {
+        for &val in &[0, 1, -1, 42, -4_294_967_296, i64::MAX, i64::MIN] {
+            let b = BigInt::from(val);
+            assert_eq!(b.to_i64(), Some(val));
+            assert_eq!(b.to_string(), val.to_string());
+            assert_eq!(big(&val.to_string()), b);
+        }
+        assert_eq!(big("-0"), BigInt::zero());
+        assert_eq!(big("9223372036854775808").to_i64(), None);
+        assert_eq!("x1".parse::<BigInt>(), Err(ParseBigIntError));
+        assert_eq!("-".parse::<BigInt>(), Err(ParseBigIntError));
+
+        let a = big("123456789012345678901234567890");
+        let b = big("-987654321098765432109876543210");
+        assert_eq!(
+            (&a * &b).to_string(),
+            "-121932631137021795226185032733622923332237463801111263526900"
+        );
+        assert_eq!((&a + &b).to_string(), "-864197532086419753208641975320");
+        assert_eq!((&a - &b).to_string(), "1111111110111111111011111111100");
+        assert_eq!(&(&a - &a) + &BigInt::zero(), BigInt::zero());
+        assert!(b < a && a > BigInt::zero() && b < big("-1"));
+        assert!(big("-2") < big("-1"));
+    }
+}
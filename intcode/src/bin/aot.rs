@@ -0,0 +1,28 @@
+// Translate a program ahead of time into Rust code, see src/aot.rs
+//
+// Usage: aot <input.txt> [output.rs]
+//
+// Without output.rs the code is printed. The fixtures of the tests are generated with
+//
+//   cargo run --bin aot -- ../day09/src/input.txt fixtures/day09.rs
+
+use intcode::{parse_program, translate};
+
+fn fail(msg: String) -> ! {
+    eprintln!("{}", msg);
+    std::process::exit(1);
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.is_empty() || args.len() > 2 {
+        fail("Usage: aot <input.txt> [output.rs]".to_string());
+    }
+    let input_str = std::fs::read_to_string(&args[0]).unwrap_or_else(|err| fail(err.to_string()));
+    let mem = parse_program(&input_str).unwrap_or_else(|err| fail(err.to_string()));
+    let code = translate(&mem);
+    match args.get(1) {
+        Some(path) => std::fs::write(path, code).unwrap_or_else(|err| fail(err.to_string())),
+        None => print!("{}", code),
+    }
+}
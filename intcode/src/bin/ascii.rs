@@ -0,0 +1,28 @@
+// Run a program in text mode, the lines of the script are entered before reading stdin
+//
+// Usage: ascii <input.txt> [script.txt]
+
+use intcode::{parse_program, Program};
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let path = match args.next() {
+        Some(path) => path,
+        None => {
+            eprintln!("Usage: ascii <input.txt> [script.txt]");
+            std::process::exit(1);
+        }
+    };
+    let input_str = std::fs::read_to_string(path).unwrap();
+    let mut program = Program::new(parse_program(&input_str).unwrap());
+    let script: Box<dyn Read> = match args.next() {
+        Some(script) => Box::new(File::open(script).unwrap()),
+        None => Box::new(io::empty()),
+    };
+    let reader = BufReader::new(script.chain(io::stdin()));
+    if let Err(err) = program.run_ascii(reader, io::stdout()) {
+        println!("fault: {}", err);
+    }
+}
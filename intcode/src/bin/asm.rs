@@ -0,0 +1,23 @@
+// Assemble a source file into the comma separated program format
+//
+// Usage: asm <source.asm>
+
+use intcode::{assemble, to_program_string};
+
+fn main() {
+    let path = match std::env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            eprintln!("Usage: asm <source.asm>");
+            std::process::exit(1);
+        }
+    };
+    let src = std::fs::read_to_string(path).unwrap();
+    match assemble(&src) {
+        Ok(mem) => println!("{}", to_program_string(&mem)),
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    }
+}
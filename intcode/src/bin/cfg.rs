@@ -0,0 +1,30 @@
+// Print the control flow graph of a program image in Graphviz DOT format
+//
+// Usage: cfg <input.txt>
+//
+// A summary of indirect jumps and writes into code is printed to stderr.
+
+use intcode::{analyze, parse_program};
+
+fn main() {
+    let path = match std::env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            eprintln!("Usage: cfg <input.txt>");
+            std::process::exit(1);
+        }
+    };
+    let input_str = std::fs::read_to_string(path).unwrap();
+    let mem = parse_program(&input_str).unwrap();
+    let cfg = analyze(&mem);
+    print!("{}", cfg.to_dot());
+
+    eprintln!("blocks: {}", cfg.blocks.len());
+    eprintln!("indirect jumps: {:?}", cfg.indirect_jumps);
+    for write in &cfg.code_writes {
+        eprintln!(
+            "{:04} writes into the instruction at {:04} (addr {})",
+            write.addr, write.instr, write.target
+        );
+    }
+}
@@ -0,0 +1,33 @@
+// Interactive debugger, see src/debugger.rs for the commands
+//
+// Usage: debugger <input.txt>
+
+use intcode::{parse_program, Debugger, Program};
+use std::io::{self, BufRead, Write};
+
+fn main() -> io::Result<()> {
+    let path = match std::env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            eprintln!("Usage: debugger <input.txt>");
+            std::process::exit(1);
+        }
+    };
+    let input_str = std::fs::read_to_string(path)?;
+    let mem = parse_program(&input_str).unwrap();
+    let mut debugger = Debugger::new(Program::new(mem));
+
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    write!(out, "(idb) ")?;
+    out.flush()?;
+    for line in stdin.lock().lines() {
+        if !debugger.command(&line?, &mut out)? {
+            break;
+        }
+        write!(out, "(idb) ")?;
+        out.flush()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,18 @@
+// Decompile a program image to pseudo Rust
+//
+// Usage: decompile <input.txt>
+
+use intcode::{decompile, parse_program};
+
+fn main() {
+    let path = match std::env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            eprintln!("Usage: decompile <input.txt>");
+            std::process::exit(1);
+        }
+    };
+    let input_str = std::fs::read_to_string(path).unwrap();
+    let mem = parse_program(&input_str).unwrap();
+    print!("{}", decompile(&mem));
+}
@@ -0,0 +1,24 @@
+// Disassemble a program image
+//
+// Usage: disasm <input.txt> [--labels]
+
+use intcode::{disassemble, disassemble_with_labels, parse_program};
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let path = match args.iter().find(|arg| !arg.starts_with("--")) {
+        Some(path) => path,
+        None => {
+            eprintln!("Usage: disasm <input.txt> [--labels]");
+            std::process::exit(1);
+        }
+    };
+    let input_str = std::fs::read_to_string(path).unwrap();
+    let mem = parse_program(&input_str).unwrap();
+    let listing = if args.iter().any(|arg| arg == "--labels") {
+        disassemble_with_labels(&mem)
+    } else {
+        disassemble(&mem)
+    };
+    print!("{}", listing);
+}
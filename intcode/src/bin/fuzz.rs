@@ -0,0 +1,36 @@
+// Fuzz the VM with random programs
+//
+// Usage: fuzz [seed] [cases] [budget]
+//
+// Prints every finding with the program image and inputs to reproduce it, exits with 1 if
+// there are findings.
+
+use intcode::fuzz;
+
+fn arg(n: usize, default: u64) -> u64 {
+    std::env::args()
+        .nth(n)
+        .map(|arg| arg.parse().expect("Arguments must be numbers"))
+        .unwrap_or(default)
+}
+
+fn main() {
+    let seed = arg(1, 2019);
+    let cases = arg(2, 100_000) as usize;
+    let budget = arg(3, 1000) as usize;
+    // findings are printed below, not by the default hook
+    std::panic::set_hook(Box::new(|_| ()));
+    let findings = fuzz(seed, cases, budget);
+    for finding in &findings {
+        println!("{}", finding);
+    }
+    println!(
+        "seed {}: {} cases, {} findings",
+        seed,
+        cases,
+        findings.len()
+    );
+    if !findings.is_empty() {
+        std::process::exit(1);
+    }
+}
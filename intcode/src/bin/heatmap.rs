@@ -0,0 +1,115 @@
+// Run a program and show which cells were read, written and executed
+//
+// Usage: heatmap <input.txt> [input]... [--width <n>] [--color] [--png <out.png>] [--scale <n>]
+//                [--diff <n>] [--patch <patch>]
+//
+// Prints the regions of code and data, the cells which differ from the image (at most n,
+// default 20), the most written cells and the heatmap.
+
+use intcode::{diff, parse_program, AccessMap, Kind, Patch, Program};
+use std::collections::VecDeque;
+
+fn usage() -> ! {
+    eprintln!(
+        "Usage: heatmap <input.txt> [input]... [--width <n>] [--color] [--png <out.png>] \
+         [--scale <n>] [--diff <n>] [--patch <patch>]"
+    );
+    std::process::exit(1);
+}
+
+fn num(arg: Option<String>) -> usize {
+    arg.and_then(|n| n.parse().ok())
+        .filter(|&n| n > 0)
+        .unwrap_or_else(|| usage())
+}
+
+fn main() {
+    let mut path = None;
+    let mut inputs = VecDeque::new();
+    let mut width = 64;
+    let mut color = false;
+    let mut png_path = None;
+    let mut scale = 4;
+    let mut max_diff = 20;
+    let mut patch = Patch::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--width" => width = num(args.next()),
+            "--color" => color = true,
+            "--png" => png_path = Some(args.next().unwrap_or_else(|| usage())),
+            "--scale" => scale = num(args.next()),
+            "--diff" => max_diff = num(args.next()),
+            "--patch" => {
+                patch =
+                    Patch::from_arg(&args.next().unwrap_or_else(|| usage())).unwrap_or_else(|err| {
+                        eprintln!("{}", err);
+                        std::process::exit(1);
+                    })
+            }
+            _ if path.is_none() => path = Some(arg),
+            _ => inputs.push_back(arg.parse().unwrap_or_else(|_| usage())),
+        }
+    }
+    let input_str = std::fs::read_to_string(path.unwrap_or_else(|| usage())).unwrap();
+    let mut image = parse_program(&input_str).unwrap();
+    if let Err(err) = patch.apply(&mut image) {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+    let mut program = Program::new(image.clone());
+    let mut output = Vec::new();
+    let mut map = AccessMap::new();
+    let result = program.run_traced(&mut inputs, &mut output, &mut map);
+
+    println!(
+        "{} instructions, {} outputs",
+        program.instruction_count(),
+        output.len()
+    );
+    if let Err(err) = result {
+        println!("fault: {}", err);
+    }
+
+    println!("regions:");
+    for region in map.regions() {
+        let kind = match region.kind {
+            Kind::Code => "code",
+            Kind::Written => "written",
+            Kind::Read => "read",
+            Kind::Untouched => continue,
+        };
+        println!("  {:04}-{:04} {}", region.start, region.end - 1, kind);
+    }
+
+    let changes = diff(&image, program.mem());
+    println!("changed cells: {}", changes.len());
+    for (addr, old, new) in changes.iter().take(max_diff) {
+        println!("  {:04}: {} -> {}", addr, old, new);
+    }
+    if changes.len() > max_diff {
+        println!("  ...");
+    }
+
+    let mut written = map
+        .cells()
+        .filter(|(_, access)| access.writes > 0)
+        .collect::<Vec<_>>();
+    written.sort_by_key(|(addr, access)| (std::cmp::Reverse(access.writes), *addr));
+    println!("most written cells:");
+    for (addr, access) in written.iter().take(10) {
+        println!(
+            "  {:04}: {} writes, now {}",
+            addr,
+            access.writes,
+            program.mem().get(*addr)
+        );
+    }
+
+    let end = map.end().max(image.len());
+    println!("heatmap (x code, w written, r read):");
+    print!("{}", map.to_text(end, width, color));
+    if let Some(png_path) = png_path {
+        std::fs::write(png_path, map.to_png(end, width, scale)).unwrap();
+    }
+}
@@ -0,0 +1,26 @@
+// Apply a patch to a program and print the patched program
+//
+// Usage: patch <input.txt> <patch>
+//
+// The patch is given as pokes like "1=12,2=2" or "0=1->2", or as @file, see src/patch.rs.
+
+use intcode::{parse_program, to_program_string, Patch};
+
+fn fail(msg: String) -> ! {
+    eprintln!("{}", msg);
+    std::process::exit(1);
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.len() != 2 {
+        fail("Usage: patch <input.txt> <patch>".to_string());
+    }
+    let input_str = std::fs::read_to_string(&args[0]).unwrap();
+    let mut image = parse_program(&input_str).unwrap();
+    let patch = Patch::from_arg(&args[1]).unwrap_or_else(|err| fail(err.to_string()));
+    if let Err(err) = patch.apply(&mut image) {
+        fail(err.to_string());
+    }
+    println!("{}", to_program_string(&image));
+}
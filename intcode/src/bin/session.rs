@@ -0,0 +1,42 @@
+// Record a run with inputs from stdin to a session file, or replay a recorded session
+//
+// Usage: session record <input.txt> <session.txt>
+//        session replay <input.txt> <session.txt>
+
+use intcode::{parse_program, Program, Session, Stdio};
+
+fn usage() -> ! {
+    eprintln!("Usage: session record|replay <input.txt> <session.txt>");
+    std::process::exit(1);
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.len() != 3 {
+        usage();
+    }
+    let input_str = std::fs::read_to_string(&args[1]).unwrap();
+    let mut program = Program::new(parse_program(&input_str).unwrap());
+    match args[0].as_str() {
+        "record" => {
+            let mut session = Session::new();
+            let result = program.run_recorded(&mut Stdio, &mut Stdio, &mut session);
+            session.save(&args[2]).unwrap();
+            if let Err(err) = result {
+                println!("fault: {}", err);
+            }
+            println!("recorded {} records", session.records.len());
+        }
+        "replay" => {
+            let session = Session::load(&args[2]).unwrap();
+            match program.replay(&session) {
+                Ok(()) => println!("replayed {} records", session.records.len()),
+                Err(divergence) => {
+                    println!("{}", divergence);
+                    std::process::exit(2);
+                }
+            }
+        }
+        _ => usage(),
+    }
+}
@@ -0,0 +1,70 @@
+// Run a program with the profiler and optionally write a trace of all executed instructions
+//
+// Usage: trace <input.txt> [input]... [--trace <trace.txt>] [--top <n>] [--patch <patch>]
+
+use intcode::{parse_program, Patch, Profiler, Program, TraceWriter};
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::BufWriter;
+
+fn usage() -> ! {
+    eprintln!(
+        "Usage: trace <input.txt> [input]... [--trace <trace.txt>] [--top <n>] [--patch <patch>]"
+    );
+    std::process::exit(1);
+}
+
+fn main() {
+    let mut path = None;
+    let mut inputs = VecDeque::new();
+    let mut trace_path = None;
+    let mut top = 10;
+    let mut patch = Patch::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--trace" => trace_path = Some(args.next().unwrap_or_else(|| usage())),
+            "--patch" => {
+                patch =
+                    Patch::from_arg(&args.next().unwrap_or_else(|| usage())).unwrap_or_else(|err| {
+                        eprintln!("{}", err);
+                        std::process::exit(1);
+                    })
+            }
+            "--top" => {
+                top = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .unwrap_or_else(|| usage())
+            }
+            _ if path.is_none() => path = Some(arg),
+            _ => inputs.push_back(arg.parse().unwrap_or_else(|_| usage())),
+        }
+    }
+    let input_str = std::fs::read_to_string(path.unwrap_or_else(|| usage())).unwrap();
+    let mut program = Program::with_patch(parse_program(&input_str).unwrap(), &patch)
+        .unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        });
+    let mut output = Vec::new();
+    let mut profiler = Profiler::new(top);
+
+    let result = match trace_path {
+        Some(trace_path) => {
+            let file = BufWriter::new(File::create(trace_path).unwrap());
+            let mut tracer = (TraceWriter::new(file), profiler);
+            let result = program.run_traced(&mut inputs, &mut output, &mut tracer);
+            tracer.0.finish().unwrap();
+            profiler = tracer.1;
+            result
+        }
+        None => program.run_traced(&mut inputs, &mut output, &mut profiler),
+    };
+
+    println!("outputs: {:?}", output);
+    if let Err(err) = result {
+        println!("fault: {}", err);
+    }
+    print!("{}", profiler);
+}
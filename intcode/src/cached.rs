@@ -0,0 +1,312 @@
+use crate::error::VmError;
+use crate::io::{Input, Output};
+use crate::memory::Memory;
+use crate::op::{parse_opcode, Op, ParamMode};
+use crate::program::{Program, State};
+use std::collections::VecDeque;
+
+// Execution engine with a decode cache
+//
+// Every instruction of the initial image is decoded once, op, param modes and raw params
+// are kept in the cache. A write into a cached instruction drops it from the cache, so
+// self modifying programs behave the same as with Program::resume.
+//
+// Instructions behind the initial image and instructions which can not be decoded are
+// executed by Program::step, which also reports the faults.
+
+#[derive(Debug, Copy, Clone)]
+struct Decoded {
+    op: Op,
+    modes: [ParamMode; 3],
+    params: [i64; 3],
+}
+
+pub struct CachedProgram {
+    program: Program,
+    cache: Vec<Option<Decoded>>,
+}
+
+fn decode(program: &Program, addr: usize) -> Option<Decoded> {
+    let opcode = program.read(addr as i64).ok()?;
+    let (op, param_mode_0, param_mode_1, param_mode_2) = parse_opcode(opcode, addr).ok()?;
+    let mut params = [0; 3];
+    for (i, param) in params.iter_mut().enumerate().take(op.num_params()) {
+        *param = program.read((addr + 1 + i) as i64).ok()?;
+    }
+    Some(Decoded {
+        op,
+        modes: [param_mode_0, param_mode_1, param_mode_2],
+        params,
+    })
+}
+
+fn param_val(program: &Program, mode: ParamMode, param: i64) -> Result<i64, VmError> {
+    match mode {
+        ParamMode::Addr => program.read(param),
+        ParamMode::Val => Ok(param),
+        ParamMode::Rel => program.read(program.rel_base().saturating_add(param)),
+    }
+}
+
+fn result_addr(program: &Program, mode: ParamMode, param: i64) -> Result<usize, VmError> {
+    match mode {
+        ParamMode::Addr => program.to_addr(param),
+        ParamMode::Rel => program.to_addr(program.rel_base().saturating_add(param)),
+        ParamMode::Val => Err(VmError::WriteToImmediate {
+            ip: program.ip(),
+            opcode: program.opcode(),
+        }),
+    }
+}
+
+fn jump_target(program: &Program, mode: ParamMode, param: i64) -> Result<usize, VmError> {
+    let new_ip = param_val(program, mode, param)?;
+    if new_ip < 0 {
+        return Err(VmError::NegativeAddress {
+            ip: program.ip(),
+            opcode: program.opcode(),
+            addr: new_ip,
+        });
+    }
+    Ok(new_ip as usize)
+}
+
+// Write to mem and drop all cached instructions which contain addr
+fn write(program: &mut Program, cache: &mut [Option<Decoded>], addr: usize, val: i64) {
+    program.mem_mut().set(addr, val);
+    for decoded in cache.iter_mut().take(addr + 1).skip(addr.saturating_sub(3)) {
+        *decoded = None;
+    }
+}
+
+fn step_uncached(
+    program: &mut Program,
+    cache: &mut [Option<Decoded>],
+) -> Result<Option<State>, VmError> {
+    let writes = parse_opcode(program.opcode(), program.ip())
+        .map(|(op, _, _, _)| op.writes())
+        .unwrap_or(false);
+    let state = program.step()?;
+    // the write addr is not known here
+    if writes {
+        cache.iter_mut().for_each(|decoded| *decoded = None);
+    }
+    Ok(state)
+}
+
+fn execute(
+    program: &mut Program,
+    cache: &mut [Option<Decoded>],
+    decoded: Decoded,
+) -> Result<Option<State>, VmError> {
+    let Decoded { op, modes, params } = decoded;
+    let ip = program.ip();
+    if op != Op::Halt {
+        program.check_budgets()?;
+    }
+    match op {
+        Op::Add | Op::Mul | Op::Less | Op::Equals => {
+            let lhs = param_val(program, modes[0], params[0])?;
+            let rhs = param_val(program, modes[1], params[1])?;
+            let addr = result_addr(program, modes[2], params[2])?;
+            let val = match op {
+                Op::Add => lhs.checked_add(rhs),
+                Op::Mul => lhs.checked_mul(rhs),
+                Op::Less => Some((lhs < rhs) as i64),
+                _ => Some((lhs == rhs) as i64),
+            };
+            let val = val.ok_or(VmError::Overflow {
+                ip,
+                opcode: program.opcode(),
+            })?;
+            write(program, cache, addr, val);
+            program.set_ip(ip + 4);
+        }
+        Op::In => {
+            let addr = result_addr(program, modes[0], params[0])?;
+            let val = match program.pop_input() {
+                Some(val) => val,
+                None => return Ok(Some(State::NeedsInput)),
+            };
+            write(program, cache, addr, val);
+            program.set_ip(ip + 2);
+        }
+        Op::Out => {
+            let val = param_val(program, modes[0], params[0])?;
+            program.set_ip(ip + 2);
+            program.count_instruction();
+            return Ok(Some(State::Output(val)));
+        }
+        Op::JumpIfTrue | Op::JumpIfFalse => {
+            let val = param_val(program, modes[0], params[0])?;
+            let new_ip = jump_target(program, modes[1], params[1])?;
+            if (val != 0) == (op == Op::JumpIfTrue) {
+                program.set_ip(new_ip);
+            } else {
+                program.set_ip(ip + 3);
+            }
+        }
+        Op::SetRelBase => {
+            let val = param_val(program, modes[0], params[0])?;
+            program.set_rel_base(program.rel_base().saturating_add(val));
+            program.set_ip(ip + 2);
+        }
+        Op::Halt => return Ok(Some(State::Halted)),
+    }
+    program.count_instruction();
+    Ok(None)
+}
+
+fn step_cached(
+    program: &mut Program,
+    cache: &mut [Option<Decoded>],
+) -> Result<Option<State>, VmError> {
+    let ip = program.ip();
+    let decoded = match cache.get(ip) {
+        Some(Some(decoded)) => Some(*decoded),
+        Some(None) => decode(program, ip).inspect(|&decoded| cache[ip] = Some(decoded)),
+        None => None,
+    };
+    match decoded {
+        Some(decoded) => execute(program, cache, decoded),
+        None => step_uncached(program, cache),
+    }
+}
+
+fn resume_cached(program: &mut Program, cache: &mut [Option<Decoded>]) -> Result<State, VmError> {
+    loop {
+        if let Some(state) = step_cached(program, cache)? {
+            return Ok(state);
+        }
+    }
+}
+
+impl CachedProgram {
+    pub fn new(mem: Vec<i64>) -> Self {
+        Self::from_program(Program::new(mem))
+    }
+
+    pub fn from_program(program: Program) -> Self {
+        let cache = vec![None; program.mem().image().len()];
+        Self { program, cache }
+    }
+
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    // Drops the whole cache, the program could be modified in any way
+    pub fn program_mut(&mut self) -> &mut Program {
+        self.cache.iter_mut().for_each(|decoded| *decoded = None);
+        &mut self.program
+    }
+
+    pub fn into_program(self) -> Program {
+        self.program
+    }
+
+    pub fn mem(&self) -> &Memory {
+        self.program.mem()
+    }
+
+    pub fn push_input(&mut self, val: i64) {
+        self.program.push_input(val);
+    }
+
+    // Same as Program::step
+    pub fn step(&mut self) -> Result<Option<State>, VmError> {
+        step_cached(&mut self.program, &mut self.cache)
+    }
+
+    // Same as Program::resume
+    pub fn resume(&mut self) -> Result<State, VmError> {
+        resume_cached(&mut self.program, &mut self.cache)
+    }
+
+    // Same as Program::run
+    pub fn run<I, O>(&mut self, input: &mut I, output: &mut O) -> Result<(), VmError>
+    where
+        I: Input + ?Sized,
+        O: Output + ?Sized,
+    {
+        let cache = &mut self.cache;
+        self.program
+            .run_with(input, output, |program| resume_cached(program, cache))
+    }
+
+    // Same as Program::run_collect
+    pub fn run_collect(&mut self, inputs: &[i64]) -> Result<Vec<i64>, VmError> {
+        let mut input = inputs.iter().copied().collect::<VecDeque<_>>();
+        let mut output = Vec::new();
+        self.run(&mut input, &mut output)?;
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::program::parse_program;
+
+    #[test]
+    fn test_self_modifying() {
+        // overwrite the opcode at 4 (out [0]) with 104 (out #0)
+        let mem = vec![1101, 103, 1, 4, 4, 0, 99];
+        assert_eq!(CachedProgram::new(mem).run_collect(&[]), Ok(vec![0]));
+
+        // output [13], increment the param of the cached out and loop once
+        let mem = vec![4, 13, 101, 1, 1, 1, 1001, 15, -1, 15, 1005, 15, 0, 99, 7, 2];
+        assert_eq!(CachedProgram::new(mem).run_collect(&[]), Ok(vec![99, 7]));
+    }
+
+    #[test]
+    fn test_same_as_program() {
+        for (input, inputs) in &[
+            (include_str!("../../day02/src/input.txt"), vec![]),
+            (include_str!("../../day05/src/input.txt"), vec![5]),
+            (include_str!("../../day09/src/input.txt"), vec![1]),
+        ] {
+            let mem = parse_program(input).unwrap();
+            let mut cached = CachedProgram::new(mem.clone());
+            let mut program = Program::new(mem);
+            assert_eq!(cached.run_collect(inputs), program.run_collect(inputs));
+            assert_eq!(cached.program().mem(), program.mem());
+            assert_eq!(cached.program().ip(), program.ip());
+            assert_eq!(
+                cached.program().instruction_count(),
+                program.instruction_count()
+            );
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        for mem in &[
+            vec![1, -1, 0, 0],
+            vec![42],
+            vec![1101, 1, 2],
+            vec![11101, 1, 2, 3],
+            vec![1102, i64::MAX, 2, 0, 99],
+        ] {
+            assert_eq!(
+                CachedProgram::new(mem.clone()).run_collect(&[]),
+                Program::new(mem.clone()).run_collect(&[])
+            );
+        }
+    }
+
+    #[test]
+    fn test_budget() {
+        let mut cached = CachedProgram::new(vec![1105, 1, 0]);
+        cached.program_mut().set_instruction_budget(100);
+        assert_eq!(
+            cached.resume(),
+            Err(VmError::BudgetExhausted {
+                ip: 0,
+                opcode: 1105,
+                limit: crate::error::Limit::Instructions
+            })
+        );
+        assert_eq!(cached.program().instruction_count(), 100);
+    }
+}
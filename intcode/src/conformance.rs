@@ -0,0 +1,410 @@
+// Conformance tests of the VM
+//
+// - every op with every param mode
+// - the example programs of the puzzle descriptions of day02, day05 and day09
+// - the real inputs with their known answers, day13 part 2 with a patch
+//
+// Every program is run with Program and CachedProgram, both have to agree. The real
+// inputs are also run with NativeProgram, translated in fixtures.
+
+use crate::cached::CachedProgram;
+use crate::error::VmError;
+use crate::memory::Memory;
+use crate::native::{translated, NativeProgram, Translated};
+use crate::network::permutations;
+use crate::patch::Patch;
+use crate::program::{parse_program, Program, State};
+use std::collections::HashSet;
+
+// Translation of the day input mem is based on, the inputs all have different lengths
+fn translation(mem: &[i64]) -> Option<&'static Translated> {
+    [
+        &translated::day02::TRANSLATED,
+        &translated::day05::TRANSLATED,
+        &translated::day07::TRANSLATED,
+        &translated::day09::TRANSLATED,
+        &translated::day11::TRANSLATED,
+        &translated::day13::TRANSLATED,
+    ]
+    .iter()
+    .copied()
+    .find(|translated| translated.image.len() == mem.len())
+}
+
+// Run to halt with all engines, returns the outputs and the final mem
+fn run(mem: &[i64], inputs: &[i64]) -> (Vec<i64>, Memory) {
+    let mut program = Program::new(mem.to_vec());
+    let outputs = program.run_collect(inputs).unwrap();
+    let mut cached = CachedProgram::new(mem.to_vec());
+    assert_eq!(cached.run_collect(inputs), Ok(outputs.clone()));
+    assert_eq!(cached.mem(), program.mem());
+    assert_eq!(
+        cached.program().instruction_count(),
+        program.instruction_count()
+    );
+    if let Some(translated) = translation(mem) {
+        let mut native = NativeProgram::new(mem.to_vec(), translated);
+        assert_eq!(native.run_collect(inputs), Ok(outputs.clone()));
+        assert_eq!(native.mem(), program.mem());
+        assert_eq!(
+            native.program().instruction_count(),
+            program.instruction_count()
+        );
+    }
+    (outputs, program.mem().clone())
+}
+
+fn outputs(mem: &[i64], inputs: &[i64]) -> Vec<i64> {
+    run(mem, inputs).0
+}
+
+fn input(day: &str) -> Vec<i64> {
+    let input = match day {
+        "day02" => include_str!("../../day02/src/input.txt"),
+        "day05" => include_str!("../../day05/src/input.txt"),
+        "day07" => include_str!("../../day07/src/input.txt"),
+        "day09" => include_str!("../../day09/src/input.txt"),
+        "day11" => include_str!("../../day11/src/input.txt"),
+        "day13" => include_str!("../../day13/src/input.txt"),
+        _ => panic!("No input for {}", day),
+    };
+    parse_program(input).unwrap()
+}
+
+#[test]
+fn test_ops() {
+    // Op::Add and Op::Mul, Addr, Val and Rel params
+    assert_eq!(run(&[1, 5, 6, 0, 99, 2, 3], &[]).1[0], 5);
+    assert_eq!(run(&[2, 5, 6, 0, 99, 2, 3], &[]).1[0], 6);
+    assert_eq!(run(&[1101, 2, 3, 0, 99], &[]).1[0], 5);
+    assert_eq!(run(&[1102, -2, 3, 0, 99], &[]).1[0], -6);
+    assert_eq!(run(&[1001, 5, 10, 0, 99, 7], &[]).1[0], 17);
+    assert_eq!(run(&[109, 7, 22201, 0, 1, 2, 99, 4, 5, 0], &[]).1[9], 9);
+    assert_eq!(run(&[109, 7, 21202, 0, 3, -7, 99, 4], &[]).1[0], 12);
+
+    // Op::In and Op::Out, Addr, Val and Rel params
+    assert_eq!(outputs(&[3, 5, 4, 5, 99, 0], &[42]), vec![42]);
+    assert_eq!(outputs(&[104, -7, 99], &[]), vec![-7]);
+    assert_eq!(outputs(&[109, 7, 203, 0, 204, 0, 99, 0], &[13]), vec![13]);
+
+    // Op::JumpIfTrue and Op::JumpIfFalse, taken and not taken, output 1 if taken
+    for (opcode, val, taken) in &[
+        (1105, 1, true),
+        (1105, 0, false),
+        (1106, 0, true),
+        (1106, 5, false),
+    ] {
+        let mem = [*opcode, *val, 6, 104, 0, 99, 104, 1, 99];
+        assert_eq!(outputs(&mem, &[]), vec![*taken as i64]);
+    }
+    // targets from Addr and Rel params
+    assert_eq!(
+        outputs(&[5, 9, 10, 104, 0, 99, 104, 1, 99, 1, 6], &[]),
+        vec![1]
+    );
+    assert_eq!(
+        outputs(&[109, 11, 2206, 0, 1, 104, 0, 99, 104, 1, 99, 0, 8], &[]),
+        vec![1]
+    );
+
+    // Op::Less and Op::Equals
+    for (opcode, lhs, rhs, result) in &[
+        (1107, 1, 2, 1),
+        (1107, 2, 2, 0),
+        (1107, -3, 2, 1),
+        (1108, 2, 2, 1),
+        (1108, 2, -2, 0),
+    ] {
+        let mem = [*opcode, *lhs, *rhs, 0, 99];
+        assert_eq!(run(&mem, &[]).1[0], *result);
+    }
+
+    // Op::SetRelBase, Val, Addr and Rel params
+    assert_eq!(outputs(&[109, 3, 109, -1, 204, 0, 99], &[]), vec![109]);
+    assert_eq!(outputs(&[9, 5, 204, 0, 99, 4], &[]), vec![99]);
+    assert_eq!(outputs(&[109, 6, 209, 1, 204, -1, 99, 2], &[]), vec![2]);
+
+    // Op::Halt
+    let mut program = Program::new(vec![99, 104, 1]);
+    assert_eq!(program.resume(), Ok(State::Halted));
+    assert_eq!(program.ip(), 0);
+}
+
+#[test]
+fn test_faults() {
+    for (mem, err) in &[
+        (vec![42], VmError::InvalidOpcode { ip: 0, opcode: 42 }),
+        (
+            vec![301, 0, 0, 0],
+            VmError::InvalidMode {
+                ip: 0,
+                opcode: 301,
+                mode: 3,
+            },
+        ),
+        (
+            vec![11101, 1, 1, 0],
+            VmError::WriteToImmediate {
+                ip: 0,
+                opcode: 11101,
+            },
+        ),
+        (
+            vec![1105, 1, -1],
+            VmError::NegativeAddress {
+                ip: 0,
+                opcode: 1105,
+                addr: -1,
+            },
+        ),
+        (vec![3, 0, 99], VmError::UnexpectedEof { ip: 0, opcode: 3 }),
+        (
+            vec![1102, i64::MAX, 2, 0, 99],
+            VmError::Overflow {
+                ip: 0,
+                opcode: 1102,
+            },
+        ),
+    ] {
+        assert_eq!(Program::new(mem.clone()).run_collect(&[]), Err(*err));
+        assert_eq!(CachedProgram::new(mem.clone()).run_collect(&[]), Err(*err));
+    }
+}
+
+#[test]
+fn test_day02_examples() {
+    for (mem, result) in &[
+        (
+            vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50],
+            vec![3500, 9, 10, 70, 2, 3, 11, 0, 99, 30, 40, 50],
+        ),
+        (vec![1, 0, 0, 0, 99], vec![2, 0, 0, 0, 99]),
+        (vec![2, 3, 0, 3, 99], vec![2, 3, 0, 6, 99]),
+        (vec![2, 4, 4, 5, 99, 0], vec![2, 4, 4, 5, 99, 9801]),
+        (
+            vec![1, 1, 1, 4, 99, 5, 6, 0, 99],
+            vec![30, 1, 1, 4, 2, 5, 6, 0, 99],
+        ),
+    ] {
+        assert_eq!(run(mem, &[]).1, Memory::new(result.clone()));
+    }
+}
+
+#[test]
+fn test_day05_examples() {
+    assert_eq!(outputs(&[3, 0, 4, 0, 99], &[-5]), vec![-5]);
+    assert_eq!(
+        run(&[1002, 4, 3, 4, 33], &[]).1,
+        Memory::new(vec![1002, 4, 3, 4, 99])
+    );
+    assert_eq!(
+        run(&[1101, 100, -1, 4, 0], &[]).1,
+        Memory::new(vec![1101, 100, -1, 4, 99])
+    );
+
+    // equal to 8 and less than 8, with Addr and Val params
+    let equal_addr = [3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8];
+    let less_addr = [3, 9, 7, 9, 10, 9, 4, 9, 99, -1, 8];
+    let equal_val = [3, 3, 1108, -1, 8, 3, 4, 3, 99];
+    let less_val = [3, 3, 1107, -1, 8, 3, 4, 3, 99];
+    for input in 6..=10 {
+        for mem in &[&equal_addr[..], &equal_val[..]] {
+            assert_eq!(outputs(mem, &[input]), vec![(input == 8) as i64]);
+        }
+        for mem in &[&less_addr[..], &less_val[..]] {
+            assert_eq!(outputs(mem, &[input]), vec![(input < 8) as i64]);
+        }
+    }
+
+    // jumps, output 0 if the input is 0, else 1
+    let jump_addr = [3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9];
+    let jump_val = [3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1];
+    for input in -1..=2 {
+        assert_eq!(outputs(&jump_addr, &[input]), vec![(input != 0) as i64]);
+        assert_eq!(outputs(&jump_val, &[input]), vec![(input != 0) as i64]);
+    }
+
+    // 999 below 8, 1000 for 8, 1001 above 8
+    let compare = [
+        3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36, 98, 0, 0,
+        1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000, 1, 20, 4, 20,
+        1105, 1, 46, 98, 99,
+    ];
+    assert_eq!(outputs(&compare, &[7]), vec![999]);
+    assert_eq!(outputs(&compare, &[8]), vec![1000]);
+    assert_eq!(outputs(&compare, &[9]), vec![1001]);
+}
+
+#[test]
+fn test_day09_examples() {
+    let quine = [
+        109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+    ];
+    assert_eq!(outputs(&quine, &[]), quine.to_vec());
+
+    let output = outputs(&[1102, 34_915_192, 34_915_192, 7, 4, 7, 99, 0], &[]);
+    assert_eq!(output.len(), 1);
+    assert_eq!(output[0].to_string().len(), 16);
+
+    assert_eq!(
+        outputs(&[104, 1_125_899_906_842_624, 99], &[]),
+        vec![1_125_899_906_842_624]
+    );
+}
+
+#[test]
+fn test_day02() {
+    let mut mem = input("day02");
+    mem[1] = 12;
+    mem[2] = 2;
+    assert_eq!(run(&mem, &[]).1[0], 3_716_250);
+    // part 2 answer 6472
+    mem[1] = 64;
+    mem[2] = 72;
+    assert_eq!(run(&mem, &[]).1[0], 19_690_720);
+}
+
+#[test]
+fn test_day05() {
+    let mem = input("day05");
+    let diagnostics = outputs(&mem, &[1]);
+    let (code, checks) = diagnostics.split_last().unwrap();
+    assert!(checks.iter().all(|&check| check == 0));
+    assert_eq!(*code, 7_566_643);
+    assert_eq!(outputs(&mem, &[5]), vec![9_265_694]);
+}
+
+#[test]
+fn test_day07() {
+    let mem = input("day07");
+
+    // chain of amplifiers
+    let part1 = permutations(&[0, 1, 2, 3, 4])
+        .iter()
+        .map(|phases| {
+            phases
+                .iter()
+                .fold(0, |signal, &phase| outputs(&mem, &[phase, signal])[0])
+        })
+        .max();
+    assert_eq!(part1, Some(914_828));
+
+    // feedback loop, with the cached and the native engine
+    let part2 = permutations(&[5, 6, 7, 8, 9])
+        .iter()
+        .map(|phases| {
+            let mut amplifiers = phases
+                .iter()
+                .map(|&phase| {
+                    let mut cached = CachedProgram::new(mem.clone());
+                    let mut native = NativeProgram::new(mem.clone(), translation(&mem).unwrap());
+                    cached.push_input(phase);
+                    native.push_input(phase);
+                    (cached, native)
+                })
+                .collect::<Vec<_>>();
+            let mut signal = 0;
+            loop {
+                for (cached, native) in &mut amplifiers {
+                    cached.push_input(signal);
+                    native.push_input(signal);
+                    let state = cached.resume();
+                    assert_eq!(native.resume(), state);
+                    match state {
+                        Ok(State::Output(val)) => signal = val,
+                        Ok(State::Halted) => return signal,
+                        state => panic!("Unexpected state {:?}", state),
+                    }
+                }
+            }
+        })
+        .max();
+    assert_eq!(part2, Some(17_956_613));
+}
+
+#[test]
+fn test_day09() {
+    let mem = input("day09");
+    assert_eq!(outputs(&mem, &[1]), vec![2_350_741_403]);
+    assert_eq!(outputs(&mem, &[2]), vec![53_088]);
+}
+
+#[test]
+fn test_day11() {
+    // not solved yet, only check that the program runs
+    // the robot gets black panels until it halts
+    let mem = input("day11");
+    let mut program = Program::new(mem.clone());
+    let mut cached = CachedProgram::new(mem.clone());
+    let mut native = NativeProgram::new(mem.clone(), translation(&mem).unwrap());
+    let mut painted = 0;
+    loop {
+        let state = program.resume();
+        assert_eq!(cached.resume(), state);
+        assert_eq!(native.resume(), state);
+        match state {
+            Ok(State::NeedsInput) => {
+                program.push_input(0);
+                cached.push_input(0);
+                native.push_input(0);
+            }
+            Ok(State::Output(_)) => painted += 1,
+            Ok(State::Halted) => break,
+            Err(err) => panic!("{}", err),
+        }
+    }
+    assert!(painted > 0 && painted % 2 == 0);
+    assert_eq!(cached.mem(), program.mem());
+    assert_eq!(native.mem(), program.mem());
+}
+
+#[test]
+fn test_day13() {
+    // the arcade draws the screen as (x, y, tile) triples without input
+    let mem = input("day13");
+    let screen = outputs(&mem, &[]);
+    let blocks = screen
+        .chunks(3)
+        .filter(|tile| tile[2] == 2)
+        .map(|tile| (tile[0], tile[1]))
+        .collect::<HashSet<_>>();
+    assert_eq!(blocks.len(), 233);
+
+    // free play patches the first opcode
+    let patch = "0=1->2".parse::<Patch>().unwrap();
+    let mut program = Program::with_patch(mem.clone(), &patch).unwrap();
+    let mut patched = mem.clone();
+    patch.apply(&mut patched).unwrap();
+    let mut native = NativeProgram::new(patched.clone(), translation(&mem).unwrap());
+    let mut cached = CachedProgram::new(patched);
+    let (mut tile, mut ball, mut paddle, mut score) = (Vec::new(), 0_i64, 0_i64, 0);
+    loop {
+        let state = program.resume();
+        assert_eq!(cached.resume(), state);
+        assert_eq!(native.resume(), state);
+        match state {
+            Ok(State::NeedsInput) => {
+                program.push_input((ball - paddle).signum());
+                cached.push_input((ball - paddle).signum());
+                native.push_input((ball - paddle).signum());
+            }
+            Ok(State::Output(val)) => {
+                tile.push(val);
+                if let [x, y, id] = tile[..] {
+                    match (x, y, id) {
+                        (-1, 0, val) => score = val,
+                        (x, _, 4) => ball = x,
+                        (x, _, 3) => paddle = x,
+                        _ => (),
+                    }
+                    tile.clear();
+                }
+            }
+            Ok(State::Halted) => break,
+            Err(err) => panic!("{}", err),
+        }
+    }
+    assert_eq!(score, 11_991);
+    assert_eq!(cached.mem(), program.mem());
+    assert_eq!(native.mem(), program.mem());
+}
@@ -0,0 +1,350 @@
+use crate::disasm::{decode_mem, Instr};
+use crate::error::VmError;
+use crate::op::ParamMode;
+use crate::program::{Program, State};
+use crate::snapshot::Snapshot;
+use std::collections::{BTreeSet, VecDeque};
+use std::io::{self, Write};
+
+// Debugger around a program
+//
+// Commands:
+//
+// - s, step [n]             execute n instructions (default 1)
+// - c, continue [n]         run until a breakpoint, watchpoint, missing input, halt or fault,
+//                           at most n instructions (default CONTINUE_LIMIT)
+// - b, break <addr>         set a breakpoint on ip
+// - d, delete <addr>        delete a breakpoint
+// - w, watch <addr>         stop when an instruction writes to addr
+// - uw, unwatch <addr>      delete a watchpoint
+// - x <addr> [n]            print n cells of mem starting at addr (default 1, at most X_LIMIT)
+// - set <addr> <val>        modify mem
+// - ip [val]                print or set ip
+// - rb [val]                print or set rel base
+// - i, input <val>...       add inputs
+// - r, regs                 print ip, rel base and the current instruction
+// - h, history [n]          print the last n executed instructions (default all recorded)
+// - save <file>             save a snapshot of the program
+// - load <file>             restore the program from a saved snapshot
+// - q, quit
+
+const HISTORY_LEN: usize = 32;
+// a program which loops forever stops after this many instructions on continue
+const CONTINUE_LIMIT: u64 = 10_000_000;
+const X_LIMIT: usize = 1024;
+
+enum Stop {
+    Breakpoint(usize),
+    Limit(u64),
+    Watchpoint { addr: usize, old: i64, new: i64 },
+    NeedsInput,
+    Halted,
+    Fault(VmError),
+}
+
+pub struct Debugger {
+    program: Program,
+    breakpoints: BTreeSet<usize>,
+    watchpoints: BTreeSet<usize>,
+    history: VecDeque<(usize, Instr)>,
+}
+
+fn parse_num<T: std::str::FromStr>(arg: Option<&&str>) -> Option<T> {
+    arg.and_then(|arg| arg.parse().ok())
+}
+
+impl Debugger {
+    pub fn new(program: Program) -> Self {
+        Self {
+            program,
+            breakpoints: BTreeSet::new(),
+            watchpoints: BTreeSet::new(),
+            history: VecDeque::with_capacity(HISTORY_LEN),
+        }
+    }
+
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    // Addr the instruction writes to, if it writes
+    fn result_addr(&self, instr: &Instr) -> Option<usize> {
+        if !instr.op.writes() {
+            return None;
+        }
+        let param = instr.params.last()?;
+        let addr = match param.mode {
+            ParamMode::Addr => param.val,
+            ParamMode::Rel => self.program.rel_base().checked_add(param.val)?,
+            ParamMode::Val => return None,
+        };
+        if addr < 0 {
+            None
+        } else {
+            Some(addr as usize)
+        }
+    }
+
+    fn step_one(&mut self, out: &mut dyn Write) -> io::Result<Option<Stop>> {
+        let ip = self.program.ip();
+        let instr = decode_mem(self.program.mem(), ip);
+        let watch = instr
+            .as_ref()
+            .and_then(|instr| self.result_addr(instr))
+            .filter(|addr| self.watchpoints.contains(addr))
+            .map(|addr| (addr, self.program.mem().get(addr)));
+
+        let state = match self.program.step() {
+            Ok(state) => state,
+            Err(err) => return Ok(Some(Stop::Fault(err))),
+        };
+        match state {
+            Some(State::NeedsInput) => return Ok(Some(Stop::NeedsInput)),
+            Some(State::Halted) => return Ok(Some(Stop::Halted)),
+            Some(State::Output(val)) => writeln!(out, "output: {}", val)?,
+            None => (),
+        }
+        if let Some(instr) = instr {
+            if self.history.len() == HISTORY_LEN {
+                self.history.pop_front();
+            }
+            self.history.push_back((ip, instr));
+        }
+        if let Some((addr, old)) = watch {
+            let new = self.program.mem().get(addr);
+            return Ok(Some(Stop::Watchpoint { addr, old, new }));
+        }
+        Ok(None)
+    }
+
+    fn print_stop(&self, stop: Option<Stop>, out: &mut dyn Write) -> io::Result<()> {
+        match stop {
+            Some(Stop::Breakpoint(addr)) => writeln!(out, "breakpoint at {}", addr)?,
+            Some(Stop::Limit(count)) => writeln!(out, "stopped after {} instructions", count)?,
+            Some(Stop::Watchpoint { addr, old, new }) => {
+                writeln!(out, "watchpoint [{}]: {} -> {}", addr, old, new)?
+            }
+            Some(Stop::NeedsInput) => writeln!(out, "needs input")?,
+            Some(Stop::Halted) => writeln!(out, "halted")?,
+            Some(Stop::Fault(err)) => writeln!(out, "fault: {}", err)?,
+            None => (),
+        }
+        self.print_regs(out)
+    }
+
+    fn print_regs(&self, out: &mut dyn Write) -> io::Result<()> {
+        let ip = self.program.ip();
+        match decode_mem(self.program.mem(), ip) {
+            Some(instr) => writeln!(
+                out,
+                "ip={} rb={} | {:04}: {}",
+                ip,
+                self.program.rel_base(),
+                ip,
+                instr
+            ),
+            None => writeln!(
+                out,
+                "ip={} rb={} | {:04}: DATA {}",
+                ip,
+                self.program.rel_base(),
+                ip,
+                self.program.mem().get(ip)
+            ),
+        }
+    }
+
+    fn step(&mut self, count: usize, out: &mut dyn Write) -> io::Result<()> {
+        let mut stop = None;
+        for _ in 0..count {
+            stop = self.step_one(out)?;
+            if stop.is_some() {
+                break;
+            }
+        }
+        self.print_stop(stop, out)
+    }
+
+    fn cont(&mut self, limit: u64, out: &mut dyn Write) -> io::Result<()> {
+        let mut count = 0;
+        let stop = loop {
+            if let Some(stop) = self.step_one(out)? {
+                break stop;
+            }
+            let ip = self.program.ip();
+            if self.breakpoints.contains(&ip) {
+                break Stop::Breakpoint(ip);
+            }
+            count += 1;
+            if count >= limit {
+                break Stop::Limit(count);
+            }
+        };
+        self.print_stop(Some(stop), out)
+    }
+
+    // Execute a command, returns false on quit
+    pub fn command(&mut self, line: &str, out: &mut dyn Write) -> io::Result<bool> {
+        let args = line.split_whitespace().collect::<Vec<_>>();
+        let cmd = match args.first() {
+            Some(cmd) => *cmd,
+            None => return Ok(true),
+        };
+        match cmd {
+            "s" | "step" => {
+                let count = parse_num(args.get(1)).unwrap_or(1);
+                self.step(count, out)?;
+            }
+            "c" | "continue" => {
+                let limit = parse_num(args.get(1)).unwrap_or(CONTINUE_LIMIT);
+                self.cont(limit, out)?;
+            }
+            "b" | "break" => match parse_num(args.get(1)) {
+                Some(addr) => {
+                    self.breakpoints.insert(addr);
+                }
+                None => writeln!(out, "breakpoints: {:?}", self.breakpoints)?,
+            },
+            "d" | "delete" => match parse_num(args.get(1)) {
+                Some(addr) => {
+                    self.breakpoints.remove(&addr);
+                }
+                None => writeln!(out, "usage: delete <addr>")?,
+            },
+            "w" | "watch" => match parse_num(args.get(1)) {
+                Some(addr) => {
+                    self.watchpoints.insert(addr);
+                }
+                None => writeln!(out, "watchpoints: {:?}", self.watchpoints)?,
+            },
+            "uw" | "unwatch" => match parse_num(args.get(1)) {
+                Some(addr) => {
+                    self.watchpoints.remove(&addr);
+                }
+                None => writeln!(out, "usage: unwatch <addr>")?,
+            },
+            "x" => match parse_num::<usize>(args.get(1)) {
+                Some(addr) => {
+                    let count = parse_num(args.get(2)).unwrap_or(1).min(X_LIMIT);
+                    let vals = (addr..addr.saturating_add(count))
+                        .map(|addr| self.program.mem().get(addr).to_string())
+                        .collect::<Vec<_>>();
+                    writeln!(out, "{:04}: {}", addr, vals.join(" "))?;
+                }
+                None => writeln!(out, "usage: x <addr> [n]")?,
+            },
+            "set" => match (parse_num(args.get(1)), parse_num(args.get(2))) {
+                (Some(addr), Some(val)) => self.program.mem_mut().set(addr, val),
+                _ => writeln!(out, "usage: set <addr> <val>")?,
+            },
+            "ip" => match parse_num(args.get(1)) {
+                Some(ip) => self.program.set_ip(ip),
+                None => writeln!(out, "ip={}", self.program.ip())?,
+            },
+            "rb" => match parse_num(args.get(1)) {
+                Some(rel_base) => self.program.set_rel_base(rel_base),
+                None => writeln!(out, "rb={}", self.program.rel_base())?,
+            },
+            "i" | "input" => {
+                for arg in &args[1..] {
+                    match arg.parse() {
+                        Ok(val) => self.program.push_input(val),
+                        Err(_) => writeln!(out, "invalid input {:?}", arg)?,
+                    }
+                }
+            }
+            "r" | "regs" => self.print_regs(out)?,
+            "h" | "history" => {
+                let count = parse_num(args.get(1)).unwrap_or(HISTORY_LEN);
+                let skip = self.history.len().saturating_sub(count);
+                for (addr, instr) in self.history.iter().skip(skip) {
+                    writeln!(out, "{:04}: {}", addr, instr)?;
+                }
+            }
+            "save" => match args.get(1) {
+                Some(path) => {
+                    if let Err(err) = self.program.snapshot().save(path) {
+                        writeln!(out, "save failed: {}", err)?;
+                    }
+                }
+                None => writeln!(out, "usage: save <file>")?,
+            },
+            "load" => match args.get(1).map(Snapshot::load) {
+                Some(Ok(snapshot)) => {
+                    self.program.restore(&snapshot);
+                    self.history.clear();
+                    self.print_regs(out)?;
+                }
+                Some(Err(err)) => writeln!(out, "load failed: {}", err)?,
+                None => writeln!(out, "usage: load <file>")?,
+            },
+            "q" | "quit" => return Ok(false),
+            _ => writeln!(out, "unknown command {:?}", cmd)?,
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn run(debugger: &mut Debugger, line: &str) -> String {
+        let mut out = Vec::new();
+        debugger.command(line, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_debugger() {
+        // read input into [9], output it, add 1 to it and halt
+        let mem = vec![3, 9, 4, 9, 1001, 9, 1, 9, 99, 0];
+        let mut debugger = Debugger::new(Program::new(mem));
+
+        assert_eq!(
+            run(&mut debugger, "s"),
+            "needs input\nip=0 rb=0 | 0000: IN -> [9]\n"
+        );
+        run(&mut debugger, "i 41");
+        run(&mut debugger, "b 4");
+        assert_eq!(
+            run(&mut debugger, "c"),
+            "output: 41\nbreakpoint at 4\nip=4 rb=0 | 0004: ADD [9], #1 -> [9]\n"
+        );
+        run(&mut debugger, "w 9");
+        assert_eq!(
+            run(&mut debugger, "c"),
+            "watchpoint [9]: 41 -> 42\nip=8 rb=0 | 0008: HLT\n"
+        );
+        assert_eq!(
+            run(&mut debugger, "h 2"),
+            "0002: OUT [9]\n0004: ADD [9], #1 -> [9]\n"
+        );
+        run(&mut debugger, "set 9 7");
+        assert_eq!(run(&mut debugger, "x 8 2"), "0008: 99 7\n");
+        assert_eq!(run(&mut debugger, "c"), "halted\nip=8 rb=0 | 0008: HLT\n");
+
+        run(&mut debugger, "ip 2");
+        run(&mut debugger, "rb 5");
+        assert_eq!(
+            run(&mut debugger, "s"),
+            "output: 7\nip=4 rb=5 | 0004: ADD [9], #1 -> [9]\n"
+        );
+        assert_eq!(
+            run(&mut debugger, "x 0 100000000000").split(' ').count(),
+            1 + 1024
+        );
+        assert!(!debugger.command("q", &mut Vec::new()).unwrap());
+    }
+
+    #[test]
+    fn test_continue_limit() {
+        // jump to itself forever
+        let mut debugger = Debugger::new(Program::new(vec![1105, 1, 0]));
+        assert_eq!(
+            run(&mut debugger, "c 1000"),
+            "stopped after 1000 instructions\nip=0 rb=0 | 0000: JT #1, #0\n"
+        );
+        assert_eq!(debugger.program().instruction_count(), 1000);
+    }
+}
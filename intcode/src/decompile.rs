@@ -0,0 +1,692 @@
+use crate::analysis::{analyze, Block, Cfg, Edge};
+use crate::disasm::{Instr, Param};
+use crate::op::{Op, ParamMode};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write;
+
+// Decompiler to pseudo Rust
+//
+// Based on the control flow graph of analysis:
+//
+// - Functions start at addr 0 (main) and at every call target
+// - A function which starts with ARB #n has a stack frame of n cells, rel params are named
+//   by their slot relative to the rel base on entry: ret_addr (slot 0), then arg1 ..
+//   for the slots written by the callers before a call and local1 .. for the others, up
+//   to slot n-1. Args of calls behind the frame are named like in the callee with its name
+//   in front, f0042.arg1, .. (slot n is the ret_addr of the callee), call.arg1 if the
+//   function calls more than one function. In main the rel base starts at 0, so rel params
+//   are shown as mem.
+// - Jumps back to a block are loops, forward conditional jumps are if or if / else,
+//   everything else is a goto
+// - Compare into a cell which is only read by the following jump is folded into the
+//   condition
+//
+// If ARB is not used with a direct value, later rel params are shown as rel[n].
+
+struct Cond {
+    lhs: String,
+    op: &'static str,
+    rhs: String,
+}
+
+impl Cond {
+    fn negate(self) -> Self {
+        let op = match self.op {
+            "<" => ">=",
+            ">=" => "<",
+            "==" => "!=",
+            _ => "==",
+        };
+        Cond { op, ..self }
+    }
+}
+
+struct Func<'a> {
+    entry: usize,
+    frame: i64,
+    blocks: Vec<&'a Block>,
+    // rel base delta on entry of every block, None if unknown
+    deltas: HashMap<usize, Option<i64>>,
+    callees: BTreeSet<usize>,
+    // slots written by the callers
+    args: BTreeSet<i64>,
+}
+
+struct Decompiler {
+    // addrs of jumps, which have the compare before them folded into the condition
+    folded: BTreeSet<usize>,
+    // labels used by gotos
+    labels: BTreeSet<usize>,
+    out: String,
+    indent: usize,
+    // loop headers and the addr behind the loop
+    loops: Vec<(usize, Option<usize>)>,
+}
+
+fn func_name(entry: usize) -> String {
+    if entry == 0 {
+        "main".to_string()
+    } else {
+        format!("f{:04}", entry)
+    }
+}
+
+// Target of the call at the end of the block
+fn call_target(block: &Block) -> Option<(usize, usize)> {
+    let site = block
+        .succs
+        .iter()
+        .find(|(_, edge)| *edge == Edge::Return)?
+        .0;
+    let target = block.succs.iter().find(|(_, edge)| *edge == Edge::Taken)?.0;
+    Some((target, site))
+}
+
+fn last(block: &Block) -> &(usize, Instr) {
+    block.instrs.last().unwrap()
+}
+
+fn is_val(param: Param, val: i64) -> bool {
+    param.mode == ParamMode::Val && param.val == val
+}
+
+fn is_compare(op: Op) -> bool {
+    op == Op::Less || op == Op::Equals
+}
+
+fn find_folded(cfg: &Cfg) -> BTreeSet<usize> {
+    let mut reads = HashMap::new();
+    for block in cfg.blocks.values() {
+        for (_, instr) in &block.instrs {
+            let num_reads = instr.params.len() - instr.op.writes() as usize;
+            for param in &instr.params[..num_reads] {
+                if param.mode == ParamMode::Addr {
+                    *reads.entry(param.val).or_insert(0) += 1;
+                }
+            }
+        }
+    }
+    let mut sites = HashMap::<i64, Vec<usize>>::new();
+    for block in cfg.blocks.values() {
+        let len = block.instrs.len();
+        if len < 2 {
+            continue;
+        }
+        let (_, compare) = &block.instrs[len - 2];
+        let (addr, jump) = &block.instrs[len - 1];
+        if !is_compare(compare.op) || jump.jump_taken().is_some() {
+            continue;
+        }
+        let (cond, result) = (jump.params[0], compare.params[2]);
+        if result.mode == ParamMode::Addr && cond == result {
+            sites.entry(result.val).or_default().push(*addr);
+        }
+    }
+    sites
+        .into_iter()
+        .filter(|(cell, addrs)| reads.get(cell) == Some(&addrs.len()))
+        .flat_map(|(_, addrs)| addrs)
+        .collect()
+}
+
+impl<'a> Func<'a> {
+    fn new(cfg: &'a Cfg, entry: usize) -> Self {
+        let frame = match cfg.blocks.get(&entry).map(|block| &block.instrs[0]) {
+            Some((_, instr)) if entry != 0 && instr.op == Op::SetRelBase => match instr.params[0] {
+                Param {
+                    mode: ParamMode::Val,
+                    val,
+                } if val > 0 => val,
+                _ => 0,
+            },
+            _ => 0,
+        };
+
+        let mut deltas = HashMap::<usize, Option<i64>>::new();
+        let mut todo = vec![(entry, Some(0))];
+        while let Some((start, delta)) = todo.pop() {
+            let block = match cfg.blocks.get(&start) {
+                Some(block) => block,
+                None => continue,
+            };
+            match deltas.get(&start) {
+                Some(known) if *known == delta || known.is_none() => continue,
+                Some(_) => {
+                    // conflicting deltas
+                    deltas.insert(start, None);
+                }
+                None => {
+                    deltas.insert(start, delta);
+                }
+            }
+            let delta = deltas[&start];
+            let mut end_delta = delta;
+            for (_, instr) in &block.instrs {
+                if instr.op == Op::SetRelBase {
+                    end_delta = match instr.params[0] {
+                        Param {
+                            mode: ParamMode::Val,
+                            val,
+                        } => end_delta.map(|d| d + val),
+                        _ => None,
+                    };
+                }
+            }
+            let call = call_target(block);
+            for &(succ, _) in &block.succs {
+                // the callee is a function of its own
+                if call.map(|(target, _)| target) != Some(succ) {
+                    todo.push((succ, end_delta));
+                }
+            }
+        }
+
+        let mut blocks = deltas
+            .keys()
+            .map(|start| &cfg.blocks[start])
+            .collect::<Vec<_>>();
+        blocks.sort_by_key(|block| block.start);
+        let callees = blocks
+            .iter()
+            .filter_map(|block| call_target(block))
+            .map(|(target, _)| target)
+            .collect();
+        Self {
+            entry,
+            frame,
+            blocks,
+            deltas,
+            callees,
+            args: BTreeSet::new(),
+        }
+    }
+
+    // Slots of the callees which are written before the calls, as (callee, slot)
+    fn passed_args(&self) -> Vec<(usize, i64)> {
+        let mut passed = Vec::new();
+        for block in &self.blocks {
+            let target = match call_target(block) {
+                Some((target, _)) => target,
+                None => continue,
+            };
+            let mut delta = self.deltas[&block.start];
+            let mut writes = Vec::new();
+            for (_, instr) in &block.instrs {
+                if instr.op.writes() {
+                    let param = instr.params[instr.params.len() - 1];
+                    if let (ParamMode::Rel, Some(d)) = (param.mode, delta) {
+                        writes.push(d + param.val);
+                    }
+                }
+                if instr.op == Op::SetRelBase {
+                    delta = match instr.params[0] {
+                        Param {
+                            mode: ParamMode::Val,
+                            val,
+                        } => delta.map(|d| d + val),
+                        _ => None,
+                    };
+                }
+            }
+            // the callee starts with the rel base at the call
+            if let Some(delta) = delta {
+                passed.extend(
+                    writes
+                        .into_iter()
+                        .map(|slot| (target, slot - delta))
+                        .filter(|&(_, slot)| slot > 0),
+                );
+            }
+        }
+        passed
+    }
+
+    fn index_of(&self, addr: usize) -> Option<usize> {
+        self.blocks.iter().position(|block| block.start == addr)
+    }
+
+    fn start_at(&self, index: usize) -> Option<usize> {
+        self.blocks.get(index).map(|block| block.start)
+    }
+
+    fn operand(&self, param: Param, delta: Option<i64>) -> String {
+        match param.mode {
+            ParamMode::Val => param.val.to_string(),
+            ParamMode::Addr => format!("mem[{}]", param.val),
+            ParamMode::Rel => match delta.map(|d| d + param.val) {
+                None => format!("rel[{}]", param.val),
+                Some(slot) if self.entry == 0 && slot >= 0 => format!("mem[{}]", slot),
+                Some(0) => "ret_addr".to_string(),
+                Some(slot) if slot > 0 && slot < self.frame && self.args.contains(&slot) => {
+                    format!("arg{}", slot)
+                }
+                Some(slot) if slot > 0 && slot < self.frame => format!("local{}", slot),
+                Some(slot) if slot > self.frame && self.frame > 0 => {
+                    let callee = match self.callees.iter().next() {
+                        Some(&callee) if self.callees.len() == 1 => func_name(callee),
+                        _ => "call".to_string(),
+                    };
+                    format!("{}.arg{}", callee, slot - self.frame)
+                }
+                Some(slot) => format!("rel[{}]", slot),
+            },
+        }
+    }
+}
+
+impl Decompiler {
+    fn line(&mut self, text: &str) {
+        writeln!(self.out, "{}{}", "    ".repeat(self.indent), text).unwrap();
+    }
+
+    fn func(&mut self, func: &Func) {
+        if func.frame > 0 {
+            self.line(&format!("// frame of {} cells", func.frame));
+        }
+        self.line(&format!("fn {}() {{", func_name(func.entry)));
+        self.indent += 1;
+        self.range(func, 0, func.blocks.len(), None, None);
+        self.indent -= 1;
+        self.line("}");
+    }
+
+    fn jump(&mut self, target: usize, next: Option<usize>) -> Option<String> {
+        if Some(target) == next {
+            return None;
+        }
+        for &(header, exit) in self.loops.iter().rev() {
+            if header == target {
+                return Some(format!("continue 'l{:04};", header));
+            }
+            if exit == Some(target) {
+                return Some(format!("break 'l{:04};", header));
+            }
+        }
+        self.labels.insert(target);
+        Some(format!("goto L{:04};", target))
+    }
+
+    fn cond(&self, func: &Func, block: &Block, delta: Option<i64>) -> Cond {
+        let (addr, jump) = last(block);
+        let cond = if self.folded.contains(addr) {
+            let (_, compare) = &block.instrs[block.instrs.len() - 2];
+            Cond {
+                lhs: func.operand(compare.params[0], delta),
+                op: if compare.op == Op::Less { "<" } else { "==" },
+                rhs: func.operand(compare.params[1], delta),
+            }
+        } else {
+            Cond {
+                lhs: func.operand(jump.params[0], delta),
+                op: "!=",
+                rhs: "0".to_string(),
+            }
+        };
+        if jump.op == Op::JumpIfTrue {
+            cond
+        } else {
+            cond.negate()
+        }
+    }
+
+    // Statements of the block without the terminating jump, returns the delta at the end
+    fn statements(&mut self, func: &Func, block: &Block, skip: &[usize]) -> Option<i64> {
+        let mut delta = func.deltas[&block.start];
+        for (addr, instr) in &block.instrs {
+            if skip.contains(addr) {
+                continue;
+            }
+            let operand = |i: usize| func.operand(instr.params[i], delta);
+            let stmt = match instr.op {
+                Op::Add if is_val(instr.params[1], 0) => {
+                    format!("{} = {};", operand(2), operand(0))
+                }
+                Op::Add if is_val(instr.params[0], 0) => {
+                    format!("{} = {};", operand(2), operand(1))
+                }
+                Op::Mul if is_val(instr.params[1], 1) => {
+                    format!("{} = {};", operand(2), operand(0))
+                }
+                Op::Mul if is_val(instr.params[0], 1) => {
+                    format!("{} = {};", operand(2), operand(1))
+                }
+                Op::Add
+                    if instr.params[1].mode == ParamMode::Val
+                        && instr.params[1].val < 0
+                        && instr.params[1].val != i64::MIN =>
+                {
+                    format!(
+                        "{} = {} - {};",
+                        operand(2),
+                        operand(0),
+                        -instr.params[1].val
+                    )
+                }
+                Op::Add => format!("{} = {} + {};", operand(2), operand(0), operand(1)),
+                Op::Mul => format!("{} = {} * {};", operand(2), operand(0), operand(1)),
+                Op::Less => format!("{} = ({} < {}) as i64;", operand(2), operand(0), operand(1)),
+                Op::Equals => {
+                    format!(
+                        "{} = ({} == {}) as i64;",
+                        operand(2),
+                        operand(0),
+                        operand(1)
+                    )
+                }
+                Op::In => format!("{} = input();", operand(0)),
+                Op::Out => format!("output({});", operand(0)),
+                Op::SetRelBase => {
+                    let param = instr.params[0];
+                    let stmt = match param.mode {
+                        ParamMode::Val => None,
+                        _ => Some(format!("rel_base += {};", operand(0))),
+                    };
+                    delta = match param.mode {
+                        ParamMode::Val => delta.map(|d| d + param.val),
+                        _ => None,
+                    };
+                    match stmt {
+                        Some(stmt) => stmt,
+                        None => continue,
+                    }
+                }
+                Op::Halt => "halt();".to_string(),
+                // terminator
+                Op::JumpIfTrue | Op::JumpIfFalse => continue,
+            };
+            self.line(&stmt);
+        }
+        delta
+    }
+
+    // Emit blocks[from..to], next is the addr control reaches after the range
+    fn range(
+        &mut self,
+        func: &Func,
+        from: usize,
+        to: usize,
+        next: Option<usize>,
+        no_loop: Option<usize>,
+    ) {
+        let mut k = from;
+        while k < to {
+            let block = func.blocks[k];
+            let follow = if k + 1 < to {
+                func.start_at(k + 1)
+            } else {
+                next
+            };
+            if self.labels.contains(&block.start) {
+                self.indent -= 1;
+                self.line(&format!("L{:04}:", block.start));
+                self.indent += 1;
+            }
+
+            // loop, if there are jumps back to this block
+            let back = (k..to)
+                .rev()
+                .filter(|&m| call_target(func.blocks[m]).is_none())
+                .find(|&m| last(func.blocks[m]).1.jump_target() == Some(block.start));
+            if let (Some(m), true) = (back, no_loop != Some(k)) {
+                let exit = if m + 1 < to {
+                    func.start_at(m + 1)
+                } else {
+                    next
+                };
+                self.line(&format!("'l{:04}: loop {{", block.start));
+                self.indent += 1;
+                self.loops.push((block.start, exit));
+                // control continues at the header after the body
+                self.range(func, k, m + 1, Some(block.start), Some(k));
+                self.loops.pop();
+                self.indent -= 1;
+                self.line("}");
+                k = m + 1;
+                continue;
+            }
+
+            let (addr, instr) = last(block);
+            let mut skip = Vec::new();
+            if self.folded.contains(addr) {
+                skip.push(block.instrs[block.instrs.len() - 2].0);
+            }
+            let call = call_target(block);
+            if call.is_some() {
+                // the store of the return addr
+                skip.push(block.instrs[block.instrs.len().saturating_sub(2)].0);
+            }
+            let delta = self.statements(func, block, &skip);
+
+            if let Some((target, site)) = call {
+                self.line(&format!("{}();", func_name(target)));
+                if let Some(stmt) = self.jump(site, follow) {
+                    self.line(&stmt);
+                }
+            } else if instr.op == Op::Halt {
+                // nothing follows
+            } else if !matches!(instr.op, Op::JumpIfTrue | Op::JumpIfFalse) {
+                if let Some(stmt) = self.jump(block.end(), follow) {
+                    self.line(&stmt);
+                }
+            } else if instr.jump_target().is_none() && instr.params[1].mode != ParamMode::Val {
+                let target = func.operand(instr.params[1], delta);
+                let stmt = if target == "ret_addr" {
+                    "return;".to_string()
+                } else {
+                    format!("jump({});", target)
+                };
+                match instr.jump_taken() {
+                    Some(true) => self.line(&stmt),
+                    Some(false) => (),
+                    None => {
+                        let cond = self.cond(func, block, delta);
+                        self.line(&format!(
+                            "if {} {} {} {{ {} }}",
+                            cond.lhs, cond.op, cond.rhs, stmt
+                        ));
+                    }
+                }
+                if instr.jump_taken() != Some(true) {
+                    if let Some(stmt) = self.jump(block.end(), follow) {
+                        self.line(&stmt);
+                    }
+                }
+            } else {
+                let target = instr.jump_target().unwrap();
+                match instr.jump_taken() {
+                    Some(true) => {
+                        if let Some(stmt) = self.jump(target, follow) {
+                            self.line(&stmt);
+                        }
+                    }
+                    Some(false) => {
+                        if let Some(stmt) = self.jump(block.end(), follow) {
+                            self.line(&stmt);
+                        }
+                    }
+                    None => {
+                        let cond = self.cond(func, block, delta);
+                        // a jump to the end of the range only, if control continues there
+                        let inside =
+                            |t: usize| t > k && (t < to || t == to && next == Some(target));
+                        match func.index_of(target).filter(|&t| inside(t)) {
+                            // forward jump inside the range: if / else
+                            Some(t) if func.start_at(k + 1) == Some(block.end()) => {
+                                let target_next = if t < to { func.start_at(t) } else { next };
+                                // then part ends with a jump behind the else part
+                                let else_end = last(func.blocks[t - 1])
+                                    .1
+                                    .jump_target()
+                                    .filter(|_| t - 1 > k)
+                                    .filter(|_| {
+                                        last(func.blocks[t - 1]).1.jump_taken() == Some(true)
+                                    })
+                                    .and_then(|end| {
+                                        func.index_of(end).filter(|&e| e > t && e <= to)
+                                    });
+                                let cond = cond.negate();
+                                self.line(&format!("if {} {} {} {{", cond.lhs, cond.op, cond.rhs));
+                                self.indent += 1;
+                                match else_end {
+                                    Some(e) => {
+                                        let end_next = if e < to { func.start_at(e) } else { next };
+                                        self.range(func, k + 1, t, end_next, None);
+                                        self.indent -= 1;
+                                        self.line("} else {");
+                                        self.indent += 1;
+                                        self.range(func, t, e, end_next, None);
+                                        k = e;
+                                    }
+                                    None => {
+                                        self.range(func, k + 1, t, target_next, None);
+                                        k = t;
+                                    }
+                                }
+                                self.indent -= 1;
+                                self.line("}");
+                                continue;
+                            }
+                            _ => {
+                                if let Some(stmt) = self.jump(target, None) {
+                                    self.line(&format!(
+                                        "if {} {} {} {{ {} }}",
+                                        cond.lhs, cond.op, cond.rhs, stmt
+                                    ));
+                                }
+                                if let Some(stmt) = self.jump(block.end(), follow) {
+                                    self.line(&stmt);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            k += 1;
+        }
+    }
+}
+
+// Decompile all functions reachable from addr 0
+pub fn decompile(mem: &[i64]) -> String {
+    let cfg = analyze(mem);
+    let mut entries = BTreeSet::new();
+    entries.insert(0);
+    for block in cfg.blocks.values() {
+        if let Some((target, _)) = call_target(block) {
+            entries.insert(target);
+        }
+    }
+    let mut funcs = entries
+        .iter()
+        .map(|&entry| Func::new(&cfg, entry))
+        .collect::<Vec<_>>();
+    let passed = funcs
+        .iter()
+        .flat_map(|func| func.passed_args())
+        .collect::<Vec<_>>();
+    for (callee, slot) in passed {
+        if let Some(func) = funcs.iter_mut().find(|func| func.entry == callee) {
+            func.args.insert(slot);
+        }
+    }
+
+    let mut decompiler = Decompiler {
+        folded: find_folded(&cfg),
+        labels: BTreeSet::new(),
+        out: String::new(),
+        indent: 0,
+        loops: Vec::new(),
+    };
+    // the first pass collects the labels
+    for pass in 0..2 {
+        decompiler.out.clear();
+        for (i, func) in funcs.iter().enumerate() {
+            if i > 0 {
+                decompiler.out.push('\n');
+            }
+            decompiler.func(func);
+        }
+        if pass == 0 && decompiler.labels.is_empty() {
+            break;
+        }
+    }
+    decompiler.out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::asm::assemble;
+
+    #[test]
+    fn test_decompile() {
+        let mem = assemble(
+            "
+                in n
+            loop:
+                lt n, #1, tmp
+                jt tmp, #done
+                add n, #0, r+1
+                add #ret, #0, r+0
+                jt #1, #square
+            ret:
+                out r+1
+                add n, #-1, n
+                jt #1, #loop
+            done:
+                hlt
+            square:
+                arb #3
+                mul r-2, r-2, r-2
+                eq r-2, #4, tmp
+                jf tmp, #skip
+                add r-2, #100, r-2
+            skip:
+                arb #-3
+                jt #1, r+0
+            n:   data 0
+            tmp: data 0
+            ",
+        )
+        .unwrap();
+        assert_eq!(
+            decompile(&mem),
+            "fn main() {
+    mem[52] = input();
+    'l0002: loop {
+        if mem[52] < 1 { break 'l0002; }
+        mem[1] = mem[52];
+        f0030();
+        output(mem[1]);
+        mem[52] = mem[52] - 1;
+    }
+    halt();
+}
+
+// frame of 3 cells
+fn f0030() {
+    arg1 = arg1 * arg1;
+    if arg1 == 4 {
+        arg1 = arg1 + 100;
+    }
+    return;
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_decompile_day09() {
+        let mem = crate::program::parse_program(include_str!("../../day09/src/input.txt")).unwrap();
+        let src = decompile(&mem);
+        assert!(src.contains(
+            "        f0922.arg1 = arg1 - 1;
+        f0922();
+        local2 = f0922.arg1;
+        f0922.arg1 = arg1 - 3;
+        f0922();
+        arg1 = f0922.arg1 + local2;
+"
+        ));
+    }
+}
@@ -0,0 +1,328 @@
+use crate::memory::Memory;
+use crate::op::{parse_opcode, Op, ParamMode};
+use std::collections::BTreeSet;
+use std::fmt;
+
+// Disassembler
+//
+// Listing format, one instruction per line:
+//
+//   0042: ADD [r+3], #7 -> [120]
+//
+// - [120] param specifies mem addr (ParamMode::Addr)
+// - #7 param is a direct value (ParamMode::Val)
+// - [r+3] param specifies rel mem addr from rel base (ParamMode::Rel)
+// - the addr to write to is printed after ->
+// - cells which can not be decoded are printed as DATA
+//
+// With labels, only code reachable from addr 0 is decoded and direct jump targets
+// are labeled (L0042), everything else is printed as DATA.
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub mode: ParamMode,
+    pub val: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instr {
+    pub op: Op,
+    pub params: Vec<Param>,
+}
+
+impl Instr {
+    // Number of cells including the opcode
+    pub fn size(&self) -> usize {
+        1 + self.params.len()
+    }
+
+    // Jump target, if it is a direct value
+    pub fn jump_target(&self) -> Option<usize> {
+        match self.op {
+            Op::JumpIfTrue | Op::JumpIfFalse => match self.params[1] {
+                Param {
+                    mode: ParamMode::Val,
+                    val,
+                } if val >= 0 => Some(val as usize),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    // Some(true) / Some(false) if the jump is always / never taken, None if unknown
+    pub fn jump_taken(&self) -> Option<bool> {
+        let cond = match self.op {
+            Op::JumpIfTrue | Op::JumpIfFalse => self.params[0],
+            _ => return Some(false),
+        };
+        if cond.mode != ParamMode::Val {
+            return None;
+        }
+        Some((cond.val != 0) == (self.op == Op::JumpIfTrue))
+    }
+
+    // Control can continue with the next instruction
+    pub fn falls_through(&self) -> bool {
+        self.op != Op::Halt && self.jump_taken() != Some(true)
+    }
+}
+
+// Decode the instruction at addr, None if it is not a valid instruction
+//
+// Param modes for params the op does not have must be 0, otherwise the listing
+// could not be assembled back into the same image
+pub fn decode(mem: &[i64], addr: usize) -> Option<Instr> {
+    decode_cells(mem, addr, true)
+}
+
+// Decode the instruction at addr of a running program, unused param modes are ignored
+// like the program does
+pub fn decode_mem(mem: &Memory, addr: usize) -> Option<Instr> {
+    let cells = (addr..addr.saturating_add(4))
+        .map(|addr| mem.get(addr))
+        .collect::<Vec<_>>();
+    decode_cells(&cells, 0, false)
+}
+
+fn decode_cells(mem: &[i64], addr: usize, strict: bool) -> Option<Instr> {
+    let opcode = *mem.get(addr)?;
+    let (op, param_mode_0, param_mode_1, param_mode_2) = parse_opcode(opcode, addr).ok()?;
+    if strict && opcode / 100 >= 10_i64.pow(op.num_params() as u32) {
+        return None;
+    }
+    let param_modes = [param_mode_0, param_mode_1, param_mode_2];
+    let mut params = Vec::with_capacity(op.num_params());
+    for (i, &mode) in param_modes.iter().enumerate().take(op.num_params()) {
+        let val = *mem.get(addr + 1 + i)?;
+        params.push(Param { mode, val });
+    }
+    if op.writes() && params.last().map(|param| param.mode) == Some(ParamMode::Val) {
+        return None;
+    }
+    Some(Instr { op, params })
+}
+
+// Static pass, follows all direct jumps starting at addr 0
+// Returns the addrs of all reachable instructions and all direct jump targets
+pub fn find_code(mem: &[i64]) -> (BTreeSet<usize>, BTreeSet<usize>) {
+    let mut code = BTreeSet::new();
+    let mut targets = BTreeSet::new();
+    let mut todo = vec![0];
+    while let Some(addr) = todo.pop() {
+        if code.contains(&addr) {
+            continue;
+        }
+        let instr = match decode(mem, addr) {
+            Some(instr) => instr,
+            None => continue,
+        };
+        code.insert(addr);
+        if let Some(target) = instr.jump_target() {
+            if instr.jump_taken() != Some(false) {
+                targets.insert(target);
+                todo.push(target);
+            }
+        }
+        if instr.falls_through() {
+            todo.push(addr + instr.size());
+        }
+    }
+    (code, targets)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Line {
+    Instr(usize, Instr),
+    Data(usize, i64),
+}
+
+pub struct Listing {
+    pub lines: Vec<Line>,
+    pub labels: BTreeSet<usize>,
+}
+
+// Linear sweep, every cell which can be decoded is treated as code
+pub fn disassemble(mem: &[i64]) -> Listing {
+    let mut lines = Vec::new();
+    let mut addr = 0;
+    while addr < mem.len() {
+        match decode(mem, addr) {
+            Some(instr) => {
+                let len = instr.size();
+                lines.push(Line::Instr(addr, instr));
+                addr += len;
+            }
+            None => {
+                lines.push(Line::Data(addr, mem[addr]));
+                addr += 1;
+            }
+        }
+    }
+    Listing {
+        lines,
+        labels: BTreeSet::new(),
+    }
+}
+
+// Only code found by the static pass is decoded, jump targets are labeled
+//
+// Targets inside of a printed instruction (or behind the image) get no label, they are
+// printed as numbers.
+pub fn disassemble_with_labels(mem: &[i64]) -> Listing {
+    let (code, mut labels) = find_code(mem);
+    let mut lines = Vec::new();
+    let mut starts = BTreeSet::new();
+    let mut addr = 0;
+    while addr < mem.len() {
+        starts.insert(addr);
+        match decode(mem, addr).filter(|_| code.contains(&addr)) {
+            Some(instr) => {
+                let len = instr.size();
+                lines.push(Line::Instr(addr, instr));
+                addr += len;
+            }
+            None => {
+                lines.push(Line::Data(addr, mem[addr]));
+                addr += 1;
+            }
+        }
+    }
+    labels.retain(|addr| starts.contains(addr));
+    Listing { lines, labels }
+}
+
+fn fmt_param(f: &mut fmt::Formatter, param: Param, labeled: bool) -> fmt::Result {
+    match param.mode {
+        ParamMode::Addr => write!(f, "[{}]", param.val),
+        ParamMode::Val if labeled => write!(f, "#L{:04}", param.val),
+        ParamMode::Val => write!(f, "#{}", param.val),
+        ParamMode::Rel if param.val < 0 => write!(f, "[r{}]", param.val),
+        ParamMode::Rel => write!(f, "[r+{}]", param.val),
+    }
+}
+
+fn fmt_instr(f: &mut fmt::Formatter, instr: &Instr, labels: &BTreeSet<usize>) -> fmt::Result {
+    write!(f, "{}", instr.op.mnemonic().to_uppercase())?;
+    let (params, result) = if instr.op.writes() {
+        let (result, params) = instr.params.split_last().unwrap();
+        (params, Some(result))
+    } else {
+        (&instr.params[..], None)
+    };
+    let label = instr.jump_target().filter(|target| labels.contains(target));
+    for (i, &param) in params.iter().enumerate() {
+        write!(f, "{}", if i == 0 { " " } else { ", " })?;
+        // only the jump target is labeled
+        fmt_param(f, param, i == 1 && label.is_some())?;
+    }
+    if let Some(&result) = result {
+        write!(f, " -> ")?;
+        fmt_param(f, result, false)?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Instr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_instr(f, self, &BTreeSet::new())
+    }
+}
+
+impl fmt::Display for Listing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for line in &self.lines {
+            match line {
+                Line::Instr(addr, instr) => {
+                    if self.labels.contains(addr) {
+                        writeln!(f, "L{:04}:", addr)?;
+                    }
+                    write!(f, "{:04}: ", addr)?;
+                    fmt_instr(f, instr, &self.labels)?;
+                    writeln!(f)?;
+                }
+                Line::Data(addr, val) => {
+                    if self.labels.contains(addr) {
+                        writeln!(f, "L{:04}:", addr)?;
+                    }
+                    writeln!(f, "{:04}: DATA {}", addr, val)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(
+            decode(&[21101, 3, 7, 120], 0),
+            Some(Instr {
+                op: Op::Add,
+                params: vec![
+                    Param {
+                        mode: ParamMode::Val,
+                        val: 3
+                    },
+                    Param {
+                        mode: ParamMode::Val,
+                        val: 7
+                    },
+                    Param {
+                        mode: ParamMode::Rel,
+                        val: 120
+                    },
+                ],
+            })
+        );
+        // write to direct value, invalid opcode, truncated
+        assert_eq!(decode(&[11101, 3, 7, 120], 0), None);
+        assert_eq!(decode(&[42], 0), None);
+        assert_eq!(decode(&[1, 2, 3], 0), None);
+        // unused param mode
+        assert_eq!(decode(&[199], 0), None);
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let mem = vec![1201, 3, 7, 120, 3, 5, 204, -1, 99, 42];
+        let listing = disassemble(&mem).to_string();
+        assert_eq!(
+            listing,
+            "0000: ADD [r+3], #7 -> [120]\n\
+             0004: IN -> [5]\n\
+             0006: OUT [r-1]\n\
+             0008: HLT\n\
+             0009: DATA 42\n"
+        );
+    }
+
+    #[test]
+    fn test_disassemble_with_labels() {
+        // jump over data, loop until [20] == 0
+        let mem = vec![1105, 1, 4, 42, 1001, 20, -1, 20, 1005, 20, 4, 99];
+        let listing = disassemble_with_labels(&mem).to_string();
+        assert_eq!(
+            listing,
+            "0000: JT #1, #L0004\n\
+             0003: DATA 42\n\
+             L0004:\n\
+             0004: ADD [20], #-1 -> [20]\n\
+             0008: JT [20], #L0004\n\
+             0011: HLT\n"
+        );
+
+        // the jump target 4 is inside of the ADD, it is not labeled
+        let mem = vec![1005, 9, 4, 1101, 99, 0, 10, 99, 0, 0, 0];
+        let listing = disassemble_with_labels(&mem);
+        assert!(listing.labels.is_empty());
+        assert_eq!(
+            listing.to_string().lines().take(3).collect::<Vec<_>>(),
+            vec!["0000: JT [9], #4", "0003: ADD #99, #0 -> [10]", "0007: HLT"]
+        );
+    }
+}
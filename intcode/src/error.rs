@@ -0,0 +1,129 @@
+use std::fmt;
+
+// Budget of a program which was exhausted
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Limit {
+    Instructions,
+    Time,
+}
+
+// Faults of a program, all of them store the ip and the raw opcode of the faulting instruction
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum VmError {
+    InvalidOpcode {
+        ip: usize,
+        opcode: i64,
+    },
+    InvalidMode {
+        ip: usize,
+        opcode: i64,
+        mode: i64,
+    },
+    WriteToImmediate {
+        ip: usize,
+        opcode: i64,
+    },
+    NegativeAddress {
+        ip: usize,
+        opcode: i64,
+        addr: i64,
+    },
+    OutOfMemory {
+        ip: usize,
+        opcode: i64,
+        addr: i64,
+    },
+    UnexpectedEof {
+        ip: usize,
+        opcode: i64,
+    },
+    // result of Op::Add or Op::Mul does not fit into the word type
+    Overflow {
+        ip: usize,
+        opcode: i64,
+    },
+    // not a fault, the instruction at ip was not executed and the program can be resumed
+    // after raising the budget
+    BudgetExhausted {
+        ip: usize,
+        opcode: i64,
+        limit: Limit,
+    },
+}
+
+impl VmError {
+    pub fn ip(&self) -> usize {
+        match *self {
+            VmError::InvalidOpcode { ip, .. }
+            | VmError::InvalidMode { ip, .. }
+            | VmError::WriteToImmediate { ip, .. }
+            | VmError::NegativeAddress { ip, .. }
+            | VmError::OutOfMemory { ip, .. }
+            | VmError::UnexpectedEof { ip, .. }
+            | VmError::Overflow { ip, .. }
+            | VmError::BudgetExhausted { ip, .. } => ip,
+        }
+    }
+
+    pub fn opcode(&self) -> i64 {
+        match *self {
+            VmError::InvalidOpcode { opcode, .. }
+            | VmError::InvalidMode { opcode, .. }
+            | VmError::WriteToImmediate { opcode, .. }
+            | VmError::NegativeAddress { opcode, .. }
+            | VmError::OutOfMemory { opcode, .. }
+            | VmError::UnexpectedEof { opcode, .. }
+            | VmError::Overflow { opcode, .. }
+            | VmError::BudgetExhausted { opcode, .. } => opcode,
+        }
+    }
+}
+
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            VmError::InvalidOpcode { ip, opcode } => {
+                write!(f, "Invalid opcode {} at addr {}", opcode, ip)
+            }
+            VmError::InvalidMode { ip, opcode, mode } => write!(
+                f,
+                "Invalid param mode value {} in opcode {} at addr {}",
+                mode, opcode, ip
+            ),
+            VmError::WriteToImmediate { ip, opcode } => write!(
+                f,
+                "Write to immediate param in opcode {} at addr {}",
+                opcode, ip
+            ),
+            VmError::NegativeAddress { ip, opcode, addr } => write!(
+                f,
+                "Negative addr {} accessed by opcode {} at addr {}",
+                addr, opcode, ip
+            ),
+            VmError::OutOfMemory { ip, opcode, addr } => write!(
+                f,
+                "Addr {} out of memory accessed by opcode {} at addr {}",
+                addr, opcode, ip
+            ),
+            VmError::UnexpectedEof { ip, opcode } => {
+                write!(f, "No input available for opcode {} at addr {}", opcode, ip)
+            }
+            VmError::Overflow { ip, opcode } => {
+                write!(f, "Overflow in opcode {} at addr {}", opcode, ip)
+            }
+            VmError::BudgetExhausted { ip, opcode, limit } => {
+                let limit = match limit {
+                    Limit::Instructions => "Instruction",
+                    Limit::Time => "Time",
+                };
+                write!(
+                    f,
+                    "{} budget exhausted before opcode {} at addr {}",
+                    limit, opcode, ip
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for VmError {}
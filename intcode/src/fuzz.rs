@@ -0,0 +1,481 @@
+use crate::cached::CachedProgram;
+use crate::error::VmError;
+use crate::memory::Memory;
+use crate::native::{NativeProgram, Translated};
+use crate::program::{Program, State};
+use crate::snapshot::Snapshot;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+// Property based fuzzing of the VM
+//
+// Random images are built mostly from valid opcodes with random param modes and params
+// near the image, so the programs actually jump around and write into their own code.
+// Every case is run with an instruction budget and has to satisfy:
+//
+// - no panics, faults are reported as VmError
+// - deterministic, a second run gives the same outcome
+// - snapshots, continuing from a (serialized) snapshot gives the same outcome as running
+//   through
+// - engines, Program, CachedProgram and NativeProgram give the same outcome
+//
+// NativeProgram needs a translated image, so some cases are mutations of the translated
+// programs below instead of random images.
+
+// generated with the aot tool from the examples of day05 and day09
+mod compare {
+    include!("../fixtures/compare.rs");
+}
+mod quine {
+    include!("../fixtures/quine.rs");
+}
+
+static TRANSLATED: [&Translated; 2] = [&compare::TRANSLATED, &quine::TRANSLATED];
+
+// xorshift64*, good enough for test data
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // the state must not be 0
+        Self {
+            state: (seed ^ 0x9e37_79b9_7f4a_7c15) | 1,
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    // Uniform in 0..n, n must not be 0
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    // Uniform in lo..=hi
+    pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        match (hi.wrapping_sub(lo) as u64).checked_add(1) {
+            Some(n) => lo.wrapping_add(self.below(n) as i64),
+            None => self.next_u64() as i64,
+        }
+    }
+
+    // true with probability 1/n
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.below(n) == 0
+    }
+}
+
+// opcode, number of params, index of the written param
+const OPS: [(i64, usize, Option<usize>); 10] = [
+    (1, 3, Some(2)),
+    (2, 3, Some(2)),
+    (3, 1, Some(0)),
+    (4, 1, None),
+    (5, 2, None),
+    (6, 2, None),
+    (7, 3, Some(2)),
+    (8, 3, Some(2)),
+    (9, 1, None),
+    (99, 0, None),
+];
+
+fn random_val(rng: &mut Rng, len: usize) -> i64 {
+    match rng.below(40) {
+        0 => rng.next_u64() as i64,
+        1 => [i64::MIN, i64::MAX, -1, 0][rng.below(4) as usize],
+        2..=7 => rng.range(-10, 10),
+        _ => rng.range(0, len as i64),
+    }
+}
+
+pub fn random_image(rng: &mut Rng, len: usize) -> Vec<i64> {
+    let mut mem = Vec::with_capacity(len);
+    while mem.len() < len {
+        if rng.one_in(20) {
+            mem.push(random_val(rng, len));
+            continue;
+        }
+        let (mut opcode, num_params, write) = OPS[rng.below(OPS.len() as u64) as usize];
+        for (i, factor) in [100, 1000, 10000].iter().enumerate().take(num_params) {
+            // mostly valid modes, sometimes the invalid mode 3 or a write to an immediate
+            let mode = if rng.one_in(100) {
+                3
+            } else if Some(i) == write && !rng.one_in(50) {
+                rng.below(2) * 2
+            } else {
+                rng.below(3)
+            };
+            opcode += mode as i64 * factor;
+        }
+        mem.push(opcode);
+        for _ in 0..num_params {
+            mem.push(random_val(rng, len));
+        }
+    }
+    mem.truncate(len);
+    mem
+}
+
+pub fn random_inputs(rng: &mut Rng, max_len: usize) -> Vec<i64> {
+    let len = rng.below(max_len as u64 + 1) as usize;
+    (0..len).map(|_| random_val(rng, 16)).collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Case {
+    pub mem: Vec<i64>,
+    pub inputs: Vec<i64>,
+    pub mem_limit: Option<usize>,
+}
+
+impl Case {
+    pub fn random(rng: &mut Rng) -> Self {
+        let len = rng.range(1, 64) as usize;
+        let mem = random_image(rng, len);
+        let inputs = random_inputs(rng, 8);
+        let mem_limit = if rng.one_in(2) {
+            Some(len + rng.below(4096) as usize)
+        } else {
+            None
+        };
+        Self {
+            mem,
+            inputs,
+            mem_limit,
+        }
+    }
+
+    // One of the translated programs with a few random cells
+    pub fn translated(rng: &mut Rng) -> Self {
+        let translated = TRANSLATED[rng.below(TRANSLATED.len() as u64) as usize];
+        let mut mem = translated.image.to_vec();
+        let len = mem.len();
+        for _ in 0..rng.below(4) {
+            let addr = rng.below(len as u64) as usize;
+            mem[addr] = random_val(rng, len);
+        }
+        Self {
+            mem,
+            inputs: random_inputs(rng, 8),
+            mem_limit: None,
+        }
+    }
+
+    fn program(&self) -> Program {
+        match self.mem_limit {
+            Some(limit) => Program::with_mem_limit(self.mem.clone(), limit),
+            None => Program::new(self.mem.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum End {
+    Halted,
+    Fault(VmError),
+    // the program needs more inputs than the case has
+    InputExhausted,
+    BudgetExhausted,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub end: End,
+    pub outputs: Vec<i64>,
+    pub steps: usize,
+    pub mem: Memory,
+    pub ip: usize,
+    pub rel_base: i64,
+}
+
+trait Engine {
+    fn step(&mut self) -> Result<Option<State>, VmError>;
+    fn push_input(&mut self, val: i64);
+    fn program(&self) -> &Program;
+}
+
+impl Engine for Program {
+    fn step(&mut self) -> Result<Option<State>, VmError> {
+        Program::step(self)
+    }
+
+    fn push_input(&mut self, val: i64) {
+        Program::push_input(self, val)
+    }
+
+    fn program(&self) -> &Program {
+        self
+    }
+}
+
+impl Engine for CachedProgram {
+    fn step(&mut self) -> Result<Option<State>, VmError> {
+        CachedProgram::step(self)
+    }
+
+    fn push_input(&mut self, val: i64) {
+        CachedProgram::push_input(self, val)
+    }
+
+    fn program(&self) -> &Program {
+        CachedProgram::program(self)
+    }
+}
+
+// Single steps with an instruction budget of 1, the native code stops at the next
+// instruction
+impl Engine for NativeProgram {
+    fn step(&mut self) -> Result<Option<State>, VmError> {
+        let count = self.program().instruction_count();
+        self.set_instruction_budget(1);
+        let result = self.resume();
+        self.clear_budgets();
+        match result {
+            Ok(State::Output(val)) => Ok(Some(State::Output(val))),
+            // the next instruction halted or faulted before its budget check, the next step
+            // gets there again
+            _ if self.program().instruction_count() > count => Ok(None),
+            result => result.map(Some),
+        }
+    }
+
+    fn push_input(&mut self, val: i64) {
+        NativeProgram::push_input(self, val)
+    }
+
+    fn program(&self) -> &Program {
+        NativeProgram::program(self)
+    }
+}
+
+// Execute up to budget instructions, inputs are taken from inputs starting at *next_input
+fn run<E: Engine>(
+    engine: &mut E,
+    inputs: &[i64],
+    next_input: &mut usize,
+    budget: usize,
+) -> Outcome {
+    let mut outputs = Vec::new();
+    let mut steps = 0;
+    let end = loop {
+        if steps == budget {
+            break End::BudgetExhausted;
+        }
+        steps += 1;
+        match engine.step() {
+            Ok(None) => (),
+            Ok(Some(State::Output(val))) => outputs.push(val),
+            Ok(Some(State::NeedsInput)) => match inputs.get(*next_input) {
+                Some(&val) => {
+                    engine.push_input(val);
+                    *next_input += 1;
+                }
+                None => break End::InputExhausted,
+            },
+            Ok(Some(State::Halted)) => break End::Halted,
+            Err(err) => break End::Fault(err),
+        }
+    };
+    let program = engine.program();
+    Outcome {
+        end,
+        outputs,
+        steps,
+        mem: program.mem().clone(),
+        ip: program.ip(),
+        rel_base: program.rel_base(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub case: Case,
+    pub invariant: &'static str,
+    pub detail: String,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mem = self
+            .case
+            .mem
+            .iter()
+            .map(i64::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        writeln!(f, "{}: {}", self.invariant, self.detail)?;
+        writeln!(f, "  mem: {}", mem)?;
+        writeln!(f, "  inputs: {:?}", self.case.inputs)?;
+        write!(f, "  mem limit: {:?}", self.case.mem_limit)
+    }
+}
+
+fn check_invariants(case: &Case, budget: usize) -> Result<(), (&'static str, String)> {
+    let outcome = run(&mut case.program(), &case.inputs, &mut 0, budget);
+
+    let replay = run(&mut case.program(), &case.inputs, &mut 0, budget);
+    if replay != outcome {
+        return Err((
+            "deterministic",
+            format!("{:?} != {:?}", replay.end, outcome.end),
+        ));
+    }
+
+    let mut cached = CachedProgram::from_program(case.program());
+    let cached = run(&mut cached, &case.inputs, &mut 0, budget);
+    if cached != outcome {
+        return Err((
+            "engines",
+            format!("cached {:?} != plain {:?}", cached.end, outcome.end),
+        ));
+    }
+
+    // the translated image only has to match partly, see NativeProgram::from_program
+    let translated = TRANSLATED
+        .iter()
+        .find(|translated| translated.image.len() == case.mem.len());
+    if let Some(translated) = translated {
+        let mut native = NativeProgram::from_program(case.program(), translated);
+        let native = run(&mut native, &case.inputs, &mut 0, budget);
+        if native != outcome {
+            return Err((
+                "engines",
+                format!("native {:?} != plain {:?}", native.end, outcome.end),
+            ));
+        }
+    }
+
+    // snapshot halfway, then finish with the original and with a restored program
+    let half = outcome.steps / 2;
+    let mut program = case.program();
+    let mut next_input = 0;
+    let first = run(&mut program, &case.inputs, &mut next_input, half);
+    if first.end == End::BudgetExhausted {
+        // through the file format
+        let snapshot = program.snapshot();
+        let mut file = Vec::new();
+        snapshot.write_to(&mut file).unwrap();
+        if Snapshot::read_from(&file[..]).ok() != Some(snapshot.clone()) {
+            return Err(("snapshot", "serialized snapshot differs".to_string()));
+        }
+        let mut restored = Program::from_snapshot(snapshot);
+        let mut restored_input = next_input;
+        let rest = run(&mut program, &case.inputs, &mut next_input, budget - half);
+        let restored = run(
+            &mut restored,
+            &case.inputs,
+            &mut restored_input,
+            budget - half,
+        );
+        if restored != rest {
+            return Err(("snapshot", format!("{:?} != {:?}", restored.end, rest.end)));
+        }
+        if [first.outputs, rest.outputs].concat() != outcome.outputs {
+            return Err(("snapshot", "outputs differ from a single run".to_string()));
+        }
+    }
+    Ok(())
+}
+
+// Check all invariants for case, panics are reported as findings too
+pub fn check_case(case: &Case, budget: usize) -> Option<Finding> {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| check_invariants(case, budget)));
+    let (invariant, detail) = match result {
+        Ok(Ok(())) => return None,
+        Ok(Err(err)) => err,
+        Err(payload) => {
+            let detail = payload
+                .downcast_ref::<&str>()
+                .map(|msg| msg.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            ("no panics", detail)
+        }
+    };
+    Some(Finding {
+        case: case.clone(),
+        invariant,
+        detail,
+    })
+}
+
+// Check num_cases cases, every fourth is a translated program, returns all findings
+pub fn fuzz(seed: u64, num_cases: usize, budget: usize) -> Vec<Finding> {
+    let mut rng = Rng::new(seed);
+    (0..num_cases)
+        .filter_map(|i| {
+            let case = if i % 4 == 0 {
+                Case::translated(&mut rng)
+            } else {
+                Case::random(&mut rng)
+            };
+            check_case(&case, budget)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(0);
+        let vals = (0..1000).map(|_| rng.range(-3, 3)).collect::<Vec<_>>();
+        assert!(vals.iter().all(|val| (-3..=3).contains(val)));
+        assert!((-3..=3).all(|val| vals.contains(&val)));
+        assert_eq!(Rng::new(7).next_u64(), Rng::new(7).next_u64());
+        Rng::new(1).range(i64::MIN, i64::MAX);
+    }
+
+    #[test]
+    fn test_run() {
+        // output the input + 1 until the input is 0
+        let case = Case {
+            mem: vec![3, 12, 1001, 12, 1, 13, 4, 13, 1005, 12, 0, 99, 0, 0],
+            inputs: vec![5, 6],
+            mem_limit: None,
+        };
+        let outcome = run(&mut case.program(), &case.inputs, &mut 0, 1000);
+        assert_eq!(outcome.outputs, vec![6, 7]);
+        assert_eq!(outcome.end, End::InputExhausted);
+        let outcome = run(&mut case.program(), &case.inputs, &mut 0, 4);
+        assert_eq!(outcome.end, End::BudgetExhausted);
+        assert_eq!(outcome.outputs, vec![6]);
+        assert_eq!(check_case(&case, 1000), None);
+    }
+
+    #[test]
+    fn test_native() {
+        // the unmodified quine, stepped through the native code
+        let case = Case {
+            mem: quine::TRANSLATED.image.to_vec(),
+            inputs: Vec::new(),
+            mem_limit: None,
+        };
+        let mut native = NativeProgram::new(case.mem.clone(), &quine::TRANSLATED);
+        let outcome = run(&mut native, &case.inputs, &mut 0, 1000);
+        assert_eq!(
+            outcome,
+            run(&mut case.program(), &case.inputs, &mut 0, 1000)
+        );
+        assert_eq!(outcome.outputs, case.mem);
+        assert_eq!(check_case(&case, 1000), None);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        let findings = fuzz(2019, 2000, 500);
+        for finding in &findings {
+            println!("{}", finding);
+        }
+        assert!(findings.is_empty());
+    }
+}
@@ -0,0 +1,290 @@
+use crate::memory::Memory;
+use crate::png::encode_png;
+use crate::trace::{Event, Tracer};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+// Memory accesses of a run
+//
+// AccessMap is a Tracer which counts reads, writes and executions per cell. Cells of an
+// executed instruction count as executed, its params are not counted as reads. From the
+// counts the address space is split into regions of code, written data and read only data
+// and rendered as heatmap, as text for the terminal or as PNG.
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Access {
+    pub reads: u64,
+    pub writes: u64,
+    pub execs: u64,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Kind {
+    Untouched,
+    Code,
+    Written,
+    Read,
+}
+
+impl Access {
+    pub fn total(&self) -> u64 {
+        self.reads + self.writes + self.execs
+    }
+
+    pub fn kind(&self) -> Kind {
+        if self.execs > 0 {
+            Kind::Code
+        } else if self.writes > 0 {
+            Kind::Written
+        } else if self.reads > 0 {
+            Kind::Read
+        } else {
+            Kind::Untouched
+        }
+    }
+}
+
+impl Kind {
+    fn symbol(self) -> char {
+        match self {
+            Kind::Untouched => '.',
+            Kind::Code => 'x',
+            Kind::Written => 'w',
+            Kind::Read => 'r',
+        }
+    }
+}
+
+// Run of cells with the same kind, start..end
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub start: usize,
+    pub end: usize,
+    pub kind: Kind,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AccessMap {
+    cells: BTreeMap<usize, Access>,
+}
+
+impl Tracer for AccessMap {
+    fn trace(&mut self, event: &Event) {
+        for addr in event.ip..event.ip + event.instr.size() {
+            self.cells.entry(addr).or_default().execs += 1;
+        }
+        for &addr in &event.reads {
+            self.cells.entry(addr).or_default().reads += 1;
+        }
+        if let Some((addr, _)) = event.write {
+            self.cells.entry(addr).or_default().writes += 1;
+        }
+    }
+}
+
+// Brightness 0..=255 of count, logarithmic up to max
+fn intensity(count: u64, max: u64) -> u8 {
+    if count == 0 {
+        return 0;
+    }
+    let scale = (count as f64).ln_1p() / (max as f64).ln_1p();
+    (64.0 + 191.0 * scale) as u8
+}
+
+impl AccessMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, addr: usize) -> Access {
+        self.cells.get(&addr).copied().unwrap_or_default()
+    }
+
+    // Addr behind the last accessed cell
+    pub fn end(&self) -> usize {
+        self.cells.keys().next_back().map_or(0, |addr| addr + 1)
+    }
+
+    // All accessed cells in addr order
+    pub fn cells(&self) -> impl Iterator<Item = (usize, Access)> + '_ {
+        self.cells.iter().map(|(&addr, &access)| (addr, access))
+    }
+
+    // Regions of accessed cells, untouched cells in between are not returned
+    pub fn regions(&self) -> Vec<Region> {
+        let mut regions = Vec::<Region>::new();
+        for (addr, access) in self.cells() {
+            let kind = access.kind();
+            match regions.last_mut() {
+                Some(region) if region.end == addr && region.kind == kind => region.end += 1,
+                _ => regions.push(Region {
+                    start: addr,
+                    end: addr + 1,
+                    kind,
+                }),
+            }
+        }
+        regions
+    }
+
+    // Heatmap of 0..end with width cells per row, rows without accesses are collapsed
+    //
+    // Every cell is shown by its kind: x code, w written, r read, . untouched. With color
+    // the background shows the number of accesses (ANSI 256 colors grayscale).
+    pub fn to_text(&self, end: usize, width: usize, color: bool) -> String {
+        let max = self.cells.values().map(Access::total).max().unwrap_or(0);
+        let mut out = String::new();
+        let mut skipped = false;
+        for row in (0..end).step_by(width) {
+            let cells = row..(row + width).min(end);
+            if self.cells.range(cells.clone()).next().is_none() {
+                if !skipped {
+                    writeln!(out, "     ...").unwrap();
+                }
+                skipped = true;
+                continue;
+            }
+            skipped = false;
+            write!(out, "{:04} ", row).unwrap();
+            for addr in cells {
+                let access = self.get(addr);
+                let symbol = access.kind().symbol();
+                if color && access.total() > 0 {
+                    let gray = 232 + intensity(access.total(), max) as u32 * 23 / 255;
+                    write!(out, "\x1b[48;5;{}m{}\x1b[0m", gray, symbol).unwrap();
+                } else {
+                    out.push(symbol);
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    // Heatmap of 0..end as PNG, one scale x scale square per cell and width cells per row
+    //
+    // Red shows writes, green executions and blue reads, each logarithmic up to the highest
+    // count of all cells.
+    pub fn to_png(&self, end: usize, width: usize, scale: usize) -> Vec<u8> {
+        let max = self
+            .cells
+            .values()
+            .map(|access| access.reads.max(access.writes).max(access.execs))
+            .max()
+            .unwrap_or(0);
+        let rows = end.div_ceil(width).max(1);
+        let (png_width, png_height) = (width * scale, rows * scale);
+        let mut rgb = vec![0; 3 * png_width * png_height];
+        for (addr, access) in self.cells.range(..end) {
+            let color = [
+                intensity(access.writes, max),
+                intensity(access.execs, max),
+                intensity(access.reads, max),
+            ];
+            let (x, y) = (addr % width * scale, addr / width * scale);
+            for dy in 0..scale {
+                for dx in 0..scale {
+                    let pixel = 3 * ((y + dy) * png_width + x + dx);
+                    rgb[pixel..pixel + 3].copy_from_slice(&color);
+                }
+            }
+        }
+        encode_png(png_width as u32, png_height as u32, &rgb)
+    }
+}
+
+// Cells of mem which differ from image as (addr, old, new), cells behind the image were 0
+pub fn diff(image: &[i64], mem: &Memory) -> Vec<(usize, i64, i64)> {
+    // untouched cells of mem read as 0, only the image and the cells of mem can differ
+    let behind = mem
+        .cells()
+        .map(|(addr, _)| addr)
+        .filter(|&addr| addr >= image.len());
+    (0..image.len())
+        .chain(behind)
+        .filter_map(|addr| {
+            let old = image.get(addr).copied().unwrap_or(0);
+            let new = mem.get(addr);
+            if old != new {
+                Some((addr, old, new))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::program::Program;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_access_map() {
+        // count [9] down from 2 to 0
+        let mem = vec![1001, 9, -1, 9, 1005, 9, 0, 99, 0, 2];
+        let mut program = Program::new(mem.clone());
+        let mut map = AccessMap::new();
+        program
+            .run_traced(&mut VecDeque::new(), &mut Vec::new(), &mut map)
+            .unwrap();
+        assert_eq!(
+            map.get(9),
+            Access {
+                reads: 4,
+                writes: 2,
+                execs: 0
+            }
+        );
+        assert_eq!(map.get(0).execs, 2);
+        assert_eq!(map.get(7).execs, 1);
+        assert_eq!(map.end(), 10);
+
+        let regions = map
+            .regions()
+            .iter()
+            .map(|region| (region.start, region.end, region.kind))
+            .collect::<Vec<_>>();
+        assert_eq!(regions, vec![(0, 8, Kind::Code), (9, 10, Kind::Written)]);
+        assert_eq!(diff(&mem, program.mem()), vec![(9, 2, 0)]);
+        assert_eq!(map.to_text(10, 8, false), "0000 xxxxxxxx\n0008 .w\n");
+    }
+
+    #[test]
+    fn test_heatmap() {
+        // write the input to 40, read it back and output it
+        let mem = vec![3, 40, 4, 40, 99];
+        let mut program = Program::new(mem.clone());
+        let mut map = AccessMap::new();
+        program
+            .run_traced(&mut VecDeque::from(vec![5]), &mut Vec::new(), &mut map)
+            .unwrap();
+        let regions = map.regions();
+        assert_eq!(
+            regions,
+            vec![
+                Region {
+                    start: 0,
+                    end: 5,
+                    kind: Kind::Code
+                },
+                Region {
+                    start: 40,
+                    end: 41,
+                    kind: Kind::Written
+                }
+            ]
+        );
+        assert_eq!(
+            map.to_text(48, 8, false),
+            "0000 xxxxx...\n     ...\n0040 w.......\n"
+        );
+        assert!(map.to_text(48, 8, true).contains("\x1b[48;5;"));
+        assert_eq!(diff(&mem, program.mem()), vec![(40, 0, 5)]);
+
+        let png = map.to_png(48, 8, 2);
+        // 16 x 12 pixels
+        assert_eq!(&png[16..24], &[0, 0, 0, 16, 0, 0, 0, 12]);
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::VecDeque;
+use std::sync::mpsc::{Receiver, Sender};
+
+// Input and output channels of a program
+//
+// - Input returns None if no more input is available
+// - Queues, closures, iterators, mpsc channels and stdin / stdout can be used as channels
+
+pub trait Input {
+    fn read(&mut self) -> Option<i64>;
+}
+
+pub trait Output {
+    fn write(&mut self, val: i64);
+}
+
+impl Input for VecDeque<i64> {
+    fn read(&mut self) -> Option<i64> {
+        self.pop_front()
+    }
+}
+
+impl Output for VecDeque<i64> {
+    fn write(&mut self, val: i64) {
+        self.push_back(val);
+    }
+}
+
+impl Output for Vec<i64> {
+    fn write(&mut self, val: i64) {
+        self.push(val);
+    }
+}
+
+// Blocks until a value is received, None if all senders are gone
+impl Input for Receiver<i64> {
+    fn read(&mut self) -> Option<i64> {
+        self.recv().ok()
+    }
+}
+
+// Values are dropped if the receiver is gone
+impl Output for Sender<i64> {
+    fn write(&mut self, val: i64) {
+        let _ = self.send(val);
+    }
+}
+
+// Input from a closure
+pub struct InputFn<F>(pub F);
+
+impl<F: FnMut() -> Option<i64>> Input for InputFn<F> {
+    fn read(&mut self) -> Option<i64> {
+        (self.0)()
+    }
+}
+
+// Output to a closure
+pub struct OutputFn<F>(pub F);
+
+impl<F: FnMut(i64)> Output for OutputFn<F> {
+    fn write(&mut self, val: i64) {
+        (self.0)(val)
+    }
+}
+
+// Input from an iterator
+pub struct InputIter<I>(pub I);
+
+impl<I: Iterator<Item = i64>> Input for InputIter<I> {
+    fn read(&mut self) -> Option<i64> {
+        self.0.next()
+    }
+}
+
+// Input from stdin (one value per line) and output to stdout
+//
+// The prompt goes to stderr to keep stdout for the outputs of the program. Lines which are not
+// a number are rejected and the prompt is repeated.
+pub struct Stdio;
+
+impl Input for Stdio {
+    fn read(&mut self) -> Option<i64> {
+        loop {
+            eprintln!("Enter input:");
+            let mut input_text = String::new();
+            match std::io::stdin().read_line(&mut input_text) {
+                Ok(0) | Err(_) => return None,
+                Ok(_) => match input_text.trim().parse() {
+                    Ok(val) => return Some(val),
+                    Err(_) => eprintln!("Not a number: {:?}", input_text.trim()),
+                },
+            }
+        }
+    }
+}
+
+impl Output for Stdio {
+    fn write(&mut self, val: i64) {
+        println!("{}", val);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_channels() {
+        let mut queue: VecDeque<i64> = vec![1, 2].into();
+        assert_eq!(queue.read(), Some(1));
+        queue.write(3);
+        assert_eq!(queue.read(), Some(2));
+        assert_eq!(queue.read(), Some(3));
+        assert_eq!(queue.read(), None);
+
+        let mut iter = InputIter(vec![4, 5].into_iter());
+        assert_eq!(iter.read(), Some(4));
+        assert_eq!(iter.read(), Some(5));
+        assert_eq!(iter.read(), None);
+
+        let mut count = 0;
+        let mut input = InputFn(|| {
+            count += 1;
+            Some(count)
+        });
+        assert_eq!(input.read(), Some(1));
+        assert_eq!(input.read(), Some(2));
+
+        let mut sum = 0;
+        let mut output = OutputFn(|val| sum += val);
+        output.write(6);
+        output.write(7);
+        assert_eq!(sum, 13);
+
+        let (mut tx, mut rx) = std::sync::mpsc::channel();
+        tx.write(8);
+        drop(tx);
+        assert_eq!(rx.read(), Some(8));
+        assert_eq!(rx.read(), None);
+    }
+}
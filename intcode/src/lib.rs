@@ -0,0 +1,61 @@
+// translated programs refer to the crate as intcode
+extern crate self as intcode;
+
+mod analysis;
+mod aot;
+mod ascii;
+mod asm;
+mod bigint;
+mod cached;
+#[cfg(test)]
+mod conformance;
+mod debugger;
+mod decompile;
+mod disasm;
+mod error;
+mod fuzz;
+mod heatmap;
+mod io;
+mod memory;
+mod native;
+mod network;
+mod op;
+mod patch;
+mod png;
+mod program;
+mod session;
+mod snapshot;
+mod symbolic;
+mod threaded;
+mod trace;
+mod word;
+
+pub use analysis::{analyze, Block, Cfg, CodeWrite, Edge};
+pub use aot::translate;
+pub use ascii::{encode_line, AsciiError, AsciiInput, AsciiOutput, Chunk};
+pub use asm::{assemble, to_program_string, AsmError};
+pub use bigint::{BigInt, ParseBigIntError};
+pub use cached::CachedProgram;
+pub use debugger::Debugger;
+pub use decompile::decompile;
+pub use disasm::{
+    decode, decode_mem, disassemble, disassemble_with_labels, find_code, Instr, Line, Listing,
+    Param,
+};
+pub use error::{Limit, VmError};
+pub use fuzz::{check_case, fuzz, random_image, random_inputs, Case, End, Finding, Outcome, Rng};
+pub use heatmap::{diff, Access, AccessMap, Kind, Region};
+pub use io::{Input, InputFn, InputIter, Output, OutputFn, Stdio};
+pub use memory::Memory;
+pub use native::{CodeGuard, Native, NativeFn, NativeProgram, Translated};
+pub use network::{permutations, AddMachineError, Network, NetworkError, NetworkState, Route};
+pub use op::{parse_opcode, Op, ParamMode};
+pub use patch::{Patch, PatchError, Poke};
+pub use png::encode_png;
+pub use program::{parse_program, Program, State};
+pub use session::{Divergence, Io, Record, Session};
+pub use snapshot::Snapshot;
+pub use symbolic::{run_symbolic, solve, Poly, Sym, SymError};
+pub use threaded::{ChannelInput, MachineExit, ThreadedNetwork};
+pub use trace::{Event, Profiler, TraceWriter, Tracer};
+pub use word::{to_words, Word};
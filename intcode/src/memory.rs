@@ -0,0 +1,202 @@
+use crate::word::Word;
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+
+// Memory of a program
+//
+// - The program image is stored as is
+// - Everything behind the image is stored in pages, which are allocated on first write
+// - Untouched cells read as 0
+// - An optional limit restricts the addressable space to 0..limit
+// - Cells are i64 by default, see Word for the other word types
+
+const PAGE_SIZE: usize = 1024;
+
+#[derive(Debug, Clone)]
+pub struct Memory<W = i64> {
+    image: Vec<W>,
+    pages: HashMap<usize, Box<[W]>>,
+    limit: Option<usize>,
+    // returned for untouched cells
+    zero: W,
+}
+
+impl<W: Word> Memory<W> {
+    pub fn new(image: Vec<W>) -> Self {
+        Self {
+            image,
+            pages: HashMap::new(),
+            limit: None,
+            zero: W::zero(),
+        }
+    }
+
+    pub fn with_limit(image: Vec<W>, limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Self::new(image)
+        }
+    }
+
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    pub fn in_bounds(&self, addr: usize) -> bool {
+        match self.limit {
+            Some(limit) => addr < limit,
+            None => true,
+        }
+    }
+
+    // Length of the image plus all allocated pages behind it, the pages in between may be
+    // untouched, see cells for the allocated cells only
+    pub fn len(&self) -> usize {
+        let pages_end = self
+            .pages
+            .keys()
+            .map(|page| (page + 1) * PAGE_SIZE)
+            .max()
+            .unwrap_or(0);
+        self.image.len() + pages_end
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, addr: usize) -> W {
+        self[addr].clone()
+    }
+
+    pub fn set(&mut self, addr: usize, val: W) {
+        self[addr] = val;
+    }
+
+    pub(crate) fn image(&self) -> &[W] {
+        &self.image
+    }
+
+    // Allocated pages as first addr and cells, sorted by addr
+    pub(crate) fn pages(&self) -> Vec<(usize, &[W])> {
+        let mut pages = self
+            .pages
+            .iter()
+            .map(|(page, cells)| (self.image.len() + page * PAGE_SIZE, &cells[..]))
+            .collect::<Vec<_>>();
+        pages.sort_by_key(|(addr, _)| *addr);
+        pages
+    }
+
+    // Cells of the image and of all allocated pages as (addr, val), sorted by addr
+    pub fn cells(&self) -> impl Iterator<Item = (usize, &W)> + '_ {
+        let pages = self.pages().into_iter().flat_map(|(start, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .map(move |(offset, val)| (start + offset, val))
+        });
+        self.image.iter().enumerate().chain(pages)
+    }
+}
+
+// Memories are equal if all cells have the same value, a page written with zeros equals an
+// untouched page
+impl<W: Word> PartialEq for Memory<W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells().all(|(addr, val)| *val == other[addr])
+            && other.cells().all(|(addr, val)| *val == self[addr])
+    }
+}
+
+impl<W: Word + Eq> Eq for Memory<W> {}
+
+impl<W: Word> Index<usize> for Memory<W> {
+    type Output = W;
+
+    fn index(&self, addr: usize) -> &W {
+        if addr < self.image.len() {
+            return &self.image[addr];
+        }
+        let offset = addr - self.image.len();
+        match self.pages.get(&(offset / PAGE_SIZE)) {
+            Some(page) => &page[offset % PAGE_SIZE],
+            None => &self.zero,
+        }
+    }
+}
+
+impl<W: Word> IndexMut<usize> for Memory<W> {
+    fn index_mut(&mut self, addr: usize) -> &mut W {
+        if addr < self.image.len() {
+            return &mut self.image[addr];
+        }
+        let offset = addr - self.image.len();
+        let page = self
+            .pages
+            .entry(offset / PAGE_SIZE)
+            .or_insert_with(|| vec![W::zero(); PAGE_SIZE].into_boxed_slice());
+        &mut page[offset % PAGE_SIZE]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_memory() {
+        let mut mem = Memory::new(vec![1, 2, 3]);
+        assert_eq!(mem.get(2), 3);
+        assert_eq!(mem.get(3), 0);
+        assert_eq!(mem[1_000_000_000], 0);
+        assert_eq!(mem.len(), 3);
+
+        mem.set(1, 5);
+        mem.set(1_000_000_000, 7);
+        assert_eq!(mem[1], 5);
+        assert_eq!(mem.get(1_000_000_000), 7);
+        assert_eq!(mem.get(1_000_000_001), 0);
+        assert_eq!(mem.pages.len(), 1);
+
+        let mut mem = Memory::new(vec![1, 2, 3]);
+        mem[4] = 9;
+        assert_eq!(mem.len(), 3 + PAGE_SIZE);
+        let cells = mem
+            .cells()
+            .map(|(addr, &val)| (addr, val))
+            .collect::<Vec<_>>();
+        assert_eq!(cells.len(), 3 + PAGE_SIZE);
+        assert_eq!(
+            &cells[..6],
+            &[(0, 1), (1, 2), (2, 3), (3, 0), (4, 9), (5, 0)]
+        );
+
+        // only the allocated pages are visited
+        mem.set(1_000_000_000, 7);
+        assert_eq!(mem.cells().count(), 3 + 2 * PAGE_SIZE);
+        assert!(mem.cells().any(|cell| cell == (1_000_000_000, &7)));
+    }
+
+    #[test]
+    fn test_memory_eq() {
+        let mut mem = Memory::new(vec![1, 2, 3]);
+        let untouched = mem.clone();
+        mem.set(5000, 0);
+        assert_eq!(mem, untouched);
+        assert_eq!(untouched, mem);
+        mem.set(5000, 1);
+        assert_ne!(mem, untouched);
+        assert_ne!(untouched, mem);
+        assert_ne!(Memory::new(vec![1, 2, 3]), Memory::new(vec![1, 2, 4]));
+        assert_eq!(Memory::new(vec![1, 0]), Memory::new(vec![1]));
+    }
+
+    #[test]
+    fn test_memory_limit() {
+        let mem = Memory::with_limit(vec![1, 2, 3], 10);
+        assert!(mem.in_bounds(9));
+        assert!(!mem.in_bounds(10));
+        assert!(Memory::<i64>::new(vec![]).in_bounds(usize::MAX));
+    }
+}
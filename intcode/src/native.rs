@@ -0,0 +1,292 @@
+use crate::error::VmError;
+use crate::io::{Input, Output};
+use crate::memory::Memory;
+use crate::op::{parse_opcode, ParamMode};
+use crate::program::{Program, State};
+use std::collections::VecDeque;
+
+// Execution engine for programs translated ahead of time, see translate
+//
+// The native code runs as long as it can and falls back to Program::step for a single
+// instruction otherwise. The CodeGuard tracks which translated instructions differ from
+// the translated image, those are always executed by the interpreter. Writes of the native
+// code go through the guard, for instructions executed by the interpreter the write addr
+// is marked before the step.
+//
+// Programs with a mem limit are always interpreted, the native code does not check it.
+
+pub enum Native {
+    Paused(State),
+    // the instruction at ip has to be executed by the interpreter
+    Fallback,
+}
+
+pub type NativeFn = fn(&mut Program, &mut CodeGuard) -> Native;
+
+// Output of translate
+pub struct Translated {
+    pub image: &'static [i64],
+    // addr and size of the translated instructions
+    pub instrs: &'static [(usize, usize)],
+    pub native: NativeFn,
+}
+
+pub struct CodeGuard {
+    image: &'static [i64],
+    instrs: &'static [(usize, usize)],
+    // cell is part of a translated instruction
+    code: Vec<bool>,
+    // size of the translated instruction starting at the cell, 0 if there is none
+    sizes: Vec<usize>,
+    modified: Vec<bool>,
+}
+
+impl CodeGuard {
+    fn new(translated: &Translated, mem: &Memory) -> Self {
+        let mut code = vec![false; translated.image.len()];
+        let mut sizes = vec![0; translated.image.len()];
+        for &(addr, size) in translated.instrs {
+            code[addr..addr + size]
+                .iter_mut()
+                .for_each(|cell| *cell = true);
+            sizes[addr] = size;
+        }
+        let mut guard = Self {
+            image: translated.image,
+            instrs: translated.instrs,
+            code,
+            sizes,
+            modified: vec![false; translated.image.len()],
+        };
+        guard.verify(mem);
+        guard
+    }
+
+    // Compare all translated instructions with mem
+    fn verify(&mut self, mem: &Memory) {
+        for &(addr, size) in self.instrs {
+            self.modified[addr] = (addr..addr + size).any(|cell| mem[cell] != self.image[cell]);
+        }
+    }
+
+    // Mark all translated instructions containing addr
+    fn mark(&mut self, addr: usize) {
+        if addr < self.code.len() && self.code[addr] {
+            for start in addr.saturating_sub(3)..=addr {
+                if self.sizes[start] > addr - start {
+                    self.modified[start] = true;
+                }
+            }
+        }
+    }
+
+    #[inline]
+    pub fn is_modified(&self, addr: usize) -> bool {
+        self.modified[addr]
+    }
+
+    #[inline]
+    pub fn write(&mut self, program: &mut Program, addr: usize, val: i64) {
+        program.mem_mut()[addr] = val;
+        self.mark(addr);
+    }
+}
+
+// Addr the instruction at ip writes to, None if it does not write or faults
+fn write_addr(program: &Program) -> Option<usize> {
+    let ip = program.ip();
+    let (op, param_mode_0, param_mode_1, param_mode_2) = parse_opcode(program.opcode(), ip).ok()?;
+    if !op.writes() {
+        return None;
+    }
+    let index = op.num_params() - 1;
+    let param = program.read((ip + 1 + index) as i64).ok()?;
+    let addr = match [param_mode_0, param_mode_1, param_mode_2][index] {
+        ParamMode::Addr => param,
+        ParamMode::Rel => program.rel_base().saturating_add(param),
+        ParamMode::Val => return None,
+    };
+    if addr < 0 {
+        None
+    } else {
+        Some(addr as usize)
+    }
+}
+
+pub struct NativeProgram {
+    program: Program,
+    guard: CodeGuard,
+    native: NativeFn,
+    // the program was changed from outside
+    dirty: bool,
+}
+
+fn resume_native(
+    program: &mut Program,
+    guard: &mut CodeGuard,
+    native: NativeFn,
+) -> Result<State, VmError> {
+    let interpret_only = program.mem().limit().is_some();
+    loop {
+        if !interpret_only {
+            if let Native::Paused(state) = native(program, guard) {
+                return Ok(state);
+            }
+        }
+        if let Some(addr) = write_addr(program) {
+            guard.mark(addr);
+        }
+        if let Some(state) = program.step()? {
+            return Ok(state);
+        }
+    }
+}
+
+impl NativeProgram {
+    pub fn new(mem: Vec<i64>, translated: &Translated) -> Self {
+        Self::from_program(Program::new(mem), translated)
+    }
+
+    // The program does not have to match the translated image, instructions which differ
+    // are interpreted
+    pub fn from_program(program: Program, translated: &Translated) -> Self {
+        let guard = CodeGuard::new(translated, program.mem());
+        Self {
+            program,
+            guard,
+            native: translated.native,
+            dirty: false,
+        }
+    }
+
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    // The translated instructions are verified again before the program continues
+    pub fn program_mut(&mut self) -> &mut Program {
+        self.dirty = true;
+        &mut self.program
+    }
+
+    // Budgets do not change the code, unlike program_mut
+    pub fn set_instruction_budget(&mut self, budget: u64) {
+        self.program.set_instruction_budget(budget);
+    }
+
+    pub fn clear_budgets(&mut self) {
+        self.program.clear_budgets();
+    }
+
+    pub fn into_program(self) -> Program {
+        self.program
+    }
+
+    pub fn mem(&self) -> &Memory {
+        self.program.mem()
+    }
+
+    pub fn push_input(&mut self, val: i64) {
+        self.program.push_input(val);
+    }
+
+    // Same as Program::resume
+    pub fn resume(&mut self) -> Result<State, VmError> {
+        if self.dirty {
+            self.guard.verify(self.program.mem());
+            self.dirty = false;
+        }
+        resume_native(&mut self.program, &mut self.guard, self.native)
+    }
+
+    // Same as Program::run
+    pub fn run<I, O>(&mut self, input: &mut I, output: &mut O) -> Result<(), VmError>
+    where
+        I: Input + ?Sized,
+        O: Output + ?Sized,
+    {
+        if self.dirty {
+            self.guard.verify(self.program.mem());
+            self.dirty = false;
+        }
+        let guard = &mut self.guard;
+        let native = self.native;
+        self.program.run_with(input, output, |program| {
+            resume_native(program, guard, native)
+        })
+    }
+
+    // Same as Program::run_collect
+    pub fn run_collect(&mut self, inputs: &[i64]) -> Result<Vec<i64>, VmError> {
+        let mut input = inputs.iter().copied().collect::<VecDeque<_>>();
+        let mut output = Vec::new();
+        self.run(&mut input, &mut output)?;
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod translated {
+    // The day inputs, generated with the aot tool and checked by aot::test::test_fixtures
+    pub mod day02 {
+        include!("../fixtures/day02.rs");
+    }
+    pub mod day05 {
+        include!("../fixtures/day05.rs");
+    }
+    pub mod day07 {
+        include!("../fixtures/day07.rs");
+    }
+    pub mod day09 {
+        include!("../fixtures/day09.rs");
+    }
+    pub mod day11 {
+        include!("../fixtures/day11.rs");
+    }
+    pub mod day13 {
+        include!("../fixtures/day13.rs");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::program::parse_program;
+
+    #[test]
+    fn test_self_modifying() {
+        let mem = parse_program(include_str!("../../day02/src/input.txt")).unwrap();
+        let mut native = NativeProgram::new(mem.clone(), &translated::day02::TRANSLATED);
+        let mut program = Program::new(mem);
+        // the noun and verb are params of the first instruction
+        for (addr, val) in &[(1, 12), (2, 2)] {
+            native.program_mut().mem_mut()[*addr] = *val;
+            program.mem_mut()[*addr] = *val;
+        }
+        assert_eq!(native.run_collect(&[]), program.run_collect(&[]));
+        assert_eq!(native.mem(), program.mem());
+        assert_eq!(native.mem()[0], 3_716_250);
+
+        // a different program with the same translation is interpreted
+        let mem = vec![1101, 103, 1, 4, 4, 0, 99];
+        let mut native = NativeProgram::new(mem.clone(), &translated::day02::TRANSLATED);
+        assert_eq!(native.run_collect(&[]), Ok(vec![0]));
+        assert_eq!(native.program().instruction_count(), 2);
+    }
+
+    #[test]
+    fn test_budget() {
+        let mem = parse_program(include_str!("../../day09/src/input.txt")).unwrap();
+        let mut native = NativeProgram::new(mem.clone(), &translated::day09::TRANSLATED);
+        let mut program = Program::new(mem);
+        native.program_mut().set_instruction_budget(10_000);
+        program.set_instruction_budget(10_000);
+        native.push_input(2);
+        program.push_input(2);
+        let err = program.resume();
+        assert!(matches!(err, Err(VmError::BudgetExhausted { .. })));
+        assert_eq!(native.resume(), err);
+        assert_eq!(native.program().ip(), program.ip());
+        assert_eq!(native.mem(), program.mem());
+    }
+}
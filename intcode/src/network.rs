@@ -0,0 +1,436 @@
+use crate::error::VmError;
+use crate::program::{Program, State};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+// Network of programs connected by named channels
+//
+// Every machine reads its inputs from one channel and sends its outputs along a route:
+//
+// - Route::Channel, every output is sent to the channel (chains, loops)
+// - Route::Broadcast, every output is sent to all channels
+// - Route::Packets(len), outputs are grouped into packets of len values, the first value
+//   is the addr, the remaining values are sent to the channel named like the addr
+// - Route::None, outputs are only recorded
+//
+// Machines are run round robin, each one until it halts, needs an input which is not
+// available or executed SLICE_LEN instructions. A machine with an idle input gets it
+// instead of blocking, then it is the next machine's turn. The network is run until all
+// machines halted, or a whole round passes without any output and without any consumed
+// channel value:
+//
+// - Deadlock, all machines which did not halt wait for input
+// - Quiescent, the remaining machines only consume idle inputs
+
+// Instructions per turn of a machine, a machine which does not wait for input can not starve
+// the others
+const SLICE_LEN: u64 = 100_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Route {
+    Channel(String),
+    Broadcast(Vec<String>),
+    Packets(usize),
+    None,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetworkState {
+    Halted,
+    Quiescent,
+    // names of the waiting machines
+    Deadlock(Vec<String>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkError {
+    pub machine: String,
+    pub err: VmError,
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Machine {}: {}", self.machine, self.err)
+    }
+}
+
+impl std::error::Error for NetworkError {}
+
+// Machine names have to be unique, in a ThreadedNetwork also the input channels
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddMachineError {
+    DuplicateName(String),
+    // the channel is already read by another machine
+    DuplicateInput(String),
+}
+
+impl fmt::Display for AddMachineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AddMachineError::DuplicateName(name) => write!(f, "Machine {} already exists", name),
+            AddMachineError::DuplicateInput(channel) => {
+                write!(f, "Channel {} is already read by another machine", channel)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AddMachineError {}
+
+impl Route {
+    // Pass the channels and values to send for the output val to send_fn
+    // packet collects the outputs of an incomplete packet
+    pub(crate) fn dispatch<F>(&self, packet: &mut Vec<i64>, val: i64, mut send_fn: F)
+    where
+        F: FnMut(&str, &[i64]),
+    {
+        match self {
+            Route::Channel(channel) => send_fn(channel, &[val]),
+            Route::Broadcast(targets) => {
+                for channel in targets {
+                    send_fn(channel, &[val]);
+                }
+            }
+            Route::Packets(len) => {
+                packet.push(val);
+                if packet.len() == *len {
+                    send_fn(&packet[0].to_string(), &packet[1..]);
+                    packet.clear();
+                }
+            }
+            Route::None => (),
+        }
+    }
+}
+
+struct Machine {
+    name: String,
+    program: Program,
+    input: String,
+    route: Route,
+    idle_input: Option<i64>,
+    halted: bool,
+    // outputs of an incomplete packet
+    packet: Vec<i64>,
+    outputs: Vec<i64>,
+}
+
+#[derive(Default)]
+pub struct Network {
+    machines: Vec<Machine>,
+    channels: HashMap<String, VecDeque<i64>>,
+}
+
+fn send(channels: &mut HashMap<String, VecDeque<i64>>, channel: &str, vals: &[i64]) {
+    channels
+        .entry(channel.to_string())
+        .or_default()
+        .extend(vals);
+}
+
+impl Machine {
+    fn route(&mut self, channels: &mut HashMap<String, VecDeque<i64>>, val: i64) {
+        self.outputs.push(val);
+        self.route.dispatch(&mut self.packet, val, |channel, vals| {
+            send(channels, channel, vals)
+        });
+    }
+
+    // Run until the machine halts, blocks or its slice is used up, returns true if it made
+    // progress
+    fn run_slice(
+        &mut self,
+        channels: &mut HashMap<String, VecDeque<i64>>,
+    ) -> Result<bool, VmError> {
+        let mut progress = false;
+        let end = self.program.instruction_count() + SLICE_LEN;
+        loop {
+            if let Some(queue) = channels.get_mut(&self.input) {
+                progress |= !queue.is_empty();
+                for val in queue.drain(..) {
+                    self.program.push_input(val);
+                }
+            }
+            let state = loop {
+                if self.program.instruction_count() >= end {
+                    // still running, it continues in the next round
+                    return Ok(true);
+                }
+                if let Some(state) = self.program.step()? {
+                    break state;
+                }
+            };
+            match state {
+                State::Output(val) => {
+                    self.route(channels, val);
+                    progress = true;
+                }
+                State::NeedsInput => {
+                    let pending = channels.get(&self.input).map_or(0, VecDeque::len);
+                    if pending > 0 {
+                        continue;
+                    }
+                    if let Some(val) = self.idle_input {
+                        self.program.push_input(val);
+                    }
+                    return Ok(progress);
+                }
+                State::Halted => {
+                    self.halted = true;
+                    return Ok(progress);
+                }
+            }
+        }
+    }
+}
+
+impl Network {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Add a machine which reads from the channel input and sends its outputs along route
+    pub fn add_machine(
+        &mut self,
+        name: &str,
+        program: Program,
+        input: &str,
+        route: Route,
+    ) -> Result<(), AddMachineError> {
+        if self.machines.iter().any(|m| m.name == name) {
+            return Err(AddMachineError::DuplicateName(name.to_string()));
+        }
+        self.machines.push(Machine {
+            name: name.to_string(),
+            program,
+            input: input.to_string(),
+            route,
+            idle_input: None,
+            halted: false,
+            packet: Vec::new(),
+            outputs: Vec::new(),
+        });
+        Ok(())
+    }
+
+    // The machine gets val whenever it waits for input and its channel is empty
+    pub fn set_idle_input(&mut self, name: &str, val: i64) {
+        if let Some(machine) = self.machines.iter_mut().find(|m| m.name == name) {
+            machine.idle_input = Some(val);
+        }
+    }
+
+    pub fn send(&mut self, channel: &str, val: i64) {
+        send(&mut self.channels, channel, &[val]);
+    }
+
+    // Values sent to the channel which were not consumed yet
+    pub fn channel(&self, channel: &str) -> Option<&VecDeque<i64>> {
+        self.channels.get(channel)
+    }
+
+    pub fn channel_mut(&mut self, channel: &str) -> &mut VecDeque<i64> {
+        self.channels.entry(channel.to_string()).or_default()
+    }
+
+    // All outputs of the machine so far
+    pub fn outputs(&self, name: &str) -> Option<&[i64]> {
+        self.machines
+            .iter()
+            .find(|m| m.name == name)
+            .map(|m| &m.outputs[..])
+    }
+
+    pub fn program(&self, name: &str) -> Option<&Program> {
+        self.machines
+            .iter()
+            .find(|m| m.name == name)
+            .map(|m| &m.program)
+    }
+
+    pub fn run(&mut self) -> Result<NetworkState, NetworkError> {
+        loop {
+            let mut progress = false;
+            for machine in self.machines.iter_mut().filter(|m| !m.halted) {
+                progress |= machine
+                    .run_slice(&mut self.channels)
+                    .map_err(|err| NetworkError {
+                        machine: machine.name.clone(),
+                        err,
+                    })?;
+            }
+
+            let running = self
+                .machines
+                .iter()
+                .filter(|m| !m.halted)
+                .collect::<Vec<_>>();
+            if running.is_empty() {
+                return Ok(NetworkState::Halted);
+            }
+            if progress {
+                continue;
+            }
+            if running.iter().all(|m| m.idle_input.is_none()) {
+                let waiting = running.iter().map(|m| m.name.clone()).collect();
+                return Ok(NetworkState::Deadlock(waiting));
+            }
+            return Ok(NetworkState::Quiescent);
+        }
+    }
+}
+
+// All orders of vals, e.g. the phase settings of a chain of amplifiers
+pub fn permutations(vals: &[i64]) -> Vec<Vec<i64>> {
+    if vals.len() <= 1 {
+        return vec![vals.to_vec()];
+    }
+    let mut result = Vec::new();
+    for i in 0..vals.len() {
+        let mut rest = vals.to_vec();
+        let first = rest.remove(i);
+        for mut permutation in permutations(&rest) {
+            permutation.insert(0, first);
+            result.push(permutation);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::asm::assemble;
+
+    fn program(src: &str) -> Program {
+        Program::new(assemble(src).unwrap())
+    }
+
+    #[test]
+    fn test_permutations() {
+        assert_eq!(permutations(&[]), vec![Vec::<i64>::new()]);
+        assert_eq!(
+            permutations(&[1, 2, 3]),
+            vec![
+                vec![1, 2, 3],
+                vec![1, 3, 2],
+                vec![2, 1, 3],
+                vec![2, 3, 1],
+                vec![3, 1, 2],
+                vec![3, 2, 1]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_loop() {
+        // output input + 1 until it is at least 5
+        let src = "
+            loop: in x
+                  add x, #1, x
+                  out x
+                  lt x, #5, more
+                  jt more, #loop
+                  hlt
+            x:    data 0
+            more: data 0
+        ";
+        let mut network = Network::new();
+        network
+            .add_machine("A", program(src), "a", Route::Channel("b".into()))
+            .unwrap();
+        network
+            .add_machine("B", program(src), "b", Route::Channel("a".into()))
+            .unwrap();
+        assert_eq!(
+            network.add_machine("A", program(src), "c", Route::None),
+            Err(AddMachineError::DuplicateName("A".to_string()))
+        );
+        network.send("a", 0);
+        assert_eq!(network.run(), Ok(NetworkState::Halted));
+        assert_eq!(network.outputs("A"), Some(&[1, 3, 5][..]));
+        assert_eq!(network.outputs("B"), Some(&[2, 4, 6][..]));
+        assert_eq!(network.channel("a"), Some(&VecDeque::from(vec![6])));
+    }
+
+    #[test]
+    fn test_deadlock() {
+        // output the sum of two inputs, forever
+        let src = "
+            loop: in a
+                  in b
+                  add a, b, a
+                  out a
+                  jt #1, #loop
+            a:    data 0
+            b:    data 0
+        ";
+        let mut network = Network::new();
+        let route = Route::Broadcast(vec!["x".into(), "y".into()]);
+        network
+            .add_machine("sum", program(src), "in", route)
+            .unwrap();
+        network.send("in", 1);
+        network.send("in", 2);
+        assert_eq!(
+            network.run(),
+            Ok(NetworkState::Deadlock(vec!["sum".to_string()]))
+        );
+        assert_eq!(network.channel("x"), Some(&VecDeque::from(vec![3])));
+        assert_eq!(network.channel("y"), Some(&VecDeque::from(vec![3])));
+    }
+
+    #[test]
+    fn test_packets() {
+        // send the packet (input, input * 2) for every input except -1
+        let src = "
+            loop: in addr
+                  eq addr, #-1, idle
+                  jt idle, #loop
+                  out addr
+                  mul addr, #2, addr
+                  out addr
+                  jt #1, #loop
+            addr: data 0
+            idle: data 0
+        ";
+        let mut network = Network::new();
+        network
+            .add_machine("router", program(src), "0", Route::Packets(2))
+            .unwrap();
+        network.set_idle_input("router", -1);
+        network.send("0", 7);
+        network.send("0", 3);
+        assert_eq!(network.run(), Ok(NetworkState::Quiescent));
+        assert_eq!(network.channel("7"), Some(&VecDeque::from(vec![14])));
+        assert_eq!(network.channel("3"), Some(&VecDeque::from(vec![6])));
+        assert_eq!(network.outputs("router"), Some(&[7, 14, 3, 6][..]));
+    }
+
+    #[test]
+    fn test_slices() {
+        // count down for more than a slice, B gets its turn in between
+        let busy = "
+            loop: add n, #-1, n
+                  jt n, #loop
+                  out #1
+                  hlt
+            n:    data 100000
+        ";
+        let mut network = Network::new();
+        network
+            .add_machine("A", program(busy), "a", Route::Channel("log".into()))
+            .unwrap();
+        network
+            .add_machine(
+                "B",
+                program("out #2\nhlt"),
+                "b",
+                Route::Channel("log".into()),
+            )
+            .unwrap();
+        assert_eq!(network.run(), Ok(NetworkState::Halted));
+        assert_eq!(network.channel("log"), Some(&VecDeque::from(vec![2, 1])));
+        assert_eq!(network.program("A").unwrap().instruction_count(), 200_001);
+    }
+}
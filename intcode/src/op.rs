@@ -0,0 +1,250 @@
+// Operations:
+//
+// - Halt has no params
+// - In / Out have one param, the addr to write to / read from
+// - Add / Mul have 3 params, lhs rhs output_addr
+// - JumpIfTrue / JumpIfFalse have 2 params, cmp dest, (set ip = dest, if cmp == true / false)
+// - Less / Equals, lhs rhs output_addr, (output = lhs cmp rhs)
+// - SetRelBase, val, adds val to the relative base
+//
+// Opcode has up to 5 digits:
+//
+// - First two digits are the actual op code
+// - Last three digits are the param mode from right to left (right most is first param mode)
+// - 0 means param specifies mem addr
+// - 1 means param is a direct value
+// - 2 means param specifies rel mem addr from rel base
+//
+// Omitted digits are 0 (output addr will always be 0 and probably be omitted), opcodes with
+// more than 5 digits are invalid
+
+use crate::error::VmError;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Op {
+    Add,
+    Mul,
+    In,
+    Out,
+    Halt,
+    JumpIfTrue,
+    JumpIfFalse,
+    Less,
+    Equals,
+    SetRelBase,
+}
+
+impl Op {
+    pub fn num_params(self) -> usize {
+        match self {
+            Op::Halt => 0,
+            Op::In | Op::Out | Op::SetRelBase => 1,
+            Op::JumpIfTrue | Op::JumpIfFalse => 2,
+            Op::Add | Op::Mul | Op::Less | Op::Equals => 3,
+        }
+    }
+
+    // last param is the addr to write to
+    pub fn writes(self) -> bool {
+        match self {
+            Op::Add | Op::Mul | Op::In | Op::Less | Op::Equals => true,
+            Op::Out | Op::Halt | Op::JumpIfTrue | Op::JumpIfFalse | Op::SetRelBase => false,
+        }
+    }
+
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Op::Add => "add",
+            Op::Mul => "mul",
+            Op::In => "in",
+            Op::Out => "out",
+            Op::Halt => "hlt",
+            Op::JumpIfTrue => "jt",
+            Op::JumpIfFalse => "jf",
+            Op::Less => "lt",
+            Op::Equals => "eq",
+            Op::SetRelBase => "arb",
+        }
+    }
+
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Op> {
+        match mnemonic.to_lowercase().as_str() {
+            "add" => Some(Op::Add),
+            "mul" => Some(Op::Mul),
+            "in" => Some(Op::In),
+            "out" => Some(Op::Out),
+            "hlt" => Some(Op::Halt),
+            "jt" => Some(Op::JumpIfTrue),
+            "jf" => Some(Op::JumpIfFalse),
+            "lt" => Some(Op::Less),
+            "eq" => Some(Op::Equals),
+            "arb" => Some(Op::SetRelBase),
+            _ => None,
+        }
+    }
+
+    pub fn code(self) -> i64 {
+        match self {
+            Op::Add => 1,
+            Op::Mul => 2,
+            Op::In => 3,
+            Op::Out => 4,
+            Op::JumpIfTrue => 5,
+            Op::JumpIfFalse => 6,
+            Op::Less => 7,
+            Op::Equals => 8,
+            Op::SetRelBase => 9,
+            Op::Halt => 99,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParamMode {
+    Addr,
+    Val,
+    Rel,
+}
+
+impl ParamMode {
+    pub fn code(self) -> i64 {
+        match self {
+            ParamMode::Addr => 0,
+            ParamMode::Val => 1,
+            ParamMode::Rel => 2,
+        }
+    }
+}
+
+fn to_op(val: i64, opcode: i64, addr: usize) -> Result<Op, VmError> {
+    match val {
+        1 => Ok(Op::Add),
+        2 => Ok(Op::Mul),
+        3 => Ok(Op::In),
+        4 => Ok(Op::Out),
+        5 => Ok(Op::JumpIfTrue),
+        6 => Ok(Op::JumpIfFalse),
+        7 => Ok(Op::Less),
+        8 => Ok(Op::Equals),
+        9 => Ok(Op::SetRelBase),
+        99 => Ok(Op::Halt),
+        _ => Err(VmError::InvalidOpcode { ip: addr, opcode }),
+    }
+}
+
+fn to_param_mode(val: i64, opcode: i64, addr: usize) -> Result<ParamMode, VmError> {
+    match val {
+        0 => Ok(ParamMode::Addr),
+        1 => Ok(ParamMode::Val),
+        2 => Ok(ParamMode::Rel),
+        _ => Err(VmError::InvalidMode {
+            ip: addr,
+            opcode,
+            mode: val,
+        }),
+    }
+}
+
+// parse opcode and extract op and param modes
+pub fn parse_opcode(
+    raw_opcode: i64,
+    addr: usize,
+) -> Result<(Op, ParamMode, ParamMode, ParamMode), VmError> {
+    if !(0..100_000).contains(&raw_opcode) {
+        return Err(VmError::InvalidOpcode {
+            ip: addr,
+            opcode: raw_opcode,
+        });
+    }
+    let op = to_op(raw_opcode % 100, raw_opcode, addr)?;
+    let param_mode_0 = to_param_mode(raw_opcode / 100 % 10, raw_opcode, addr)?;
+    let param_mode_1 = to_param_mode(raw_opcode / 1000 % 10, raw_opcode, addr)?;
+    let param_mode_2 = to_param_mode(raw_opcode / 10000 % 10, raw_opcode, addr)?;
+
+    Ok((op, param_mode_0, param_mode_1, param_mode_2))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_get_opcode() {
+        assert_eq!(
+            parse_opcode(1, 0),
+            Ok((Op::Add, ParamMode::Addr, ParamMode::Addr, ParamMode::Addr))
+        );
+        assert_eq!(
+            parse_opcode(2, 0),
+            Ok((Op::Mul, ParamMode::Addr, ParamMode::Addr, ParamMode::Addr))
+        );
+        assert_eq!(
+            parse_opcode(3, 0),
+            Ok((Op::In, ParamMode::Addr, ParamMode::Addr, ParamMode::Addr))
+        );
+        assert_eq!(
+            parse_opcode(4, 0),
+            Ok((Op::Out, ParamMode::Addr, ParamMode::Addr, ParamMode::Addr))
+        );
+        assert_eq!(
+            parse_opcode(99, 0),
+            Ok((Op::Halt, ParamMode::Addr, ParamMode::Addr, ParamMode::Addr))
+        );
+        assert_eq!(
+            parse_opcode(101, 0),
+            Ok((Op::Add, ParamMode::Val, ParamMode::Addr, ParamMode::Addr))
+        );
+        assert_eq!(
+            parse_opcode(1001, 0),
+            Ok((Op::Add, ParamMode::Addr, ParamMode::Val, ParamMode::Addr))
+        );
+        assert_eq!(
+            parse_opcode(1101, 0),
+            Ok((Op::Add, ParamMode::Val, ParamMode::Val, ParamMode::Addr))
+        );
+        assert_eq!(
+            parse_opcode(201, 0),
+            Ok((Op::Add, ParamMode::Rel, ParamMode::Addr, ParamMode::Addr))
+        );
+        assert_eq!(
+            parse_opcode(2001, 0),
+            Ok((Op::Add, ParamMode::Addr, ParamMode::Rel, ParamMode::Addr))
+        );
+        assert_eq!(
+            parse_opcode(2101, 0),
+            Ok((Op::Add, ParamMode::Val, ParamMode::Rel, ParamMode::Addr))
+        );
+        assert_eq!(
+            parse_opcode(20101, 0),
+            Ok((Op::Add, ParamMode::Val, ParamMode::Addr, ParamMode::Rel))
+        );
+    }
+
+    #[test]
+    fn test_get_opcode_invalid() {
+        assert_eq!(
+            parse_opcode(42, 7),
+            Err(VmError::InvalidOpcode { ip: 7, opcode: 42 })
+        );
+        assert_eq!(
+            parse_opcode(-1, 7),
+            Err(VmError::InvalidOpcode { ip: 7, opcode: -1 })
+        );
+        // a fourth param mode
+        assert_eq!(
+            parse_opcode(1_100_001, 7),
+            Err(VmError::InvalidOpcode {
+                ip: 7,
+                opcode: 1_100_001
+            })
+        );
+        assert_eq!(
+            parse_opcode(301, 7),
+            Err(VmError::InvalidMode {
+                ip: 7,
+                opcode: 301,
+                mode: 3
+            })
+        );
+    }
+}
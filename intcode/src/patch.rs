@@ -0,0 +1,286 @@
+use crate::memory::Memory;
+use crate::program::Program;
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+// Patches of program images
+//
+// A patch is a list of pokes, written as addr=value or, guarded by the value the image is
+// expected to have at addr, as addr=old->value:
+//
+//   1=12, 2=2        set noun and verb of day02
+//   0=1->2           insert quarters into the day13 arcade, only if addr 0 holds 1
+//
+// Pokes are separated by commas, whitespace or newlines, # starts a comment. All guards
+// are checked before anything is written, so a patch is applied completely or not at all.
+// Cells behind the image count as 0, pokes behind it extend the image by at most MAX_GROWTH
+// cells. Patches of a Memory are not limited, its pages are allocated on write.
+
+const MAX_GROWTH: usize = 1 << 16;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Poke {
+    pub addr: usize,
+    pub val: i64,
+    pub expected: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Patch {
+    pub pokes: Vec<Poke>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatchError {
+    InvalidPoke(String),
+    // the poke would extend the image by more than MAX_GROWTH cells
+    OutOfRange {
+        addr: usize,
+        len: usize,
+    },
+    // the image does not have the expected value, probably the patch is for another image
+    Mismatch {
+        addr: usize,
+        expected: i64,
+        actual: i64,
+    },
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PatchError::InvalidPoke(poke) => write!(f, "Invalid poke {:?}", poke),
+            PatchError::OutOfRange { addr, len } => write!(
+                f,
+                "Addr {} is too far behind the image of {} cells",
+                addr, len
+            ),
+            PatchError::Mismatch {
+                addr,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Expected {} at addr {}, the image has {}",
+                expected, addr, actual
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PatchError {}
+
+impl Patch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(mut self, addr: usize, val: i64) -> Self {
+        self.pokes.push(Poke {
+            addr,
+            val,
+            expected: None,
+        });
+        self
+    }
+
+    // Set addr to val, the image must have expected at addr
+    pub fn guarded(mut self, addr: usize, expected: i64, val: i64) -> Self {
+        self.pokes.push(Poke {
+            addr,
+            val,
+            expected: Some(expected),
+        });
+        self
+    }
+
+    // Apply to a program image
+    pub fn apply(&self, image: &mut Vec<i64>) -> Result<(), PatchError> {
+        let len = image.len();
+        if let Some(poke) = self.pokes.iter().find(|poke| poke.addr >= len + MAX_GROWTH) {
+            return Err(PatchError::OutOfRange {
+                addr: poke.addr,
+                len,
+            });
+        }
+        self.check(|addr| image.get(addr).copied().unwrap_or(0))?;
+        for poke in &self.pokes {
+            if poke.addr >= image.len() {
+                image.resize(poke.addr + 1, 0);
+            }
+            image[poke.addr] = poke.val;
+        }
+        Ok(())
+    }
+
+    // Apply to the mem of a loaded program
+    pub fn apply_mem(&self, mem: &mut Memory) -> Result<(), PatchError> {
+        self.check(|addr| mem.get(addr))?;
+        for poke in &self.pokes {
+            mem.set(poke.addr, poke.val);
+        }
+        Ok(())
+    }
+
+    fn check<F: Fn(usize) -> i64>(&self, get: F) -> Result<(), PatchError> {
+        for poke in &self.pokes {
+            if let Some(expected) = poke.expected {
+                let actual = get(poke.addr);
+                if actual != expected {
+                    return Err(PatchError::Mismatch {
+                        addr: poke.addr,
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        std::fs::read_to_string(path)?
+            .parse()
+            .map_err(|err: PatchError| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))
+    }
+
+    // Command line argument, @path loads a patch file, anything else is parsed as pokes
+    pub fn from_arg(arg: &str) -> io::Result<Self> {
+        match arg.strip_prefix('@') {
+            Some(path) => Self::load(path),
+            None => arg.parse().map_err(|err: PatchError| {
+                io::Error::new(io::ErrorKind::InvalidInput, err.to_string())
+            }),
+        }
+    }
+}
+
+fn parse_poke(text: &str) -> Option<Poke> {
+    let pos = text.find('=')?;
+    let addr = text[..pos].trim().parse().ok()?;
+    let rest = &text[pos + 1..];
+    let (expected, val) = match rest.find("->") {
+        Some(pos) => (Some(rest[..pos].trim().parse().ok()?), &rest[pos + 2..]),
+        None => (None, rest),
+    };
+    Some(Poke {
+        addr,
+        val: val.trim().parse().ok()?,
+        expected,
+    })
+}
+
+impl FromStr for Patch {
+    type Err = PatchError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut patch = Patch::new();
+        for line in s.lines() {
+            let line = match line.find('#') {
+                Some(pos) => &line[..pos],
+                None => line,
+            };
+            for poke in line
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|poke| !poke.is_empty())
+            {
+                match parse_poke(poke) {
+                    Some(poke) => patch.pokes.push(poke),
+                    None => return Err(PatchError::InvalidPoke(poke.to_string())),
+                }
+            }
+        }
+        Ok(patch)
+    }
+}
+
+impl fmt::Display for Patch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let pokes = self
+            .pokes
+            .iter()
+            .map(|poke| match poke.expected {
+                Some(expected) => format!("{}={}->{}", poke.addr, expected, poke.val),
+                None => format!("{}={}", poke.addr, poke.val),
+            })
+            .collect::<Vec<_>>();
+        write!(f, "{}", pokes.join(","))
+    }
+}
+
+impl Program {
+    // Load the image with the patch applied
+    pub fn with_patch(mem: Vec<i64>, patch: &Patch) -> Result<Self, PatchError> {
+        let mut program = Program::new(mem);
+        patch.apply_mem(program.mem_mut())?;
+        Ok(program)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let patch = "1=12, 2=2\n0=1->2 # quarters\n\n7=-1->-5".parse::<Patch>();
+        assert_eq!(
+            patch,
+            Ok(Patch::new()
+                .set(1, 12)
+                .set(2, 2)
+                .guarded(0, 1, 2)
+                .guarded(7, -1, -5))
+        );
+        assert_eq!(patch.unwrap().to_string(), "1=12,2=2,0=1->2,7=-1->-5");
+        assert_eq!("".parse(), Ok(Patch::new()));
+        for text in &["1", "x=1", "1=", "1=2->", "-1=2"] {
+            assert_eq!(
+                text.parse::<Patch>(),
+                Err(PatchError::InvalidPoke(text.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_apply() {
+        let patch = Patch::new().guarded(0, 1, 2).set(5, 7);
+        let mut image = vec![1, 0, 0];
+        patch.apply(&mut image).unwrap();
+        assert_eq!(image, vec![2, 0, 0, 0, 0, 7]);
+
+        // nothing is written if a guard fails
+        let mut image = vec![3, 0, 0];
+        assert_eq!(
+            patch.apply(&mut image),
+            Err(PatchError::Mismatch {
+                addr: 0,
+                expected: 1,
+                actual: 3
+            })
+        );
+        assert_eq!(image, vec![3, 0, 0]);
+
+        // pokes far behind the image are rejected, a Memory only allocates a page
+        for &addr in &[usize::MAX, 100_000_000_000] {
+            let patch = Patch::new().set(addr, 1);
+            assert_eq!(
+                patch.apply(&mut image),
+                Err(PatchError::OutOfRange { addr, len: 3 })
+            );
+            let program = Program::with_patch(vec![99], &patch).unwrap();
+            assert_eq!(program.mem().get(addr), 1);
+        }
+
+        let mut program = Program::with_patch(vec![1, 0, 0, 0, 99], &patch).unwrap();
+        assert_eq!(program.mem().get(5), 7);
+        patch.apply_mem(program.mem_mut()).unwrap_err();
+        Patch::new()
+            .guarded(5, 7, 8)
+            .apply_mem(program.mem_mut())
+            .unwrap();
+        assert_eq!(program.mem().get(5), 8);
+    }
+}
@@ -0,0 +1,113 @@
+// Minimal PNG encoder, 8 bit RGB without compression
+//
+// The zlib stream of the image data uses stored deflate blocks only, which is valid for
+// every decoder and needs no compressor. Good enough for small images like heatmaps.
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0_u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                0xedb8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+fn chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend(&(data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(&crc.to_be_bytes());
+}
+
+// zlib stream with stored blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(0xffff).peekable();
+    if blocks.peek().is_none() {
+        // a single empty final block
+        out.extend(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(last as u8);
+        out.extend(&len.to_le_bytes());
+        out.extend(&(!len).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(&adler32(data).to_be_bytes());
+    out
+}
+
+// PNG file of an image with rows of width RGB pixels, rgb.len() must be 3 * width * height
+pub fn encode_png(width: u32, height: u32, rgb: &[u8]) -> Vec<u8> {
+    assert_eq!(rgb.len(), 3 * width as usize * height as usize);
+    let mut out = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+    let mut header = Vec::with_capacity(13);
+    header.extend(&width.to_be_bytes());
+    header.extend(&height.to_be_bytes());
+    // bit depth 8, color type RGB, deflate, filter method 0, no interlace
+    header.extend(&[8, 2, 0, 0, 0]);
+    chunk(&mut out, b"IHDR", &header);
+
+    // every row starts with filter type 0 (none)
+    let mut raw = Vec::with_capacity(rgb.len() + height as usize);
+    if width > 0 {
+        for row in rgb.chunks(3 * width as usize) {
+            raw.push(0);
+            raw.extend(row);
+        }
+    }
+    chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+    chunk(&mut out, b"IEND", &[]);
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_encode_png() {
+        let png = encode_png(2, 1, &[255, 0, 0, 0, 0, 255]);
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[png.len() - 12..], b"\0\0\0\0IEND\xae\x42\x60\x82");
+        // IDAT: zlib header, final stored block of the 7 raw bytes, adler32
+        let idat = &png[33..];
+        assert_eq!(&idat[..8], b"\0\0\0\x12IDAT");
+        assert_eq!(&idat[8..15], &[0x78, 0x01, 1, 7, 0, 0xf8, 0xff]);
+        assert_eq!(&idat[15..22], &[0, 255, 0, 0, 0, 0, 255]);
+
+        // more than one stored block
+        let big = zlib_stored(&vec![7; 70_000]);
+        assert_eq!(big.len(), 2 + 2 * 5 + 70_000 + 4);
+        assert_eq!(big[2], 0);
+        assert_eq!(big[2 + 5 + 0xffff], 1);
+    }
+}
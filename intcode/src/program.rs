@@ -0,0 +1,607 @@
+use crate::error::{Limit, VmError};
+use crate::io::{Input, Output, Stdio};
+use crate::memory::Memory;
+use crate::op::{parse_opcode, Op, ParamMode};
+use crate::word::Word;
+use std::collections::VecDeque;
+use std::num::ParseIntError;
+use std::time::{Duration, Instant};
+
+// Notes:
+//
+// ip = instruction pointer
+// mem = memory
+// addr = address
+// param = parameter
+// val = value
+// op = operation
+
+// Parse a comma separated program image
+pub fn parse_program(input: &str) -> Result<Vec<i64>, ParseIntError> {
+    input
+        .trim()
+        .split(',')
+        .map(|val| val.trim().parse())
+        .collect()
+}
+
+// State of a program after it paused
+//
+// - NeedsInput, Op::In was reached and no input is pending, push an input and resume
+// - Output, Op::Out produced a value
+// - Halted, Op::Halt was reached, resuming again will stay halted
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum State<W = i64> {
+    NeedsInput,
+    Output(W),
+    Halted,
+}
+
+// The deadline of a time budget is checked every TIME_CHECK_INTERVAL instructions
+const TIME_CHECK_INTERVAL: u64 = 1024;
+
+// Programs are generic over the word type of their memory, Program is Program<i64>
+// Input, Output and the engines built on top of Program are only available for i64
+#[derive(Debug, Clone)]
+pub struct Program<W = i64> {
+    mem: Memory<W>,
+    ip: usize,
+    rel_base: i64,
+    input: VecDeque<W>,
+    // executed instructions
+    count: u64,
+    max_count: Option<u64>,
+    deadline: Option<Instant>,
+    // budgets are only checked when count reaches next_check
+    next_check: u64,
+}
+
+impl Program {
+    pub fn new(mem: Vec<i64>) -> Self {
+        Self::with_memory(Memory::new(mem))
+    }
+
+    // Restrict the addressable mem to 0..limit, accesses behind it fail with VmError::OutOfMemory
+    pub fn with_mem_limit(mem: Vec<i64>, limit: usize) -> Self {
+        Self::with_memory(Memory::with_limit(mem, limit))
+    }
+
+    // Run until halt, reads inputs from input and writes outputs to output
+    pub fn run<I, O>(&mut self, input: &mut I, output: &mut O) -> Result<(), VmError>
+    where
+        I: Input + ?Sized,
+        O: Output + ?Sized,
+    {
+        self.run_with(input, output, Program::resume)
+    }
+
+    // Same as run, but uses the given function to resume the program
+    pub(crate) fn run_with<I, O, F>(
+        &mut self,
+        input: &mut I,
+        output: &mut O,
+        mut resume: F,
+    ) -> Result<(), VmError>
+    where
+        I: Input + ?Sized,
+        O: Output + ?Sized,
+        F: FnMut(&mut Program) -> Result<State, VmError>,
+    {
+        loop {
+            match resume(self)? {
+                State::NeedsInput => match input.read() {
+                    Some(val) => self.push_input(val),
+                    None => {
+                        return Err(VmError::UnexpectedEof {
+                            ip: self.ip,
+                            opcode: self.opcode(),
+                        })
+                    }
+                },
+                State::Output(val) => output.write(val),
+                State::Halted => return Ok(()),
+            }
+        }
+    }
+
+    // Run until halt, reads inputs from stdin and prints outputs to stdout
+    pub fn run_stdio(&mut self) -> Result<(), VmError> {
+        self.run(&mut Stdio, &mut Stdio)
+    }
+}
+
+impl<W: Word> Program<W> {
+    pub fn with_memory(mem: Memory<W>) -> Self {
+        Self {
+            mem,
+            ip: 0,
+            rel_base: 0,
+            input: VecDeque::new(),
+            count: 0,
+            max_count: None,
+            deadline: None,
+            next_check: u64::MAX,
+        }
+    }
+
+    pub fn mem(&self) -> &Memory<W> {
+        &self.mem
+    }
+
+    pub fn mem_mut(&mut self) -> &mut Memory<W> {
+        &mut self.mem
+    }
+
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    pub fn set_ip(&mut self, ip: usize) {
+        self.ip = ip;
+    }
+
+    pub fn rel_base(&self) -> i64 {
+        self.rel_base
+    }
+
+    pub fn set_rel_base(&mut self, rel_base: i64) {
+        self.rel_base = rel_base;
+    }
+
+    // Add an input, which is consumed by the next Op::In
+    pub fn push_input(&mut self, val: W) {
+        self.input.push_back(val);
+    }
+
+    // Number of instructions executed so far, pauses are not counted
+    pub fn instruction_count(&self) -> u64 {
+        self.count
+    }
+
+    // Execute at most budget more instructions, then fail with VmError::BudgetExhausted
+    pub fn set_instruction_budget(&mut self, budget: u64) {
+        self.max_count = Some(self.count.saturating_add(budget));
+        self.update_next_check();
+    }
+
+    // Run for at most budget from now, then fail with VmError::BudgetExhausted
+    pub fn set_time_budget(&mut self, budget: Duration) {
+        self.deadline = Some(Instant::now() + budget);
+        self.update_next_check();
+    }
+
+    pub fn clear_budgets(&mut self) {
+        self.max_count = None;
+        self.deadline = None;
+        self.update_next_check();
+    }
+
+    fn update_next_check(&mut self) {
+        self.next_check = match self.deadline {
+            // check the deadline right away
+            Some(_) => self.count,
+            None => self.max_count.unwrap_or(u64::MAX),
+        };
+    }
+
+    // Called before an instruction is executed, the program stays at the instruction
+    // Public for translated programs, like count_instruction and pop_input
+    #[inline]
+    pub fn check_budgets(&mut self) -> Result<(), VmError> {
+        if self.count < self.next_check {
+            return Ok(());
+        }
+        self.check_budgets_slow()
+    }
+
+    #[cold]
+    fn check_budgets_slow(&mut self) -> Result<(), VmError> {
+        let limit = if self.max_count.is_some_and(|max| self.count >= max) {
+            Limit::Instructions
+        } else if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            Limit::Time
+        } else {
+            let time_check = self.count.saturating_add(TIME_CHECK_INTERVAL);
+            self.next_check = self.max_count.unwrap_or(u64::MAX).min(time_check);
+            return Ok(());
+        };
+        Err(VmError::BudgetExhausted {
+            ip: self.ip,
+            opcode: self.opcode(),
+            limit,
+        })
+    }
+
+    #[inline]
+    pub fn count_instruction(&mut self) {
+        self.count += 1;
+    }
+
+    // Run until halt with the given inputs and collect all outputs
+    pub fn run_collect(&mut self, inputs: &[W]) -> Result<Vec<W>, VmError> {
+        let mut inputs = inputs.iter();
+        let mut output = Vec::new();
+        loop {
+            match self.resume()? {
+                State::NeedsInput => match inputs.next() {
+                    Some(val) => self.push_input(val.clone()),
+                    None => {
+                        return Err(VmError::UnexpectedEof {
+                            ip: self.ip,
+                            opcode: self.opcode(),
+                        })
+                    }
+                },
+                State::Output(val) => output.push(val),
+                State::Halted => return Ok(output),
+            }
+        }
+    }
+
+    pub(crate) fn input(&self) -> &VecDeque<W> {
+        &self.input
+    }
+
+    pub(crate) fn input_mut(&mut self) -> &mut VecDeque<W> {
+        &mut self.input
+    }
+
+    pub fn pop_input(&mut self) -> Option<W> {
+        self.input.pop_front()
+    }
+
+    // Raw opcode at ip, used for error reporting
+    pub(crate) fn opcode(&self) -> i64 {
+        self.mem[self.ip].saturating_to_i64()
+    }
+
+    pub(crate) fn to_addr(&self, addr: i64) -> Result<usize, VmError> {
+        if addr < 0 {
+            Err(VmError::NegativeAddress {
+                ip: self.ip,
+                opcode: self.opcode(),
+                addr,
+            })
+        } else if !self.mem.in_bounds(addr as usize) {
+            Err(VmError::OutOfMemory {
+                ip: self.ip,
+                opcode: self.opcode(),
+                addr,
+            })
+        } else {
+            Ok(addr as usize)
+        }
+    }
+
+    pub(crate) fn read(&self, addr: i64) -> Result<W, VmError> {
+        Ok(self.mem.get(self.to_addr(addr)?))
+    }
+
+    fn get_param_val(&self, addr: usize, param_mode: ParamMode) -> Result<W, VmError> {
+        let param = self.read(addr as i64)?;
+        match param_mode {
+            ParamMode::Addr => self.read(param.saturating_to_i64()),
+            ParamMode::Val => Ok(param),
+            ParamMode::Rel => self.read(self.rel_base.saturating_add(param.saturating_to_i64())),
+        }
+    }
+
+    fn get_result_addr(&self, addr: usize, param_mode: ParamMode) -> Result<usize, VmError> {
+        let param = self.read(addr as i64)?.saturating_to_i64();
+        match param_mode {
+            ParamMode::Addr => self.to_addr(param),
+            ParamMode::Rel => self.to_addr(self.rel_base.saturating_add(param)),
+            ParamMode::Val => Err(VmError::WriteToImmediate {
+                ip: self.ip,
+                opcode: self.opcode(),
+            }),
+        }
+    }
+
+    fn get_jump_target(&self, addr: usize, param_mode: ParamMode) -> Result<usize, VmError> {
+        let new_ip = self.get_param_val(addr, param_mode)?.saturating_to_i64();
+        if new_ip < 0 {
+            return Err(VmError::NegativeAddress {
+                ip: self.ip,
+                opcode: self.opcode(),
+                addr: new_ip,
+            });
+        }
+        Ok(new_ip as usize)
+    }
+
+    // Run until input is needed, an output is produced or the program halts
+    pub fn resume(&mut self) -> Result<State<W>, VmError> {
+        loop {
+            if let Some(state) = self.step()? {
+                return Ok(state);
+            }
+        }
+    }
+
+    // Execute a single instruction, returns Some(State) if the program paused
+    // On State::NeedsInput and State::Halted ip is not advanced
+    pub fn step(&mut self) -> Result<Option<State<W>>, VmError> {
+        let opcode = self.read(self.ip as i64)?.saturating_to_i64();
+        let (op, param_mode_0, param_mode_1, param_mode_2) = parse_opcode(opcode, self.ip)?;
+        if op != Op::Halt {
+            self.check_budgets()?;
+        }
+        match op {
+            Op::Add => {
+                let lhs = self.get_param_val(self.ip + 1, param_mode_0)?;
+                let rhs = self.get_param_val(self.ip + 2, param_mode_1)?;
+                let result_addr = self.get_result_addr(self.ip + 3, param_mode_2)?;
+                self.mem[result_addr] = lhs.checked_add(&rhs).ok_or(VmError::Overflow {
+                    ip: self.ip,
+                    opcode,
+                })?;
+                self.ip += 4;
+            }
+            Op::Mul => {
+                let lhs = self.get_param_val(self.ip + 1, param_mode_0)?;
+                let rhs = self.get_param_val(self.ip + 2, param_mode_1)?;
+                let result_addr = self.get_result_addr(self.ip + 3, param_mode_2)?;
+                self.mem[result_addr] = lhs.checked_mul(&rhs).ok_or(VmError::Overflow {
+                    ip: self.ip,
+                    opcode,
+                })?;
+                self.ip += 4;
+            }
+            Op::In => {
+                let result_addr = self.get_result_addr(self.ip + 1, param_mode_0)?;
+                let val = match self.input.pop_front() {
+                    Some(val) => val,
+                    None => return Ok(Some(State::NeedsInput)),
+                };
+                self.mem[result_addr] = val;
+                self.ip += 2;
+            }
+            Op::Out => {
+                let val = self.get_param_val(self.ip + 1, param_mode_0)?;
+                self.ip += 2;
+                self.count += 1;
+                return Ok(Some(State::Output(val)));
+            }
+            Op::JumpIfTrue => {
+                let val = self.get_param_val(self.ip + 1, param_mode_0)?;
+                let new_ip = self.get_jump_target(self.ip + 2, param_mode_1)?;
+                if !val.is_zero() {
+                    self.ip = new_ip;
+                } else {
+                    self.ip += 3;
+                }
+            }
+            Op::JumpIfFalse => {
+                let val = self.get_param_val(self.ip + 1, param_mode_0)?;
+                let new_ip = self.get_jump_target(self.ip + 2, param_mode_1)?;
+                if val.is_zero() {
+                    self.ip = new_ip;
+                } else {
+                    self.ip += 3;
+                }
+            }
+            Op::Less => {
+                let lhs = self.get_param_val(self.ip + 1, param_mode_0)?;
+                let rhs = self.get_param_val(self.ip + 2, param_mode_1)?;
+                let result_addr = self.get_result_addr(self.ip + 3, param_mode_2)?;
+                self.mem[result_addr] = if lhs < rhs { W::one() } else { W::zero() };
+                self.ip += 4;
+            }
+            Op::Equals => {
+                let lhs = self.get_param_val(self.ip + 1, param_mode_0)?;
+                let rhs = self.get_param_val(self.ip + 2, param_mode_1)?;
+                let result_addr = self.get_result_addr(self.ip + 3, param_mode_2)?;
+                self.mem[result_addr] = if lhs == rhs { W::one() } else { W::zero() };
+                self.ip += 4;
+            }
+            Op::SetRelBase => {
+                let val = self.get_param_val(self.ip + 1, param_mode_0)?;
+                self.rel_base = self.rel_base.saturating_add(val.saturating_to_i64());
+                self.ip += 2;
+            }
+            Op::Halt => return Ok(Some(State::Halted)),
+        }
+        self.count += 1;
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bigint::BigInt;
+    use crate::word::to_words;
+
+    #[test]
+    fn test_run_day02_example() {
+        let mut program = Program::new(vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50]);
+        assert_eq!(program.resume(), Ok(State::Halted));
+        assert_eq!(program.mem()[0], 3500);
+    }
+
+    #[test]
+    fn test_resume() {
+        // output 1 if input == 8, else 0
+        let mem = vec![3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8];
+        let mut program = Program::new(mem);
+        assert_eq!(program.resume(), Ok(State::NeedsInput));
+        assert_eq!(program.ip(), 0);
+        program.push_input(8);
+        assert_eq!(program.resume(), Ok(State::Output(1)));
+        assert_eq!(program.resume(), Ok(State::Halted));
+        assert_eq!(program.resume(), Ok(State::Halted));
+    }
+
+    #[test]
+    fn test_run_collect() {
+        // output the input twice, then halt
+        let mem = vec![3, 11, 4, 11, 3, 11, 4, 11, 99];
+        let mut program = Program::new(mem);
+        assert_eq!(program.run_collect(&[1, 2]), Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn test_rel_base() {
+        let mem = vec![109, 5, 204, -1, 99, 42];
+        let mut program = Program::new(mem);
+        assert_eq!(program.resume(), Ok(State::Output(99)));
+        assert_eq!(program.rel_base(), 5);
+        assert_eq!(program.resume(), Ok(State::Halted));
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut program = Program::new(vec![1, 0, 0, 0, 42]);
+        assert_eq!(
+            program.resume(),
+            Err(VmError::InvalidOpcode { ip: 4, opcode: 42 })
+        );
+        assert_eq!(program.ip(), 4);
+
+        let mut program = Program::new(vec![11101, 1, 1, 0, 99]);
+        assert_eq!(
+            program.resume(),
+            Err(VmError::WriteToImmediate {
+                ip: 0,
+                opcode: 11101
+            })
+        );
+
+        let mut program = Program::new(vec![204, -1, 99]);
+        assert_eq!(
+            program.resume(),
+            Err(VmError::NegativeAddress {
+                ip: 0,
+                opcode: 204,
+                addr: -1
+            })
+        );
+
+        let mut program = Program::with_mem_limit(vec![4, 100, 99], 100);
+        assert_eq!(
+            program.resume(),
+            Err(VmError::OutOfMemory {
+                ip: 0,
+                opcode: 4,
+                addr: 100
+            })
+        );
+
+        let mut program = Program::new(vec![3, 0, 99]);
+        assert_eq!(
+            program.run_collect(&[]),
+            Err(VmError::UnexpectedEof { ip: 0, opcode: 3 })
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mem = vec![1102, i64::MAX, 2, 0, 99];
+        let mut program = Program::new(mem.clone());
+        assert_eq!(
+            program.resume(),
+            Err(VmError::Overflow {
+                ip: 0,
+                opcode: 1102
+            })
+        );
+
+        let mut program = Program::with_memory(Memory::new(to_words::<BigInt>(&mem).unwrap()));
+        assert_eq!(program.resume(), Ok(State::Halted));
+        assert_eq!(program.mem()[0].to_string(), "18446744073709551614");
+
+        let mut program = Program::with_memory(Memory::new(vec![1101, i32::MAX, 1, 0, 99]));
+        assert_eq!(
+            program.resume(),
+            Err(VmError::Overflow {
+                ip: 0,
+                opcode: 1101
+            })
+        );
+    }
+
+    #[test]
+    fn test_words() {
+        // day09 examples: quine, 16 digit number, large number
+        let quine = vec![
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+        let mut program = Program::with_memory(Memory::new(to_words::<BigInt>(&quine).unwrap()));
+        assert_eq!(program.run_collect(&[]), Ok(to_words(&quine).unwrap()));
+        for (mem, output) in &[
+            (
+                vec![1102, 34_915_192, 34_915_192, 7, 4, 7, 99, 0],
+                "1219070632396864",
+            ),
+            (vec![104, 1_125_899_906_842_624, 99], "1125899906842624"),
+        ] {
+            let mut program = Program::with_memory(Memory::new(to_words::<BigInt>(mem).unwrap()));
+            let outputs = program.run_collect(&[]).unwrap();
+            assert_eq!(outputs.len(), 1);
+            assert_eq!(outputs[0].to_string(), *output);
+        }
+
+        // day05 fits into i32
+        let mem = parse_program(include_str!("../../day05/src/input.txt")).unwrap();
+        let mut program = Program::with_memory(Memory::new(to_words::<i32>(&mem).unwrap()));
+        let outputs = program.run_collect(&[5]).unwrap();
+        assert_eq!(outputs, vec![9_265_694]);
+
+        // the BOOST self test overflows i32 right away, with big ints it gives the same result
+        let mem = parse_program(include_str!("../../day09/src/input.txt")).unwrap();
+        let mut program = Program::with_memory(Memory::new(to_words::<i32>(&mem).unwrap()));
+        assert_eq!(
+            program.run_collect(&[1]),
+            Err(VmError::Overflow {
+                ip: 0,
+                opcode: 1102
+            })
+        );
+        let mut program = Program::with_memory(Memory::new(to_words::<BigInt>(&mem).unwrap()));
+        let outputs = program.run_collect(&[BigInt::from(1)]).unwrap();
+        let expected = Program::new(mem).run_collect(&[1]).unwrap();
+        assert_eq!(outputs, to_words::<BigInt>(&expected).unwrap());
+    }
+
+    #[test]
+    fn test_budget() {
+        // loop forever
+        let mut program = Program::new(vec![1105, 1, 0]);
+        program.set_instruction_budget(10);
+        let exhausted = VmError::BudgetExhausted {
+            ip: 0,
+            opcode: 1105,
+            limit: Limit::Instructions,
+        };
+        assert_eq!(program.resume(), Err(exhausted));
+        assert_eq!(program.instruction_count(), 10);
+        assert_eq!(program.resume(), Err(exhausted));
+        program.set_instruction_budget(5);
+        assert_eq!(program.run_collect(&[]), Err(exhausted));
+        assert_eq!(program.instruction_count(), 15);
+
+        program.clear_budgets();
+        program.set_time_budget(Duration::from_millis(10));
+        let err = program.resume().unwrap_err();
+        assert_eq!(
+            err,
+            VmError::BudgetExhausted {
+                ip: 0,
+                opcode: 1105,
+                limit: Limit::Time
+            }
+        );
+
+        // pauses are not counted, halt is not limited
+        let mut program = Program::new(vec![3, 0, 4, 0, 99]);
+        program.set_instruction_budget(2);
+        assert_eq!(program.resume(), Ok(State::NeedsInput));
+        assert_eq!(program.run_collect(&[7]), Ok(vec![7]));
+        assert_eq!(program.instruction_count(), 2);
+        assert_eq!(program.resume(), Ok(State::Halted));
+    }
+}
@@ -0,0 +1,330 @@
+use crate::error::VmError;
+use crate::io::{Input, Output};
+use crate::program::{Program, State};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+// Record and replay of the I/O of a program
+//
+// A session is the list of all inputs consumed and outputs produced by a run, each with
+// the instruction count at which it happened, and how the run ended. Replaying a session
+// runs the program again with the recorded inputs and compares every event, the first
+// difference is reported as Divergence.
+//
+// Saved sessions are text files, one record per line:
+//
+//   intcode-session 1
+//   12 in 5
+//   345 out 999
+//   350 halt
+//
+// A run which did not halt ends with a fault line with the error message, e.g.
+// `7 fault No input available for opcode 3 at addr 2`. Inputs pushed into the program before
+// the run are not recorded.
+
+const HEADER: &str = "intcode-session 1";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Io {
+    Input(i64),
+    Output(i64),
+    Halt,
+    Fault(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    // instruction count of the program, for inputs before the consuming instruction
+    pub count: u64,
+    pub io: Io,
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.io {
+            Io::Input(val) => write!(f, "{} in {}", self.count, val),
+            Io::Output(val) => write!(f, "{} out {}", self.count, val),
+            Io::Halt => write!(f, "{} halt", self.count),
+            Io::Fault(msg) => write!(f, "{} fault {}", self.count, msg),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Session {
+    pub records: Vec<Record>,
+}
+
+// First difference between a replay and the recorded session
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    // index of the record
+    pub index: usize,
+    pub expected: Record,
+    pub actual: Record,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Divergence at record {}: expected {:?}, got {:?}",
+            self.index,
+            self.expected.to_string(),
+            self.actual.to_string()
+        )
+    }
+}
+
+fn invalid_data<T>(msg: String) -> io::Result<T> {
+    Err(io::Error::new(io::ErrorKind::InvalidData, msg))
+}
+
+fn parse_record(line: &str) -> Option<Record> {
+    let mut parts = line.splitn(3, ' ');
+    let count = parts.next()?.parse().ok()?;
+    let io = match (parts.next()?, parts.next()) {
+        ("in", Some(val)) => Io::Input(val.parse().ok()?),
+        ("out", Some(val)) => Io::Output(val.parse().ok()?),
+        ("halt", None) => Io::Halt,
+        ("fault", Some(msg)) => Io::Fault(msg.to_string()),
+        _ => return None,
+    };
+    Some(Record { count, io })
+}
+
+impl Session {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn push(&mut self, count: u64, io: Io) {
+        self.records.push(Record { count, io });
+    }
+
+    // Recorded inputs in order
+    pub fn inputs(&self) -> Vec<i64> {
+        self.records
+            .iter()
+            .filter_map(|record| match record.io {
+                Io::Input(val) => Some(val),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "{}", HEADER)?;
+        for record in &self.records {
+            writeln!(writer, "{}", record)?;
+        }
+        writer.flush()
+    }
+
+    pub fn read_from<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut lines = reader.lines();
+        match lines.next() {
+            Some(Ok(line)) if line.trim() == HEADER => (),
+            Some(Err(err)) => return Err(err),
+            _ => return invalid_data(format!("Missing header {:?}", HEADER)),
+        }
+        let mut session = Session::new();
+        for line in lines {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            match parse_record(line) {
+                Some(record) => session.records.push(record),
+                None => return invalid_data(format!("Invalid record {:?}", line)),
+            }
+        }
+        Ok(session)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.write_to(BufWriter::new(File::create(path)?))
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::read_from(BufReader::new(File::open(path)?))
+    }
+}
+
+impl Program {
+    // Same as run, all inputs read from input and all outputs are recorded into session
+    pub fn run_recorded<I, O>(
+        &mut self,
+        input: &mut I,
+        output: &mut O,
+        session: &mut Session,
+    ) -> Result<(), VmError>
+    where
+        I: Input + ?Sized,
+        O: Output + ?Sized,
+    {
+        let result = self.record(input, output, session);
+        if let Err(err) = &result {
+            session.push(self.instruction_count(), Io::Fault(err.to_string()));
+        }
+        result
+    }
+
+    fn record<I, O>(
+        &mut self,
+        input: &mut I,
+        output: &mut O,
+        session: &mut Session,
+    ) -> Result<(), VmError>
+    where
+        I: Input + ?Sized,
+        O: Output + ?Sized,
+    {
+        loop {
+            match self.resume()? {
+                State::NeedsInput => match input.read() {
+                    Some(val) => {
+                        session.push(self.instruction_count(), Io::Input(val));
+                        self.push_input(val);
+                    }
+                    None => return Err(self.eof()),
+                },
+                State::Output(val) => {
+                    session.push(self.instruction_count(), Io::Output(val));
+                    output.write(val);
+                }
+                State::Halted => {
+                    session.push(self.instruction_count(), Io::Halt);
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    fn eof(&self) -> VmError {
+        VmError::UnexpectedEof {
+            ip: self.ip(),
+            opcode: self.opcode(),
+        }
+    }
+
+    // Run with the inputs of session until its last record, every output, the input
+    // requests and the end of the run have to match the session
+    pub fn replay(&mut self, session: &Session) -> Result<(), Divergence> {
+        for (index, expected) in session.records.iter().enumerate() {
+            let io = match self.resume() {
+                Ok(State::NeedsInput) => match expected.io {
+                    Io::Input(val) => {
+                        self.push_input(val);
+                        Io::Input(val)
+                    }
+                    // the recorded run got no more input
+                    _ => Io::Fault(self.eof().to_string()),
+                },
+                Ok(State::Output(val)) => Io::Output(val),
+                Ok(State::Halted) => Io::Halt,
+                Err(err) => Io::Fault(err.to_string()),
+            };
+            let actual = Record {
+                count: self.instruction_count(),
+                io,
+            };
+            if actual != *expected {
+                return Err(Divergence {
+                    index,
+                    expected: expected.clone(),
+                    actual,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::program::parse_program;
+    use std::collections::VecDeque;
+
+    // output the input + 1 until the input is 0
+    const ECHO: [i64; 14] = [3, 12, 1001, 12, 1, 13, 4, 13, 1005, 12, 0, 99, 0, 0];
+
+    #[test]
+    fn test_record() {
+        let mut session = Session::new();
+        let mut output = Vec::new();
+        let result = Program::new(ECHO.to_vec()).run_recorded(
+            &mut VecDeque::from(vec![5, 0]),
+            &mut output,
+            &mut session,
+        );
+        assert_eq!(result, Ok(()));
+        assert_eq!(output, vec![6, 1]);
+        assert_eq!(session.inputs(), vec![5, 0]);
+        let mut text = Vec::new();
+        session.write_to(&mut text).unwrap();
+        assert_eq!(
+            String::from_utf8(text.clone()).unwrap(),
+            "intcode-session 1\n0 in 5\n3 out 6\n4 in 0\n7 out 1\n8 halt\n"
+        );
+        assert_eq!(Session::read_from(&text[..]).unwrap(), session);
+        assert!(Session::read_from(&b"intcode-session 1\n1 jump\n"[..]).is_err());
+
+        // running out of input is recorded as fault
+        let mut session = Session::new();
+        let result = Program::new(ECHO.to_vec()).run_recorded(
+            &mut VecDeque::from(vec![5]),
+            &mut Vec::new(),
+            &mut session,
+        );
+        assert_eq!(result, Err(VmError::UnexpectedEof { ip: 0, opcode: 3 }));
+        assert_eq!(
+            session.records.last().unwrap().to_string(),
+            "4 fault No input available for opcode 3 at addr 0"
+        );
+        assert_eq!(Program::new(ECHO.to_vec()).replay(&session), Ok(()));
+    }
+
+    #[test]
+    fn test_replay() {
+        let mut session = Session::new();
+        let mut program = Program::new(ECHO.to_vec());
+        program
+            .run_recorded(
+                &mut VecDeque::from(vec![5, 9, 0]),
+                &mut Vec::new(),
+                &mut session,
+            )
+            .unwrap();
+        assert_eq!(Program::new(ECHO.to_vec()).replay(&session), Ok(()));
+
+        // the program outputs the input + 2
+        let mut changed = ECHO.to_vec();
+        changed[4] = 2;
+        let divergence = Program::new(changed).replay(&session).unwrap_err();
+        assert_eq!(divergence.index, 1);
+        assert_eq!(divergence.actual.io, Io::Output(7));
+        assert_eq!(
+            divergence.to_string(),
+            "Divergence at record 1: expected \"3 out 6\", got \"3 out 7\""
+        );
+
+        // day05 with the inputs of both parts
+        let mem = parse_program(include_str!("../../day05/src/input.txt")).unwrap();
+        for &input in &[1, 5] {
+            let mut session = Session::new();
+            Program::new(mem.clone())
+                .run_recorded(
+                    &mut VecDeque::from(vec![input]),
+                    &mut Vec::new(),
+                    &mut session,
+                )
+                .unwrap();
+            assert_eq!(Program::new(mem.clone()).replay(&session), Ok(()));
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use crate::memory::Memory;
+use crate::program::Program;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+// Snapshot of the complete state of a program
+//
+// A snapshot can be restored any number of times, e.g. to fork a search after a shared
+// prefix. Saved snapshots are text files, one field per line:
+//
+//   intcode-snapshot 1
+//   ip 42
+//   rel_base 1000
+//   limit none
+//   input 5,7
+//   image 1,2,3
+//   page 3 0,0,9,...
+//
+// There is one page line per allocated page, with the addr of its first cell.
+
+const HEADER: &str = "intcode-snapshot 1";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    pub mem: Memory,
+    pub ip: usize,
+    pub rel_base: i64,
+    // inputs which were pushed but not yet consumed
+    pub input: Vec<i64>,
+}
+
+fn invalid_data<T>(msg: String) -> io::Result<T> {
+    Err(io::Error::new(io::ErrorKind::InvalidData, msg))
+}
+
+fn join(vals: &[i64]) -> String {
+    vals.iter()
+        .map(|val| val.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn parse_num<T: std::str::FromStr>(text: &str) -> io::Result<T> {
+    match text.trim().parse() {
+        Ok(val) => Ok(val),
+        Err(_) => invalid_data(format!("Invalid number {:?}", text)),
+    }
+}
+
+fn parse_list(text: &str) -> io::Result<Vec<i64>> {
+    if text.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    text.split(',').map(parse_num).collect()
+}
+
+impl Snapshot {
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "{}", HEADER)?;
+        writeln!(writer, "ip {}", self.ip)?;
+        writeln!(writer, "rel_base {}", self.rel_base)?;
+        match self.mem.limit() {
+            Some(limit) => writeln!(writer, "limit {}", limit)?,
+            None => writeln!(writer, "limit none")?,
+        }
+        writeln!(writer, "input {}", join(&self.input))?;
+        writeln!(writer, "image {}", join(self.mem.image()))?;
+        for (addr, cells) in self.mem.pages() {
+            writeln!(writer, "page {} {}", addr, join(cells))?;
+        }
+        writer.flush()
+    }
+
+    pub fn read_from<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut lines = reader.lines();
+        match lines.next() {
+            Some(Ok(line)) if line.trim() == HEADER => (),
+            Some(Err(err)) => return Err(err),
+            _ => return invalid_data(format!("Missing header {:?}", HEADER)),
+        }
+
+        let mut ip = None;
+        let mut rel_base = None;
+        let mut limit = None;
+        let mut input = None;
+        let mut image = None;
+        let mut pages = Vec::new();
+        for line in lines {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (key, rest) = match line.find(' ') {
+                Some(pos) => (&line[..pos], &line[pos + 1..]),
+                None => (line, ""),
+            };
+            match key {
+                "ip" => ip = Some(parse_num(rest)?),
+                "rel_base" => rel_base = Some(parse_num(rest)?),
+                "limit" if rest.trim() == "none" => limit = Some(None),
+                "limit" => limit = Some(Some(parse_num(rest)?)),
+                "input" => input = Some(parse_list(rest)?),
+                "image" => image = Some(parse_list(rest)?),
+                "page" => match rest.find(' ') {
+                    Some(pos) => pages.push((
+                        parse_num::<usize>(&rest[..pos])?,
+                        parse_list(&rest[pos + 1..])?,
+                    )),
+                    None => return invalid_data(format!("Invalid page {:?}", rest)),
+                },
+                _ => return invalid_data(format!("Unknown field {:?}", key)),
+            }
+        }
+
+        let (ip, rel_base, limit, input, image) = match (ip, rel_base, limit, input, image) {
+            (Some(ip), Some(rel_base), Some(limit), Some(input), Some(image)) => {
+                (ip, rel_base, limit, input, image)
+            }
+            _ => return invalid_data("Missing field".to_string()),
+        };
+        let image_len = image.len();
+        let mut mem = match limit {
+            Some(limit) => Memory::with_limit(image, limit),
+            None => Memory::new(image),
+        };
+        for (addr, cells) in pages {
+            if addr < image_len {
+                return invalid_data(format!("Page {} overlaps the image", addr));
+            }
+            for (i, val) in cells.into_iter().enumerate() {
+                mem.set(addr + i, val);
+            }
+        }
+        Ok(Self {
+            mem,
+            ip,
+            rel_base,
+            input,
+        })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.write_to(BufWriter::new(File::create(path)?))
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::read_from(BufReader::new(File::open(path)?))
+    }
+}
+
+impl Program {
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            mem: self.mem().clone(),
+            ip: self.ip(),
+            rel_base: self.rel_base(),
+            input: self.input().iter().copied().collect(),
+        }
+    }
+
+    // Reset the program to the snapshot
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        self.mem_mut().clone_from(&snapshot.mem);
+        self.set_ip(snapshot.ip);
+        self.set_rel_base(snapshot.rel_base);
+        let input = self.input_mut();
+        input.clear();
+        input.extend(&snapshot.input);
+    }
+
+    pub fn from_snapshot(snapshot: Snapshot) -> Self {
+        let mut program = Program::with_memory(snapshot.mem);
+        program.set_ip(snapshot.ip);
+        program.set_rel_base(snapshot.rel_base);
+        program.input_mut().extend(snapshot.input);
+        program
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::program::State;
+
+    #[test]
+    fn test_restore() {
+        // output 1 if input == 8, else 0
+        let mem = vec![3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8];
+        let mut program = Program::new(mem);
+        let snapshot = program.snapshot();
+        for (input, output) in &[(8, 1), (7, 0)] {
+            program.restore(&snapshot);
+            assert_eq!(program.run_collect(&[*input]), Ok(vec![*output]));
+        }
+
+        program.restore(&snapshot);
+        program.push_input(8);
+        let mut fork = Program::from_snapshot(program.snapshot());
+        assert_eq!(fork.resume(), Ok(State::Output(1)));
+        assert_eq!(program.snapshot().input, vec![8]);
+    }
+
+    #[test]
+    fn test_serialize() {
+        let mut mem = Memory::with_limit(vec![109, 5, 204, -1, 99], 5000);
+        mem.set(3000, 42);
+        let snapshot = Snapshot {
+            mem,
+            ip: 2,
+            rel_base: 5,
+            input: vec![1, -2],
+        };
+        let mut text = Vec::new();
+        snapshot.write_to(&mut text).unwrap();
+        assert!(String::from_utf8(text.clone())
+            .unwrap()
+            .starts_with("intcode-snapshot 1\nip 2\nrel_base 5\nlimit 5000\ninput 1,-2\nimage 109,5,204,-1,99\npage 2053 "));
+        assert_eq!(Snapshot::read_from(&text[..]).unwrap(), snapshot);
+
+        assert!(Snapshot::read_from(&b"intcode-snapshot 1\nip 0\n"[..]).is_err());
+        assert!(Snapshot::read_from(&b"ip 0\n"[..]).is_err());
+    }
+}
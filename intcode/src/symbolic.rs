@@ -0,0 +1,443 @@
+use crate::error::VmError;
+use crate::op::{parse_opcode, Op, ParamMode};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::Range;
+
+// Symbolic execution of programs using only Op::Add, Op::Mul and Op::Halt (day02)
+//
+// Some cells of the image are unknowns, every cell holds a polynomial in the unknowns.
+// Values read from an addr which depends on an unknown are unknown themselves (Sym::Opaque),
+// this is fine as long as they are overwritten before they are used. Opcodes, write addrs
+// and overflows must not depend on unknowns.
+
+// Polynomial with integer coefficients, terms map the exponent of every unknown to the
+// coefficient
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Poly {
+    terms: BTreeMap<Vec<u32>, i64>,
+}
+
+impl Poly {
+    pub fn constant(val: i64) -> Self {
+        let mut terms = BTreeMap::new();
+        if val != 0 {
+            terms.insert(Vec::new(), val);
+        }
+        Self { terms }
+    }
+
+    // The unknown with index var
+    pub fn var(var: usize) -> Self {
+        let mut exps = vec![0; var + 1];
+        exps[var] = 1;
+        let mut terms = BTreeMap::new();
+        terms.insert(exps, 1);
+        Self { terms }
+    }
+
+    fn insert(&mut self, mut exps: Vec<u32>, coeff: i64) -> Option<()> {
+        while exps.last() == Some(&0) {
+            exps.pop();
+        }
+        let sum = self.terms.get(&exps).unwrap_or(&0).checked_add(coeff)?;
+        if sum == 0 {
+            self.terms.remove(&exps);
+        } else {
+            self.terms.insert(exps, sum);
+        }
+        Some(())
+    }
+
+    // None on overflow of a coefficient
+    pub fn checked_add(&self, other: &Poly) -> Option<Poly> {
+        let mut result = self.clone();
+        for (exps, &coeff) in &other.terms {
+            result.insert(exps.clone(), coeff)?;
+        }
+        Some(result)
+    }
+
+    // None on overflow of a coefficient
+    pub fn checked_mul(&self, other: &Poly) -> Option<Poly> {
+        let mut result = Poly::constant(0);
+        for (lhs_exps, &lhs) in &self.terms {
+            for (rhs_exps, &rhs) in &other.terms {
+                let len = lhs_exps.len().max(rhs_exps.len());
+                let exps = (0..len)
+                    .map(|i| lhs_exps.get(i).unwrap_or(&0) + rhs_exps.get(i).unwrap_or(&0))
+                    .collect();
+                result.insert(exps, lhs.checked_mul(rhs)?)?;
+            }
+        }
+        Some(result)
+    }
+
+    pub fn as_constant(&self) -> Option<i64> {
+        match self.terms.len() {
+            0 => Some(0),
+            1 => self.terms.get(&Vec::new()).copied(),
+            _ => None,
+        }
+    }
+
+    // Highest exponent of the unknown var
+    pub fn degree(&self, var: usize) -> u32 {
+        self.terms
+            .keys()
+            .map(|exps| exps.get(var).copied().unwrap_or(0))
+            .max()
+            .unwrap_or(0)
+    }
+
+    // Value for the given unknowns, None on overflow
+    pub fn eval(&self, vals: &[i64]) -> Option<i64> {
+        let mut sum = 0_i64;
+        for (exps, &coeff) in &self.terms {
+            let mut term = coeff;
+            for (var, &exp) in exps.iter().enumerate() {
+                term = term.checked_mul(vals[var].checked_pow(exp)?)?;
+            }
+            sum = sum.checked_add(term)?;
+        }
+        Some(sum)
+    }
+
+    // Replace the unknown var by val
+    pub fn substitute(&self, var: usize, val: i64) -> Option<Poly> {
+        let mut result = Poly::constant(0);
+        for (exps, &coeff) in &self.terms {
+            let mut exps = exps.clone();
+            let mut coeff = coeff;
+            if let Some(exp) = exps.get_mut(var) {
+                coeff = coeff.checked_mul(val.checked_pow(*exp)?)?;
+                *exp = 0;
+            }
+            result.insert(exps, coeff)?;
+        }
+        Some(result)
+    }
+
+    // Format with names for the unknowns, highest degree first
+    pub fn format(&self, names: &[&str]) -> String {
+        if self.terms.is_empty() {
+            return "0".to_string();
+        }
+        let mut terms = self.terms.iter().collect::<Vec<_>>();
+        terms.sort_by_key(|(exps, _)| std::cmp::Reverse((exps.iter().sum::<u32>(), exps.to_vec())));
+        let mut result = String::new();
+        for (i, (exps, &coeff)) in terms.into_iter().enumerate() {
+            let vars = exps
+                .iter()
+                .enumerate()
+                .filter(|(_, &exp)| exp > 0)
+                .map(|(var, &exp)| match exp {
+                    1 => names[var].to_string(),
+                    _ => format!("{}^{}", names[var], exp),
+                })
+                .collect::<Vec<_>>()
+                .join("*");
+            let sign = if coeff < 0 { "-" } else { "+" };
+            if i == 0 && coeff < 0 {
+                result.push('-');
+            } else if i > 0 {
+                result.push_str(&format!(" {} ", sign));
+            }
+            let abs = coeff.unsigned_abs();
+            match (abs, vars.is_empty()) {
+                (_, true) => result.push_str(&abs.to_string()),
+                (1, false) => result.push_str(&vars),
+                (_, false) => result.push_str(&format!("{}*{}", abs, vars)),
+            }
+        }
+        result
+    }
+}
+
+// Unknowns are named x0, x1, ..
+impl fmt::Display for Poly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let num_vars = self.terms.keys().map(Vec::len).max().unwrap_or(0);
+        let names = (0..num_vars)
+            .map(|var| format!("x{}", var))
+            .collect::<Vec<_>>();
+        let names = names.iter().map(String::as_str).collect::<Vec<_>>();
+        write!(f, "{}", self.format(&names))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Sym {
+    Poly(Poly),
+    // read from an addr which depends on an unknown
+    Opaque,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SymError {
+    Vm(VmError),
+    // only Op::Add, Op::Mul and Op::Halt are supported
+    UnsupportedOp { ip: usize, op: Op },
+    // the opcode depends on an unknown
+    SymbolicOpcode { ip: usize },
+    // the write addr depends on an unknown
+    SymbolicWrite { ip: usize },
+    // write behind the image
+    WriteOutside { ip: usize, addr: i64 },
+    Overflow { ip: usize },
+}
+
+impl fmt::Display for SymError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SymError::Vm(err) => write!(f, "{}", err),
+            SymError::UnsupportedOp { ip, op } => {
+                write!(f, "Unsupported op {} at addr {}", op.mnemonic(), ip)
+            }
+            SymError::SymbolicOpcode { ip } => write!(f, "Opcode at addr {} is symbolic", ip),
+            SymError::SymbolicWrite { ip } => {
+                write!(f, "Write addr of instruction at addr {} is symbolic", ip)
+            }
+            SymError::WriteOutside { ip, addr } => {
+                write!(f, "Write to addr {} behind the image at addr {}", addr, ip)
+            }
+            SymError::Overflow { ip } => write!(f, "Overflow at addr {}", ip),
+        }
+    }
+}
+
+impl std::error::Error for SymError {}
+
+struct SymProgram {
+    mem: Vec<Sym>,
+    ip: usize,
+}
+
+impl SymProgram {
+    fn cell(&self, addr: i64) -> Result<Sym, SymError> {
+        if addr < 0 {
+            return Err(SymError::Vm(VmError::NegativeAddress {
+                ip: self.ip,
+                opcode: self.opcode()?,
+                addr,
+            }));
+        }
+        // untouched cells behind the image read as 0
+        Ok(self
+            .mem
+            .get(addr as usize)
+            .cloned()
+            .unwrap_or_else(|| Sym::Poly(Poly::constant(0))))
+    }
+
+    fn opcode(&self) -> Result<i64, SymError> {
+        match self.mem.get(self.ip) {
+            Some(Sym::Poly(poly)) => poly
+                .as_constant()
+                .ok_or(SymError::SymbolicOpcode { ip: self.ip }),
+            Some(Sym::Opaque) => Err(SymError::SymbolicOpcode { ip: self.ip }),
+            None => Ok(0),
+        }
+    }
+
+    fn param(&self, offset: usize, mode: ParamMode) -> Result<Sym, SymError> {
+        let param = self.cell((self.ip + offset) as i64)?;
+        if mode == ParamMode::Val {
+            return Ok(param);
+        }
+        // there is no Op::SetRelBase, rel base stays 0
+        match param {
+            Sym::Poly(poly) => match poly.as_constant() {
+                Some(addr) => self.cell(addr),
+                None => Ok(Sym::Opaque),
+            },
+            Sym::Opaque => Ok(Sym::Opaque),
+        }
+    }
+
+    fn result_addr(&self, offset: usize, mode: ParamMode) -> Result<usize, SymError> {
+        let ip = self.ip;
+        if mode == ParamMode::Val {
+            return Err(SymError::Vm(VmError::WriteToImmediate {
+                ip,
+                opcode: self.opcode()?,
+            }));
+        }
+        let addr = match self.cell((ip + offset) as i64)? {
+            Sym::Poly(poly) => poly.as_constant().ok_or(SymError::SymbolicWrite { ip })?,
+            Sym::Opaque => return Err(SymError::SymbolicWrite { ip }),
+        };
+        if addr < 0 || addr as usize >= self.mem.len() {
+            return Err(SymError::WriteOutside { ip, addr });
+        }
+        Ok(addr as usize)
+    }
+
+    fn run(&mut self) -> Result<(), SymError> {
+        loop {
+            let opcode = self.opcode()?;
+            let (op, mode_0, mode_1, mode_2) =
+                parse_opcode(opcode, self.ip).map_err(SymError::Vm)?;
+            let result = match op {
+                Op::Halt => return Ok(()),
+                Op::Add | Op::Mul => {
+                    let lhs = self.param(1, mode_0)?;
+                    let rhs = self.param(2, mode_1)?;
+                    let addr = self.result_addr(3, mode_2)?;
+                    let result = match (lhs, rhs) {
+                        (Sym::Poly(lhs), Sym::Poly(rhs)) => {
+                            let result = if op == Op::Add {
+                                lhs.checked_add(&rhs)
+                            } else {
+                                lhs.checked_mul(&rhs)
+                            };
+                            Sym::Poly(result.ok_or(SymError::Overflow { ip: self.ip })?)
+                        }
+                        _ => Sym::Opaque,
+                    };
+                    (addr, result)
+                }
+                _ => return Err(SymError::UnsupportedOp { ip: self.ip, op }),
+            };
+            self.mem[result.0] = result.1;
+            // no jumps, ip only grows, so this terminates
+            self.ip += 4;
+        }
+    }
+}
+
+// Run the program with the cells at the addrs unknowns as unknowns 0, 1, ..
+// Returns the mem after halt
+pub fn run_symbolic(mem: &[i64], unknowns: &[usize]) -> Result<Vec<Sym>, SymError> {
+    let mut mem = mem
+        .iter()
+        .map(|&val| Sym::Poly(Poly::constant(val)))
+        .collect::<Vec<_>>();
+    for (var, &addr) in unknowns.iter().enumerate() {
+        if addr < mem.len() {
+            mem[addr] = Sym::Poly(Poly::var(var));
+        }
+    }
+    let mut program = SymProgram { mem, ip: 0 };
+    program.run()?;
+    Ok(program.mem)
+}
+
+// Values for the unknowns in the ranges, for which poly equals target
+//
+// All unknowns but the last are enumerated, the last one is solved directly if poly is
+// linear in it, otherwise it is enumerated too. Returns the first solution in lexicographic
+// order of the unknowns.
+pub fn solve(poly: &Poly, target: i64, ranges: &[Range<i64>]) -> Option<Vec<i64>> {
+    let (last, rest) = ranges.split_last()?;
+    let var = rest.len();
+    if rest.is_empty() {
+        let rhs = poly.substitute(var, 0)?.as_constant()?;
+        if poly.degree(var) <= 1 {
+            let coeff = poly.substitute(var, 1)?.as_constant()?.checked_sub(rhs)?;
+            let diff = target.checked_sub(rhs)?;
+            if coeff == 0 {
+                return if diff == 0 && !last.is_empty() {
+                    Some(vec![last.start])
+                } else {
+                    None
+                };
+            }
+            if diff % coeff == 0 && last.contains(&(diff / coeff)) {
+                return Some(vec![diff / coeff]);
+            }
+            return None;
+        }
+        return last
+            .clone()
+            .find(|&val| poly.eval(&[val]) == Some(target))
+            .map(|val| vec![val]);
+    }
+
+    // substitute the first unknown and shift the others down
+    for val in rest[0].clone() {
+        let reduced = shift(&poly.substitute(0, val)?);
+        if let Some(mut solution) = solve(&reduced, target, &ranges[1..]) {
+            solution.insert(0, val);
+            return Some(solution);
+        }
+    }
+    None
+}
+
+// Drop the exponent of unknown 0, which must be 0
+fn shift(poly: &Poly) -> Poly {
+    let mut result = Poly::constant(0);
+    for (exps, &coeff) in &poly.terms {
+        let exps = exps.iter().skip(1).copied().collect();
+        result.insert(exps, coeff).unwrap();
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_poly() {
+        let x = Poly::var(0);
+        let y = Poly::var(1);
+        // (x + 2) * (y + 3) = x*y + 3*x + 2*y + 6
+        let poly = x
+            .checked_add(&Poly::constant(2))
+            .unwrap()
+            .checked_mul(&y.checked_add(&Poly::constant(3)).unwrap())
+            .unwrap();
+        assert_eq!(poly.eval(&[4, 5]), Some(48));
+        assert_eq!(
+            poly.format(&["noun", "verb"]),
+            "noun*verb + 3*noun + 2*verb + 6"
+        );
+        assert_eq!(poly.to_string(), "x0*x1 + 3*x0 + 2*x1 + 6");
+        assert_eq!(poly.substitute(0, 1).unwrap().to_string(), "3*x1 + 9");
+        assert_eq!(Poly::constant(0).to_string(), "0");
+        assert_eq!(solve(&poly, 48, &[0..10, 0..10]), Some(vec![2, 9]));
+        assert_eq!(solve(&poly, 7, &[0..10, 0..10]), None);
+    }
+
+    #[test]
+    fn test_run_symbolic() {
+        // [0] = [1] * [2] + [1], [3] is overwritten after a read from an unknown addr
+        let mem = vec![1, 1, 1, 3, 2, 1, 2, 3, 1, 3, 1, 0, 99];
+        let mem = run_symbolic(&mem, &[1, 2]).unwrap();
+        match &mem[0] {
+            Sym::Poly(poly) => assert_eq!(poly.to_string(), "x0*x1 + x0"),
+            sym => panic!("Expected poly, got {:?}", sym),
+        }
+
+        assert_eq!(
+            run_symbolic(&[1, 0, 0, 5, 99], &[3]),
+            Err(SymError::SymbolicWrite { ip: 0 })
+        );
+        assert_eq!(
+            run_symbolic(&[3, 0, 99], &[]),
+            Err(SymError::UnsupportedOp { ip: 0, op: Op::In })
+        );
+    }
+
+    #[test]
+    fn test_day02() {
+        let mem = crate::program::parse_program(include_str!("../../day02/src/input.txt")).unwrap();
+        let poly = match run_symbolic(&mem, &[1, 2]).unwrap().remove(0) {
+            Sym::Poly(poly) => poly,
+            Sym::Opaque => panic!("Expected poly"),
+        };
+        for &(noun, verb) in &[(12, 2), (64, 72), (0, 0)] {
+            let mut program = crate::Program::new(mem.clone());
+            program.mem_mut().set(1, noun);
+            program.mem_mut().set(2, verb);
+            program.run_collect(&[]).unwrap();
+            assert_eq!(poly.eval(&[noun, verb]), Some(program.mem()[0]));
+        }
+        assert_eq!(
+            solve(&poly, 19_690_720, &[0..100, 0..100]),
+            Some(vec![64, 72])
+        );
+    }
+}
@@ -0,0 +1,432 @@
+use crate::error::VmError;
+use crate::io::{Input, Output};
+use crate::network::{AddMachineError, NetworkError, NetworkState, Route};
+use crate::program::Program;
+use std::collections::{HashMap, VecDeque};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::Duration;
+
+// Network with one thread per machine, channels are std mpsc channels
+//
+// Same topology as Network, but every machine runs on its own thread until it halts.
+// When a machine exits, it drops its senders, so machines waiting on a channel without
+// any remaining sender see the end of input and exit too. Idle inputs are not supported,
+// machines which wait longer than the timeout are stopped. A machine which computes forever
+// is only stopped by an instruction or time budget, it fails with VmError::BudgetExhausted.
+//
+// The network is run until all machines exited:
+//
+// - Halted, all machines halted
+// - Deadlock, some machines ran out of input or timed out
+//
+// Packet routes hold a sender for every channel, so these networks only shut down by
+// halting or by the timeout. Packets to channels which no machine reads are kept in the
+// network.
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MachineExit {
+    Halted,
+    // all senders of the input channel are gone
+    InputClosed,
+    TimedOut,
+    Fault(VmError),
+}
+
+// Receiver with an optional timeout
+pub struct ChannelInput {
+    rx: Receiver<i64>,
+    timeout: Option<Duration>,
+    timed_out: bool,
+}
+
+impl ChannelInput {
+    pub fn new(rx: Receiver<i64>, timeout: Option<Duration>) -> Self {
+        Self {
+            rx,
+            timeout,
+            timed_out: false,
+        }
+    }
+
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    // Values which were sent but not read
+    pub fn remaining(&self) -> Vec<i64> {
+        self.rx.try_iter().collect()
+    }
+}
+
+impl Input for ChannelInput {
+    fn read(&mut self) -> Option<i64> {
+        match self.timeout {
+            Some(timeout) => match self.rx.recv_timeout(timeout) {
+                Ok(val) => Some(val),
+                Err(RecvTimeoutError::Timeout) => {
+                    self.timed_out = true;
+                    None
+                }
+                Err(RecvTimeoutError::Disconnected) => None,
+            },
+            None => self.rx.recv().ok(),
+        }
+    }
+}
+
+struct RouteOutput {
+    route: Route,
+    senders: HashMap<String, Sender<i64>>,
+    // values for channels which are not read by any machine
+    unrouted: HashMap<String, VecDeque<i64>>,
+    packet: Vec<i64>,
+    outputs: Vec<i64>,
+}
+
+impl Output for RouteOutput {
+    fn write(&mut self, val: i64) {
+        self.outputs.push(val);
+        let senders = &mut self.senders;
+        let unrouted = &mut self.unrouted;
+        self.route.dispatch(&mut self.packet, val, |channel, vals| {
+            match senders.get_mut(channel) {
+                Some(tx) => vals.iter().for_each(|&val| tx.write(val)),
+                None => unrouted
+                    .entry(channel.to_string())
+                    .or_default()
+                    .extend(vals),
+            }
+        });
+    }
+}
+
+struct Machine {
+    name: String,
+    program: Program,
+    input: String,
+    route: Route,
+    exit: Option<MachineExit>,
+    outputs: Vec<i64>,
+}
+
+#[derive(Default)]
+pub struct ThreadedNetwork {
+    machines: Vec<Machine>,
+    channels: HashMap<String, VecDeque<i64>>,
+    timeout: Option<Duration>,
+    instruction_budget: Option<u64>,
+    time_budget: Option<Duration>,
+}
+
+fn run_machine(
+    mut program: Program,
+    mut input: ChannelInput,
+    mut output: RouteOutput,
+) -> (Program, MachineExit, RouteOutput, ChannelInput) {
+    let exit = match program.run(&mut input, &mut output) {
+        Ok(()) => MachineExit::Halted,
+        Err(VmError::UnexpectedEof { .. }) if input.timed_out() => MachineExit::TimedOut,
+        Err(VmError::UnexpectedEof { .. }) => MachineExit::InputClosed,
+        Err(err) => MachineExit::Fault(err),
+    };
+    // the input is returned, other machines could still send to it
+    (program, exit, output, input)
+}
+
+impl ThreadedNetwork {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Add a machine which reads from the channel input and sends its outputs along route
+    // Every channel can only be read by one machine
+    pub fn add_machine(
+        &mut self,
+        name: &str,
+        program: Program,
+        input: &str,
+        route: Route,
+    ) -> Result<(), AddMachineError> {
+        if self.machines.iter().any(|m| m.name == name) {
+            return Err(AddMachineError::DuplicateName(name.to_string()));
+        }
+        if self.machines.iter().any(|m| m.input == input) {
+            return Err(AddMachineError::DuplicateInput(input.to_string()));
+        }
+        self.machines.push(Machine {
+            name: name.to_string(),
+            program,
+            input: input.to_string(),
+            route,
+            exit: None,
+            outputs: Vec::new(),
+        });
+        Ok(())
+    }
+
+    // Machines waiting longer than timeout for an input are stopped
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = Some(timeout);
+    }
+
+    // Every machine executes at most budget instructions per run
+    pub fn set_instruction_budget(&mut self, budget: u64) {
+        self.instruction_budget = Some(budget);
+    }
+
+    // Every machine runs for at most budget per run
+    pub fn set_time_budget(&mut self, budget: Duration) {
+        self.time_budget = Some(budget);
+    }
+
+    pub fn send(&mut self, channel: &str, val: i64) {
+        self.channels
+            .entry(channel.to_string())
+            .or_default()
+            .push_back(val);
+    }
+
+    // Values sent to the channel which were not consumed
+    pub fn channel(&self, channel: &str) -> Option<&VecDeque<i64>> {
+        self.channels.get(channel)
+    }
+
+    // All outputs of the machine so far
+    pub fn outputs(&self, name: &str) -> Option<&[i64]> {
+        self.machine(name).map(|m| &m.outputs[..])
+    }
+
+    pub fn program(&self, name: &str) -> Option<&Program> {
+        self.machine(name).map(|m| &m.program)
+    }
+
+    // How the machine exited in the last run
+    pub fn exit(&self, name: &str) -> Option<MachineExit> {
+        self.machine(name).and_then(|m| m.exit)
+    }
+
+    fn machine(&self, name: &str) -> Option<&Machine> {
+        self.machines.iter().find(|m| m.name == name)
+    }
+
+    pub fn run(&mut self) -> Result<NetworkState, NetworkError> {
+        let mut names = self.channels.keys().cloned().collect::<Vec<_>>();
+        for machine in &self.machines {
+            names.push(machine.input.clone());
+            if let Route::Channel(channel) = &machine.route {
+                names.push(channel.clone());
+            }
+            if let Route::Broadcast(targets) = &machine.route {
+                names.extend(targets.iter().cloned());
+            }
+        }
+        let mut senders = HashMap::new();
+        let mut receivers = HashMap::new();
+        for name in names {
+            if senders.contains_key(&name) {
+                continue;
+            }
+            let (tx, rx) = mpsc::channel();
+            for val in self.channels.remove(&name).unwrap_or_default() {
+                let _ = tx.send(val);
+            }
+            senders.insert(name.clone(), tx);
+            receivers.insert(name, rx);
+        }
+
+        let mut handles = Vec::with_capacity(self.machines.len());
+        for machine in &mut self.machines {
+            let mut program = std::mem::replace(&mut machine.program, Program::new(Vec::new()));
+            if let Some(budget) = self.instruction_budget {
+                program.set_instruction_budget(budget);
+            }
+            if let Some(budget) = self.time_budget {
+                program.set_time_budget(budget);
+            }
+            let rx = receivers.remove(&machine.input).unwrap();
+            let input = ChannelInput::new(rx, self.timeout);
+            let targets = match &machine.route {
+                Route::Channel(channel) => vec![channel.clone()],
+                Route::Broadcast(targets) => targets.clone(),
+                Route::Packets(_) => senders.keys().cloned().collect(),
+                Route::None => Vec::new(),
+            };
+            let output = RouteOutput {
+                route: machine.route.clone(),
+                senders: targets
+                    .into_iter()
+                    .map(|channel| {
+                        let tx = senders[&channel].clone();
+                        (channel, tx)
+                    })
+                    .collect(),
+                unrouted: HashMap::new(),
+                packet: Vec::new(),
+                outputs: Vec::new(),
+            };
+            handles.push((
+                machine.input.clone(),
+                thread::spawn(move || run_machine(program, input, output)),
+            ));
+        }
+        // only the machines hold senders now
+        drop(senders);
+
+        let mut inputs = Vec::with_capacity(handles.len());
+        for (machine, (name, handle)) in self.machines.iter_mut().zip(handles) {
+            let (program, exit, output, input) = handle.join().unwrap();
+            machine.program = program;
+            machine.exit = Some(exit);
+            machine.outputs.extend(output.outputs);
+            for (channel, vals) in output.unrouted {
+                self.channels.entry(channel).or_default().extend(vals);
+            }
+            inputs.push((name, input));
+        }
+        for (name, input) in inputs {
+            self.channels.insert(name, input.remaining().into());
+        }
+        for (name, rx) in receivers {
+            self.channels.insert(name, rx.try_iter().collect());
+        }
+
+        for machine in &self.machines {
+            if let Some(MachineExit::Fault(err)) = machine.exit {
+                return Err(NetworkError {
+                    machine: machine.name.clone(),
+                    err,
+                });
+            }
+        }
+        let waiting = self
+            .machines
+            .iter()
+            .filter(|m| m.exit != Some(MachineExit::Halted))
+            .map(|m| m.name.clone())
+            .collect::<Vec<_>>();
+        if waiting.is_empty() {
+            Ok(NetworkState::Halted)
+        } else {
+            Ok(NetworkState::Deadlock(waiting))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::asm::assemble;
+
+    fn program(src: &str) -> Program {
+        Program::new(assemble(src).unwrap())
+    }
+
+    #[test]
+    fn test_loop() {
+        // output input + 1 until it is at least 5
+        let src = "
+            loop: in x
+                  add x, #1, x
+                  out x
+                  lt x, #5, more
+                  jt more, #loop
+                  hlt
+            x:    data 0
+            more: data 0
+        ";
+        let mut network = ThreadedNetwork::new();
+        network
+            .add_machine("A", program(src), "a", Route::Channel("b".into()))
+            .unwrap();
+        network
+            .add_machine("B", program(src), "b", Route::Channel("a".into()))
+            .unwrap();
+        network.send("a", 0);
+        assert_eq!(network.run(), Ok(NetworkState::Halted));
+        assert_eq!(network.outputs("A"), Some(&[1, 3, 5][..]));
+        assert_eq!(network.outputs("B"), Some(&[2, 4, 6][..]));
+        assert_eq!(network.channel("a"), Some(&VecDeque::from(vec![6])));
+    }
+
+    #[test]
+    fn test_shutdown() {
+        // output the sum of two inputs, forever
+        let src = "
+            loop: in a
+                  in b
+                  add a, b, a
+                  out a
+                  jt #1, #loop
+            a:    data 0
+            b:    data 0
+        ";
+        // input is closed, as nobody else sends to "in"
+        let mut network = ThreadedNetwork::new();
+        network
+            .add_machine("sum", program(src), "in", Route::Channel("out".into()))
+            .unwrap();
+        network.send("in", 1);
+        network.send("in", 2);
+        network.send("in", 3);
+        assert_eq!(
+            network.run(),
+            Ok(NetworkState::Deadlock(vec!["sum".to_string()]))
+        );
+        assert_eq!(network.exit("sum"), Some(MachineExit::InputClosed));
+        assert_eq!(network.channel("out"), Some(&VecDeque::from(vec![3])));
+
+        // the machine keeps its own input open, the packet (7, 7) is not read
+        let mut network = ThreadedNetwork::new();
+        network
+            .add_machine("sum", program(src), "in", Route::Packets(2))
+            .unwrap();
+        network.set_timeout(Duration::from_millis(10));
+        for val in &[1, 6, 3, 4] {
+            network.send("in", *val);
+        }
+        assert_eq!(
+            network.run(),
+            Ok(NetworkState::Deadlock(vec!["sum".to_string()]))
+        );
+        assert_eq!(network.exit("sum"), Some(MachineExit::TimedOut));
+        assert_eq!(network.outputs("sum"), Some(&[7, 7][..]));
+        assert_eq!(network.channel("7"), Some(&VecDeque::from(vec![7])));
+    }
+
+    #[test]
+    fn test_budget() {
+        // B computes forever, A halts
+        let mut network = ThreadedNetwork::new();
+        network
+            .add_machine("A", program("out #1\nhlt"), "a", Route::Channel("b".into()))
+            .unwrap();
+        network
+            .add_machine("B", program("loop: jt #1, #loop"), "b", Route::None)
+            .unwrap();
+        network.set_instruction_budget(1000);
+        let err = network.run().unwrap_err();
+        assert_eq!(err.machine, "B");
+        assert!(matches!(err.err, VmError::BudgetExhausted { .. }));
+        assert_eq!(network.exit("A"), Some(MachineExit::Halted));
+
+        let mut network = ThreadedNetwork::new();
+        network
+            .add_machine("B", program("loop: jt #1, #loop"), "b", Route::None)
+            .unwrap();
+        network.set_time_budget(Duration::from_millis(10));
+        assert!(matches!(
+            network.run().unwrap_err().err,
+            VmError::BudgetExhausted { .. }
+        ));
+
+        assert_eq!(
+            network.add_machine("B", program("hlt"), "c", Route::None),
+            Err(AddMachineError::DuplicateName("B".to_string()))
+        );
+        assert_eq!(
+            network.add_machine("C", program("hlt"), "b", Route::None),
+            Err(AddMachineError::DuplicateInput("b".to_string()))
+        );
+    }
+}
@@ -0,0 +1,326 @@
+use crate::disasm::{decode_mem, Instr};
+use crate::error::VmError;
+use crate::io::{Input, Output};
+use crate::op::{Op, ParamMode};
+use crate::program::{Program, State};
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, Write};
+
+// Tracing of executed instructions
+//
+// Every executed instruction produces an Event, which is passed to a Tracer:
+//
+// - TraceWriter writes one line per event
+// - Profiler counts instructions per addr and per op
+//
+// Instructions which pause the program with State::NeedsInput are not executed and not traced.
+// Halt is traced every time it is reached, the Profiler does not count it.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub ip: usize,
+    pub rel_base: i64,
+    pub instr: Instr,
+    // values of all params which are read, in param order
+    pub operands: Vec<i64>,
+    // addrs read by Addr and Rel params
+    pub reads: Vec<usize>,
+    // addr and new value of the mem write
+    pub write: Option<(usize, i64)>,
+}
+
+pub trait Tracer {
+    fn trace(&mut self, event: &Event);
+}
+
+impl<A: Tracer, B: Tracer> Tracer for (A, B) {
+    fn trace(&mut self, event: &Event) {
+        self.0.trace(event);
+        self.1.trace(event);
+    }
+}
+
+fn resolve(program: &Program, mode: ParamMode, val: i64) -> Option<usize> {
+    let addr = match mode {
+        ParamMode::Addr => val,
+        ParamMode::Rel => program.rel_base().checked_add(val)?,
+        ParamMode::Val => return None,
+    };
+    if addr < 0 {
+        None
+    } else {
+        Some(addr as usize)
+    }
+}
+
+impl Program {
+    // Same as step, executed instructions are passed to the tracer
+    pub fn step_traced(&mut self, tracer: &mut dyn Tracer) -> Result<Option<State>, VmError> {
+        let ip = self.ip();
+        let rel_base = self.rel_base();
+        let instr = match decode_mem(self.mem(), ip) {
+            Some(instr) => instr,
+            // let step report the fault
+            None => return self.step(),
+        };
+
+        let (read_params, write_param) = if instr.op.writes() {
+            let (last, rest) = instr.params.split_last().unwrap();
+            (rest, Some(last))
+        } else {
+            (&instr.params[..], None)
+        };
+        let mut operands = Vec::with_capacity(read_params.len());
+        let mut reads = Vec::with_capacity(read_params.len());
+        for param in read_params {
+            match resolve(self, param.mode, param.val) {
+                Some(addr) => {
+                    operands.push(self.mem().get(addr));
+                    reads.push(addr);
+                }
+                None => operands.push(param.val),
+            }
+        }
+        let write_addr = write_param.and_then(|param| resolve(self, param.mode, param.val));
+
+        let state = self.step()?;
+        if state == Some(State::NeedsInput) {
+            return Ok(state);
+        }
+        let write = write_addr.map(|addr| (addr, self.mem().get(addr)));
+        tracer.trace(&Event {
+            ip,
+            rel_base,
+            instr,
+            operands,
+            reads,
+            write,
+        });
+        Ok(state)
+    }
+
+    // Same as resume, executed instructions are passed to the tracer
+    pub fn resume_traced(&mut self, tracer: &mut dyn Tracer) -> Result<State, VmError> {
+        loop {
+            if let Some(state) = self.step_traced(tracer)? {
+                return Ok(state);
+            }
+        }
+    }
+
+    // Same as run, executed instructions are passed to the tracer
+    pub fn run_traced<I, O>(
+        &mut self,
+        input: &mut I,
+        output: &mut O,
+        tracer: &mut dyn Tracer,
+    ) -> Result<(), VmError>
+    where
+        I: Input + ?Sized,
+        O: Output + ?Sized,
+    {
+        self.run_with(input, output, |program| program.resume_traced(tracer))
+    }
+}
+
+// Writes one line per event:
+//
+//   0042 rb=1000 | ADD [r+3], #7 -> [120] | 5, 7 | [120] = 12
+pub struct TraceWriter<W: Write> {
+    writer: W,
+    result: io::Result<()>,
+}
+
+impl<W: Write> TraceWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            result: Ok(()),
+        }
+    }
+
+    // Returns the writer or the first write error
+    pub fn finish(mut self) -> io::Result<W> {
+        self.result?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write> Tracer for TraceWriter<W> {
+    fn trace(&mut self, event: &Event) {
+        if self.result.is_err() {
+            return;
+        }
+        let operands = event
+            .operands
+            .iter()
+            .map(|val| val.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.result = match event.write {
+            Some((addr, val)) => writeln!(
+                self.writer,
+                "{:04} rb={} | {} | {} | [{}] = {}",
+                event.ip, event.rel_base, event.instr, operands, addr, val
+            ),
+            None => writeln!(
+                self.writer,
+                "{:04} rb={} | {} | {}",
+                event.ip, event.rel_base, event.instr, operands
+            ),
+        };
+    }
+}
+
+// Instruction counts per addr and per op
+//
+// Halt is not counted, like in Program::instruction_count, so a halted program which is
+// resumed again does not change the counts.
+#[derive(Default)]
+pub struct Profiler {
+    total: u64,
+    addrs: HashMap<usize, (u64, Instr)>,
+    ops: HashMap<Op, u64>,
+    top: usize,
+}
+
+impl Profiler {
+    // top is the number of hot addrs shown in the summary
+    pub fn new(top: usize) -> Self {
+        Self {
+            top,
+            ..Default::default()
+        }
+    }
+
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    pub fn op_count(&self, op: Op) -> u64 {
+        self.ops.get(&op).copied().unwrap_or(0)
+    }
+
+    pub fn addr_count(&self, addr: usize) -> u64 {
+        self.addrs.get(&addr).map(|(count, _)| *count).unwrap_or(0)
+    }
+
+    // Addrs sorted by count, highest first
+    pub fn hot_addrs(&self) -> Vec<(usize, u64)> {
+        let mut addrs = self
+            .addrs
+            .iter()
+            .map(|(&addr, &(count, _))| (addr, count))
+            .collect::<Vec<_>>();
+        addrs.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        addrs
+    }
+}
+
+impl Tracer for Profiler {
+    fn trace(&mut self, event: &Event) {
+        if event.instr.op == Op::Halt {
+            return;
+        }
+        self.total += 1;
+        *self.ops.entry(event.instr.op).or_insert(0) += 1;
+        self.addrs
+            .entry(event.ip)
+            .or_insert_with(|| (0, event.instr.clone()))
+            .0 += 1;
+    }
+}
+
+impl fmt::Display for Profiler {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let percent = |count: u64| 100.0 * count as f64 / self.total.max(1) as f64;
+        writeln!(f, "instructions: {}", self.total)?;
+        writeln!(f, "ops:")?;
+        let mut ops = self.ops.iter().collect::<Vec<_>>();
+        ops.sort_by(|a, b| b.1.cmp(a.1).then(a.0.code().cmp(&b.0.code())));
+        for (op, &count) in ops {
+            writeln!(
+                f,
+                "  {:<4} {:>12} {:>6.2}%",
+                op.mnemonic(),
+                count,
+                percent(count)
+            )?;
+        }
+        writeln!(f, "hot addrs:")?;
+        for (addr, count) in self.hot_addrs().into_iter().take(self.top) {
+            writeln!(
+                f,
+                "  {:04} {:>12} {:>6.2}% | {}",
+                addr,
+                count,
+                percent(count),
+                self.addrs[&addr].1
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_trace() {
+        // read input into [9], output it, add 1 to it and halt
+        let mem = vec![3, 9, 4, 9, 1001, 9, 1, 9, 99, 0];
+        let mut program = Program::new(mem);
+        let mut tracer = (TraceWriter::new(Vec::new()), Profiler::new(2));
+        let mut output = Vec::new();
+        program
+            .run_traced(&mut VecDeque::from(vec![41]), &mut output, &mut tracer)
+            .unwrap();
+        assert_eq!(output, vec![41]);
+
+        let (writer, profiler) = tracer;
+        let trace = String::from_utf8(writer.finish().unwrap()).unwrap();
+        assert_eq!(
+            trace,
+            "0000 rb=0 | IN -> [9] |  | [9] = 41\n\
+             0002 rb=0 | OUT [9] | 41\n\
+             0004 rb=0 | ADD [9], #1 -> [9] | 41, 1 | [9] = 42\n\
+             0008 rb=0 | HLT | \n"
+        );
+        assert_eq!(profiler.total(), 3);
+        assert_eq!(profiler.total(), program.instruction_count());
+        assert_eq!(profiler.op_count(Op::Add), 1);
+        assert_eq!(profiler.addr_count(4), 1);
+    }
+
+    #[test]
+    fn test_profiler() {
+        // count [8] down from 3 to 0
+        let mem = vec![1001, 8, -1, 8, 1005, 8, 0, 99, 3];
+        let mut program = Program::new(mem);
+        let mut profiler = Profiler::new(1);
+        program
+            .run_traced(&mut VecDeque::new(), &mut Vec::new(), &mut profiler)
+            .unwrap();
+        assert_eq!(profiler.addr_count(0), 3);
+        assert_eq!(profiler.op_count(Op::JumpIfTrue), 3);
+        assert_eq!(profiler.hot_addrs()[0], (0, 3));
+        assert_eq!(profiler.total(), program.instruction_count());
+
+        // resuming the halted program executes nothing
+        program.resume_traced(&mut profiler).unwrap();
+        assert_eq!(profiler.total(), program.instruction_count());
+        assert_eq!(
+            profiler.to_string(),
+            "instructions: 6\n\
+             ops:\n\
+             \x20 add             3  50.00%\n\
+             \x20 jt              3  50.00%\n\
+             hot addrs:\n\
+             \x20 0000            3  50.00% | ADD [8], #-1 -> [8]\n"
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use crate::bigint::BigInt;
+use std::fmt;
+
+// Word type of the VM memory
+//
+// - i32, the range used by the puzzles up to day07
+// - i64, the default, enough for all puzzles
+// - BigInt, never overflows
+//
+// Arithmetic is checked, an overflow stops the program with VmError::Overflow. Addrs,
+// jump targets and the rel base are always converted to i64.
+
+pub trait Word: Clone + PartialEq + PartialOrd + fmt::Debug + fmt::Display {
+    // None if val does not fit
+    fn from_i64(val: i64) -> Option<Self>;
+    // None if the word does not fit
+    fn to_i64(&self) -> Option<i64>;
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+
+    fn zero() -> Self {
+        Self::from_i64(0).unwrap()
+    }
+
+    fn one() -> Self {
+        Self::from_i64(1).unwrap()
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    // Words which do not fit are clamped to i64::MIN or i64::MAX
+    fn saturating_to_i64(&self) -> i64 {
+        match self.to_i64() {
+            Some(val) => val,
+            None if *self < Self::zero() => i64::MIN,
+            None => i64::MAX,
+        }
+    }
+}
+
+impl Word for i32 {
+    fn from_i64(val: i64) -> Option<Self> {
+        std::convert::TryFrom::try_from(val).ok()
+    }
+
+    fn to_i64(&self) -> Option<i64> {
+        Some(*self as i64)
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        i32::checked_add(*self, *rhs)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        i32::checked_mul(*self, *rhs)
+    }
+}
+
+impl Word for i64 {
+    fn from_i64(val: i64) -> Option<Self> {
+        Some(val)
+    }
+
+    fn to_i64(&self) -> Option<i64> {
+        Some(*self)
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        i64::checked_add(*self, *rhs)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        i64::checked_mul(*self, *rhs)
+    }
+
+    fn zero() -> Self {
+        0
+    }
+
+    fn one() -> Self {
+        1
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == 0
+    }
+
+    fn saturating_to_i64(&self) -> i64 {
+        *self
+    }
+}
+
+impl Word for BigInt {
+    fn from_i64(val: i64) -> Option<Self> {
+        Some(BigInt::from(val))
+    }
+
+    fn to_i64(&self) -> Option<i64> {
+        BigInt::to_i64(self)
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+
+    fn zero() -> Self {
+        BigInt::zero()
+    }
+
+    fn is_zero(&self) -> bool {
+        BigInt::is_zero(self)
+    }
+}
+
+// Convert a program image to another word type, None if a value does not fit
+pub fn to_words<W: Word>(image: &[i64]) -> Option<Vec<W>> {
+    image.iter().map(|&val| W::from_i64(val)).collect()
+}